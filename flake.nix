@@ -7,7 +7,49 @@
   };
 
   outputs = { self, nixpkgs, flake-utils }:
-    flake-utils.lib.eachDefaultSystem (system:
+    let
+      inherit (nixpkgs) lib;
+      themectlFor = pkgs: self.packages.${pkgs.stdenv.hostPlatform.system}.themectl;
+    in
+    {
+      lib = {
+        # Bundled themes as attribute sets ({ name, description, colors, ... }),
+        # generated by `themectl export-all nix-lib nix/lib --themes-dir themes`
+        themes = import ./nix/lib;
+
+        # Every generated config for a theme file, e.g.
+        #   self.lib.mkThemeFiles { inherit pkgs; theme = ./themes/nord.toml; }
+        # produces $out/<theme>.<format> for all formats
+        mkThemeFiles = { pkgs, theme }:
+          let
+            name = lib.removeSuffix ".toml" (baseNameOf theme);
+          in
+          pkgs.runCommand "themectl-theme-${name}" {
+            nativeBuildInputs = [ (themectlFor pkgs) ];
+          } ''
+            export HOME=$TMPDIR
+            # The sandbox has no terminal; git colors would fall back to 16 colors
+            export COLORTERM=truecolor
+            mkdir themes
+            cp ${theme} themes/${name}.toml
+            themectl --themes-dir themes export ${name} all --output $out
+          '';
+      };
+
+      # Theme switching for Home Manager: set `themectl.theme` and `themectl.apps`
+      homeManagerModules.default = { pkgs, ... }: {
+        imports = [ ./nix/lib/home-manager.nix ];
+        home.packages = [ (themectlFor pkgs) ];
+      };
+
+      nixosModules.default = { config, lib, pkgs, ... }: {
+        options.programs.themectl.enable = lib.mkEnableOption "themectl";
+
+        config = lib.mkIf config.programs.themectl.enable {
+          environment.systemPackages = [ (themectlFor pkgs) ];
+        };
+      };
+    } // flake-utils.lib.eachDefaultSystem (system:
       let
         pkgs = import nixpkgs { inherit system; };
         themectl = pkgs.callPackage ./default.nix {
@@ -18,6 +60,15 @@
         packages = {
           default = themectl;
           themectl = themectl;
+
+          # nix/lib as built from the current themes and generators;
+          # refresh the committed copy with `cp result/* nix/lib/`
+          nix-lib = pkgs.runCommand "themectl-nix-lib" {
+            nativeBuildInputs = [ themectl ];
+          } ''
+            export HOME=$TMPDIR
+            themectl --themes-dir ${./themes} export-all nix-lib $out
+          '';
         };
 
         apps = {
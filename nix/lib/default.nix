@@ -0,0 +1,11 @@
+# themectl theme library
+# Generated by themectl
+# Regenerate with: themectl export-all nix-lib <dir>
+
+{
+  gruvbox-dark = {
+    name = "gruvbox-dark";
+    description = "Gruvbox dark theme";
+    colors = import ./gruvbox-dark.nix;
+  };
+}
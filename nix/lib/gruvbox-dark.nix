@@ -0,0 +1,16 @@
+# Theme: gruvbox-dark
+# Gruvbox dark theme
+
+{
+  bg = "#282828";
+  fg = "#ebdbb2";
+  accent = "#fe8019";
+  red = "#cc241d";
+  green = "#98971a";
+  yellow = "#d79921";
+  blue = "#458588";
+  magenta = "#b16286";
+  cyan = "#689d6a";
+  orange = "#d65d0e";
+  purple = "#b16286";
+}
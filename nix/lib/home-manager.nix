@@ -0,0 +1,3339 @@
+# Home Manager module for all themectl themes
+# Generated by themectl
+# Select a theme with `themectl.theme` and the apps to configure with `themectl.apps`.
+# With `themectl.specialisations = true`, switch without rebuilding:
+#   $(home-manager generations | head -1 | awk '{print $7}')/specialisation/<theme>/activate
+
+{ config, lib, pkgs, ... }:
+
+let
+  cfg = config.themectl;
+  moduleArgs = { inherit config lib pkgs; };
+
+  themes = {
+    gruvbox-dark = {
+      description = "Gruvbox dark theme";
+      colors = {
+        bg = "#282828";
+        fg = "#ebdbb2";
+        accent = "#fe8019";
+        red = "#cc241d";
+        green = "#98971a";
+        yellow = "#d79921";
+        blue = "#458588";
+        magenta = "#b16286";
+        cyan = "#689d6a";
+        orange = "#d65d0e";
+        purple = "#b16286";
+      };
+      apps = {
+        kitty = { config, lib, pkgs, ... }:
+          {
+            programs.kitty = {
+              enable = true;
+              settings = {
+                background = "#282828";
+                foreground = "#ebdbb2";
+                color0 = "#282828";
+                color1 = "#cc241d";
+                color2 = "#98971a";
+                color3 = "#d79921";
+                color4 = "#458588";
+                color5 = "#b16286";
+                color6 = "#689d6a";
+                color7 = "#ebdbb2";
+                color8 = "#3d3d3d";
+                color9 = "#d64f4a";
+                color10 = "#acab47";
+                color11 = "#dfad4d";
+                color12 = "#6a9d9f";
+                color13 = "#c0819e";
+                color14 = "#86b087";
+                color15 = "#efe2c1";
+                cursor = "#fe8019";
+                cursor_text_color = "#282828";
+                selection_background = "#fe8019";
+                selection_foreground = "#282828";
+                active_border_color = "#fe8019";
+                inactive_border_color = "#282828";
+                tab_bar_background = "#282828";
+                tab_bar_margin_color = "#282828";
+                active_tab_background = "#fe8019";
+                active_tab_foreground = "#282828";
+                inactive_tab_background = "#282828";
+                inactive_tab_foreground = "#ebdbb2";
+                bell_border_color = "#d79921";
+                url_color = "#689d6a";
+              };
+            };
+          };
+        alacritty = { config, lib, pkgs, ... }:
+          {
+            programs.alacritty = {
+              enable = true;
+              settings.colors = {
+                primary = {
+                  background = "#282828";
+                  foreground = "#ebdbb2";
+                };
+                normal = {
+                  black = "#282828";
+                  red = "#cc241d";
+                  green = "#98971a";
+                  yellow = "#d79921";
+                  blue = "#458588";
+                  magenta = "#b16286";
+                  cyan = "#689d6a";
+                  white = "#ebdbb2";
+                };
+                bright = {
+                  black = "#3d3d3d";
+                  red = "#d64f4a";
+                  green = "#acab47";
+                  yellow = "#dfad4d";
+                  blue = "#6a9d9f";
+                  magenta = "#c0819e";
+                  cyan = "#86b087";
+                  white = "#efe2c1";
+                };
+                cursor = {
+                  text = "#282828";
+                  cursor = "#fe8019";
+                };
+                selection = {
+                  text = "#282828";
+                  background = "#fe8019";
+                };
+                search.matches = {
+                  foreground = "#282828";
+                  background = "#d79921";
+                };
+                search.focused_match = {
+                  foreground = "#282828";
+                  background = "#d65d0e";
+                };
+                hints.start = {
+                  foreground = "#282828";
+                  background = "#d79921";
+                };
+                hints.end = {
+                  foreground = "#282828";
+                  background = "#689d6a";
+                };
+              };
+            };
+          };
+        foot = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/foot/themes/gruvbox-dark.ini".text = ''
+              # Foot theme: gruvbox-dark
+              # Generated by themectl
+              # Include it from ~/.config/foot/foot.ini:
+              #   include=~/.config/foot/themes/gruvbox-dark.ini
+              
+              [colors]
+              foreground=ebdbb2
+              background=282828
+              regular0=282828
+              regular1=cc241d
+              regular2=98971a
+              regular3=d79921
+              regular4=458588
+              regular5=b16286
+              regular6=689d6a
+              regular7=ebdbb2
+              bright0=3d3d3d
+              bright1=d64f4a
+              bright2=acab47
+              bright3=dfad4d
+              bright4=6a9d9f
+              bright5=c0819e
+              bright6=86b087
+              bright7=efe2c1
+              selection-foreground=282828
+              selection-background=fe8019
+              cursor=282828 fe8019
+              urls=689d6a
+            '';
+          };
+        wezterm = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/wezterm/colors/gruvbox-dark.toml".text = ''
+              # WezTerm color scheme: gruvbox-dark
+              # Generated by themectl
+              # Select it in ~/.config/wezterm/wezterm.lua:
+              #   config.color_scheme = "gruvbox-dark"
+              
+              [colors]
+              foreground = "#ebdbb2"
+              background = "#282828"
+              cursor_bg = "#fe8019"
+              cursor_fg = "#282828"
+              cursor_border = "#fe8019"
+              selection_bg = "#fe8019"
+              selection_fg = "#282828"
+              scrollbar_thumb = "#3d3d3d"
+              split = "#3d3d3d"
+              ansi = ["#282828", "#cc241d", "#98971a", "#d79921", "#458588", "#b16286", "#689d6a", "#ebdbb2"]
+              brights = ["#3d3d3d", "#d64f4a", "#acab47", "#dfad4d", "#6a9d9f", "#c0819e", "#86b087", "#efe2c1"]
+              
+              [colors.tab_bar]
+              background = "#282828"
+              
+              [colors.tab_bar.active_tab]
+              bg_color = "#fe8019"
+              fg_color = "#282828"
+              
+              [colors.tab_bar.inactive_tab]
+              bg_color = "#282828"
+              fg_color = "#ebdbb2"
+              
+              [metadata]
+              name = "gruvbox-dark"
+              author = "themectl"
+            '';
+          };
+        ghostty = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/ghostty/themes/gruvbox-dark".text = ''
+              # Ghostty theme: gruvbox-dark
+              # Generated by themectl
+              # Select it in ~/.config/ghostty/config:
+              #   theme = gruvbox-dark
+              
+              palette = 0=#282828
+              palette = 1=#cc241d
+              palette = 2=#98971a
+              palette = 3=#d79921
+              palette = 4=#458588
+              palette = 5=#b16286
+              palette = 6=#689d6a
+              palette = 7=#ebdbb2
+              palette = 8=#3d3d3d
+              palette = 9=#d64f4a
+              palette = 10=#acab47
+              palette = 11=#dfad4d
+              palette = 12=#6a9d9f
+              palette = 13=#c0819e
+              palette = 14=#86b087
+              palette = 15=#efe2c1
+              background = #282828
+              foreground = #ebdbb2
+              cursor-color = #fe8019
+              cursor-text = #282828
+              selection-background = #fe8019
+              selection-foreground = #282828
+            '';
+          };
+        tmux = { config, lib, pkgs, ... }:
+          {
+            programs.tmux = {
+              enable = true;
+              extraConfig = ''
+                # Tmux theme: gruvbox-dark
+                # Generated by themectl
+                # Source it from ~/.config/tmux/tmux.conf:
+                #   source-file ~/.config/tmux/themes/gruvbox-dark.conf
+                
+                # Status bar
+                set -g status-style "bg=#282828,fg=#ebdbb2"
+                set -g status-left-style "bg=#fe8019,fg=#282828,bold"
+                set -g status-right-style "bg=#3d3d3d,fg=#ebdbb2"
+                
+                # Window status
+                set -g window-status-style "bg=#282828,fg=#3d3d3d"
+                set -g window-status-current-style "bg=#fe8019,fg=#282828,bold"
+                set -g window-status-activity-style "bg=#282828,fg=#d79921"
+                set -g window-status-bell-style "bg=#282828,fg=#cc241d,bold"
+                
+                # Pane borders
+                set -g pane-border-style "fg=#3d3d3d"
+                set -g pane-active-border-style "fg=#fe8019"
+                set -g display-panes-colour "#3d3d3d"
+                set -g display-panes-active-colour "#fe8019"
+                
+                # Messages
+                set -g message-style "bg=#3d3d3d,fg=#ebdbb2"
+                set -g message-command-style "bg=#3d3d3d,fg=#fe8019"
+                
+                # Copy mode
+                set -g mode-style "bg=#fe8019,fg=#282828"
+                set -g copy-mode-match-style "bg=#d79921,fg=#282828"
+                set -g copy-mode-current-match-style "bg=#d65d0e,fg=#282828"
+                
+                # Clock
+                set -g clock-mode-colour "#fe8019"
+              '';
+            };
+          };
+        zellij = { config, lib, pkgs, ... }:
+          {
+            programs.zellij = {
+              enable = true;
+              settings = {
+                theme = "gruvbox-dark";
+                themes.gruvbox-dark = {
+                  fg = "#ebdbb2";
+                  bg = "#282828";
+                  black = "#282828";
+                  red = "#cc241d";
+                  green = "#98971a";
+                  yellow = "#d79921";
+                  blue = "#458588";
+                  magenta = "#b16286";
+                  cyan = "#689d6a";
+                  white = "#ebdbb2";
+                  orange = "#d65d0e";
+                };
+              };
+            };
+          };
+        waybar = { config, lib, pkgs, ... }:
+          {
+            programs.waybar = {
+              enable = true;
+              style = ''
+                /* Waybar theme: gruvbox-dark */
+                
+                * {
+                  border: none;
+                  border-radius: 0px;
+                  font-family: monospace;
+                  font-size: 12px;
+                  min-height: 0;
+                }
+                
+                window#waybar {
+                  background-color: #282828;
+                  color: #ebdbb2;
+                  border-bottom: 2px solid #fe8019;
+                }
+                
+                #workspaces button {
+                  color: #a4997c;
+                  padding: 0 4px;
+                }
+                
+                #workspaces button:hover {
+                  background-color: rgba(254, 128, 25, 0.2);
+                  color: #fe8019;
+                }
+                
+                #workspaces button.focused {
+                  background-color: #fe8019;
+                  color: #282828;
+                }
+                
+                #workspaces button.urgent {
+                  background-color: #cc241d;
+                  color: #282828;
+                }
+                
+                #clock {
+                  background-color: #fe8019;
+                  color: #282828;
+                  padding: 0 8px;
+                }
+                
+                #custom-music {
+                  color: #ebdbb2;
+                  padding: 0 4px;
+                }
+                
+                #custom-music.disconnected { color: #cc241d; }
+                #custom-music.stopped { color: #d79921; }
+                #custom-music.playing { color: #98971a; }
+                #custom-music.paused { color: #689d6a; }
+                
+                #pulseaudio, #network, #battery {
+                  color: #ebdbb2;
+                  padding: 0 4px;
+                  border-left: 2px solid rgba(254, 128, 25, 0.2);
+                }
+                
+                #pulseaudio { color: #458588; }
+                #pulseaudio.muted { color: #cc241d; }
+                
+                #network { color: #689d6a; }
+                #network.disconnected { color: #cc241d; }
+                
+                #battery { color: #98971a; }
+                #battery.warning { color: #d79921; }
+                #battery.critical { color: #cc241d; }
+                
+                tooltip {
+                  background-color: #282828;
+                  color: #ebdbb2;
+                  border: 1px solid #fe8019;
+                }
+              '';
+            };
+          };
+        neovim = { config, lib, pkgs, ... }:
+          {
+            programs.neovim = {
+              enable = true;
+              extraLuaConfig = ''
+                -- Neovim colorscheme: gruvbox-dark
+                -- Generated by themectl
+                
+                local colors = {
+                  bg = "#282828",
+                  fg = "#ebdbb2",
+                  accent = "#fe8019",
+                  red = "#cc241d",
+                  green = "#98971a",
+                  yellow = "#d79921",
+                  blue = "#458588",
+                  magenta = "#b16286",
+                  cyan = "#689d6a",
+                  orange = "#d65d0e",
+                  purple = "#b16286",
+                }
+                
+                local bg_light = "#323232"
+                local fg_dim = "#8d836a"
+                
+                vim.cmd("hi clear")
+                if vim.fn.exists("syntax_on") then
+                  vim.cmd("syntax reset")
+                end
+                
+                vim.g.colors_name = "gruvbox-dark"
+                
+                vim.g.terminal_color_0 = "#282828"
+                vim.g.terminal_color_1 = "#cc241d"
+                vim.g.terminal_color_2 = "#98971a"
+                vim.g.terminal_color_3 = "#d79921"
+                vim.g.terminal_color_4 = "#458588"
+                vim.g.terminal_color_5 = "#b16286"
+                vim.g.terminal_color_6 = "#689d6a"
+                vim.g.terminal_color_7 = "#ebdbb2"
+                vim.g.terminal_color_8 = "#3d3d3d"
+                vim.g.terminal_color_9 = "#d64f4a"
+                vim.g.terminal_color_10 = "#acab47"
+                vim.g.terminal_color_11 = "#dfad4d"
+                vim.g.terminal_color_12 = "#6a9d9f"
+                vim.g.terminal_color_13 = "#c0819e"
+                vim.g.terminal_color_14 = "#86b087"
+                vim.g.terminal_color_15 = "#efe2c1"
+                
+                vim.api.nvim_set_hl(0, "Normal", { bg = colors.bg, fg = colors.fg })
+                vim.api.nvim_set_hl(0, "NormalFloat", { bg = bg_light, fg = colors.fg })
+                vim.api.nvim_set_hl(0, "FloatBorder", { bg = bg_light, fg = colors.accent })
+                vim.api.nvim_set_hl(0, "FloatTitle", { bg = bg_light, fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "CursorLine", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "CursorColumn", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "ColorColumn", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "Cursor", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "MatchParen", { bg = bg_light, fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "SignColumn", { bg = colors.bg })
+                vim.api.nvim_set_hl(0, "WinSeparator", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "VertSplit", { link = "WinSeparator" })
+                vim.api.nvim_set_hl(0, "NonText", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "Whitespace", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "EndOfBuffer", { fg = colors.bg })
+                vim.api.nvim_set_hl(0, "Directory", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "Title", { fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "Visual", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "LineNr", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "CursorLineNr", { fg = colors.accent })
+                vim.api.nvim_set_hl(0, "StatusLine", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "StatusLineNC", { bg = bg_light, fg = colors.fg })
+                vim.api.nvim_set_hl(0, "TabLine", { bg = bg_light, fg = fg_dim })
+                vim.api.nvim_set_hl(0, "TabLineSel", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "TabLineFill", { bg = colors.bg })
+                vim.api.nvim_set_hl(0, "WinBar", { fg = colors.fg, bold = true })
+                vim.api.nvim_set_hl(0, "WinBarNC", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "ErrorMsg", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "MoreMsg", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "Question", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "Comment", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "String", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "Keyword", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "Function", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "Type", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "Constant", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "Statement", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "PreProc", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "Special", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "Number", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "Boolean", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "Identifier", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "Operator", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "Delimiter", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "Todo", { fg = colors.orange })
+                vim.api.nvim_set_hl(0, "Tag", { fg = colors.orange })
+                vim.api.nvim_set_hl(0, "Error", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "WarningMsg", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiagnosticError", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "DiagnosticVirtualTextError", { bg = bg_light, fg = colors.red })
+                vim.api.nvim_set_hl(0, "DiagnosticUnderlineError", { undercurl = true, sp = colors.red })
+                vim.api.nvim_set_hl(0, "DiagnosticSignError", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "DiagnosticWarn", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiagnosticVirtualTextWarn", { bg = bg_light, fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiagnosticUnderlineWarn", { undercurl = true, sp = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiagnosticSignWarn", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiagnosticInfo", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "DiagnosticVirtualTextInfo", { bg = bg_light, fg = colors.blue })
+                vim.api.nvim_set_hl(0, "DiagnosticUnderlineInfo", { undercurl = true, sp = colors.blue })
+                vim.api.nvim_set_hl(0, "DiagnosticSignInfo", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "DiagnosticHint", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "DiagnosticVirtualTextHint", { bg = bg_light, fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "DiagnosticUnderlineHint", { undercurl = true, sp = colors.cyan })
+                vim.api.nvim_set_hl(0, "DiagnosticSignHint", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "LspReferenceText", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "LspReferenceRead", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "LspReferenceWrite", { bg = bg_light, underline = true })
+                vim.api.nvim_set_hl(0, "LspInlayHint", { fg = fg_dim, italic = true })
+                vim.api.nvim_set_hl(0, "LspSignatureActiveParameter", { fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "DiffAdd", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "DiffDelete", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "DiffChange", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "DiffText", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "Search", { bg = colors.yellow, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "IncSearch", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "CurSearch", { link = "IncSearch" })
+                vim.api.nvim_set_hl(0, "Pmenu", { bg = bg_light, fg = colors.fg })
+                vim.api.nvim_set_hl(0, "PmenuSel", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "PmenuSbar", { bg = bg_light })
+                vim.api.nvim_set_hl(0, "PmenuThumb", { bg = fg_dim })
+                vim.api.nvim_set_hl(0, "Folded", { fg = fg_dim })
+                
+                -- Treesitter
+                vim.api.nvim_set_hl(0, "@string", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "@string.escape", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@string.regexp", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@character", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "@number", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@boolean", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@constant", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@constant.builtin", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@constant.macro", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@keyword", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@keyword.function", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@keyword.return", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@keyword.conditional", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@keyword.repeat", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@keyword.import", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@keyword.exception", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "@function", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@function.call", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@function.builtin", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@function.method", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@function.macro", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@constructor", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@type", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@type.builtin", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@type.definition", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@attribute", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "@module", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@label", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@variable", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "@variable.builtin", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@variable.parameter", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "@variable.member", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@property", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@operator", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "@punctuation.delimiter", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "@punctuation.bracket", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "@punctuation.special", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@tag.attribute", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@tag.delimiter", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "@markup.raw", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "@markup.link", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "@markup.list", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "@diff.plus", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "@diff.minus", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "@diff.delta", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "@comment", { fg = fg_dim, italic = true })
+                vim.api.nvim_set_hl(0, "@comment.todo", { fg = colors.bg, bg = colors.orange, bold = true })
+                vim.api.nvim_set_hl(0, "@tag", { fg = colors.orange })
+                vim.api.nvim_set_hl(0, "@markup.heading", { fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "@markup.strong", { bold = true })
+                vim.api.nvim_set_hl(0, "@markup.italic", { italic = true })
+                vim.api.nvim_set_hl(0, "@markup.strikethrough", { strikethrough = true })
+                vim.api.nvim_set_hl(0, "@markup.link.url", { fg = colors.cyan, underline = true })
+                
+                -- LSP semantic tokens
+                vim.api.nvim_set_hl(0, "@lsp.type.class", { link = "@type" })
+                vim.api.nvim_set_hl(0, "@lsp.type.enum", { link = "@type" })
+                vim.api.nvim_set_hl(0, "@lsp.type.interface", { link = "@type" })
+                vim.api.nvim_set_hl(0, "@lsp.type.struct", { link = "@type" })
+                vim.api.nvim_set_hl(0, "@lsp.type.type", { link = "@type" })
+                vim.api.nvim_set_hl(0, "@lsp.type.typeParameter", { link = "@type.definition" })
+                vim.api.nvim_set_hl(0, "@lsp.type.enumMember", { link = "@constant" })
+                vim.api.nvim_set_hl(0, "@lsp.type.namespace", { link = "@module" })
+                vim.api.nvim_set_hl(0, "@lsp.type.function", { link = "@function" })
+                vim.api.nvim_set_hl(0, "@lsp.type.method", { link = "@function.method" })
+                vim.api.nvim_set_hl(0, "@lsp.type.macro", { link = "@function.macro" })
+                vim.api.nvim_set_hl(0, "@lsp.type.decorator", { link = "@attribute" })
+                vim.api.nvim_set_hl(0, "@lsp.type.variable", { link = "@variable" })
+                vim.api.nvim_set_hl(0, "@lsp.type.parameter", { link = "@variable.parameter" })
+                vim.api.nvim_set_hl(0, "@lsp.type.property", { link = "@property" })
+                vim.api.nvim_set_hl(0, "@lsp.type.keyword", { link = "@keyword" })
+                vim.api.nvim_set_hl(0, "@lsp.type.comment", { link = "@comment" })
+                vim.api.nvim_set_hl(0, "@lsp.type.string", { link = "@string" })
+                vim.api.nvim_set_hl(0, "@lsp.type.number", { link = "@number" })
+                vim.api.nvim_set_hl(0, "@lsp.type.operator", { link = "@operator" })
+                vim.api.nvim_set_hl(0, "@lsp.mod.deprecated", { strikethrough = true })
+                
+                -- Telescope
+                vim.api.nvim_set_hl(0, "TelescopeNormal", { link = "NormalFloat" })
+                vim.api.nvim_set_hl(0, "TelescopeBorder", { link = "FloatBorder" })
+                vim.api.nvim_set_hl(0, "TelescopeTitle", { link = "FloatTitle" })
+                vim.api.nvim_set_hl(0, "TelescopePromptPrefix", { fg = colors.accent })
+                vim.api.nvim_set_hl(0, "TelescopeSelection", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "TelescopeSelectionCaret", { bg = colors.accent, fg = colors.bg })
+                vim.api.nvim_set_hl(0, "TelescopeMatching", { fg = colors.accent, bold = true })
+                
+                -- Gitsigns
+                vim.api.nvim_set_hl(0, "GitSignsAdd", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "GitSignsChange", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "GitSignsDelete", { fg = colors.red })
+                vim.api.nvim_set_hl(0, "GitSignsCurrentLineBlame", { fg = fg_dim, italic = true })
+                
+                -- which-key
+                vim.api.nvim_set_hl(0, "WhichKey", { fg = colors.accent })
+                vim.api.nvim_set_hl(0, "WhichKeyGroup", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "WhichKeyDesc", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "WhichKeySeparator", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "WhichKeyNormal", { link = "NormalFloat" })
+                
+                -- nvim-cmp
+                vim.api.nvim_set_hl(0, "CmpItemAbbr", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "CmpItemAbbrMatch", { fg = colors.accent, bold = true })
+                vim.api.nvim_set_hl(0, "CmpItemAbbrMatchFuzzy", { fg = colors.accent })
+                vim.api.nvim_set_hl(0, "CmpItemAbbrDeprecated", { fg = fg_dim, strikethrough = true })
+                vim.api.nvim_set_hl(0, "CmpItemMenu", { fg = fg_dim })
+                vim.api.nvim_set_hl(0, "CmpItemKindText", { fg = colors.green })
+                vim.api.nvim_set_hl(0, "CmpItemKindMethod", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "CmpItemKindFunction", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "CmpItemKindConstructor", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindField", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "CmpItemKindVariable", { fg = colors.fg })
+                vim.api.nvim_set_hl(0, "CmpItemKindClass", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindInterface", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindModule", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindProperty", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "CmpItemKindKeyword", { fg = colors.blue })
+                vim.api.nvim_set_hl(0, "CmpItemKindSnippet", { fg = colors.cyan })
+                vim.api.nvim_set_hl(0, "CmpItemKindConstant", { fg = colors.purple })
+                vim.api.nvim_set_hl(0, "CmpItemKindStruct", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindEnum", { fg = colors.yellow })
+                vim.api.nvim_set_hl(0, "CmpItemKindEnumMember", { fg = colors.purple })
+              '';
+            };
+          };
+        lualine = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/nvim/lua/lualine/themes/gruvbox-dark.lua".text = ''
+              -- lualine theme: gruvbox-dark
+              -- Generated by themectl
+              -- Select it in your lualine setup:
+              --   require("lualine").setup({ options = { theme = "gruvbox-dark" } })
+              
+              local bg = "#282828"
+              local fg = "#ebdbb2"
+              local bg_light = "#323232"
+              local fg_dim = "#8d836a"
+              
+              return {
+                normal = {
+                  a = { bg = "#fe8019", fg = bg, gui = "bold" },
+                  b = { bg = bg_light, fg = "#fe8019" },
+                  c = { bg = bg, fg = fg },
+                },
+                insert = {
+                  a = { bg = "#98971a", fg = bg, gui = "bold" },
+                  b = { bg = bg_light, fg = "#98971a" },
+                  c = { bg = bg, fg = fg },
+                },
+                visual = {
+                  a = { bg = "#b16286", fg = bg, gui = "bold" },
+                  b = { bg = bg_light, fg = "#b16286" },
+                  c = { bg = bg, fg = fg },
+                },
+                replace = {
+                  a = { bg = "#cc241d", fg = bg, gui = "bold" },
+                  b = { bg = bg_light, fg = "#cc241d" },
+                  c = { bg = bg, fg = fg },
+                },
+                command = {
+                  a = { bg = "#d79921", fg = bg, gui = "bold" },
+                  b = { bg = bg_light, fg = "#d79921" },
+                  c = { bg = bg, fg = fg },
+                },
+                inactive = {
+                  a = { bg = bg_light, fg = fg_dim, gui = "bold" },
+                  b = { bg = bg_light, fg = fg_dim },
+                  c = { bg = bg, fg = fg_dim },
+                },
+              }
+            '';
+          };
+        helix = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/helix/themes/gruvbox-dark.toml".text = ''
+              # Helix theme: gruvbox-dark
+              # Generated by themectl
+              # Select it in ~/.config/helix/config.toml:
+              #   theme = "gruvbox-dark"
+              
+              # UI
+              "ui.background" = { bg = "bg" }
+              "ui.text" = "fg"
+              "ui.text.focus" = { fg = "accent", modifiers = ["bold"] }
+              "ui.cursor" = { fg = "bg", bg = "accent" }
+              "ui.cursor.primary" = { fg = "bg", bg = "accent" }
+              "ui.cursor.match" = { bg = "bg_light", modifiers = ["bold"] }
+              "ui.cursorline.primary" = { bg = "bg_light" }
+              "ui.selection" = { fg = "bg", bg = "accent" }
+              "ui.selection.primary" = { fg = "bg", bg = "accent" }
+              "ui.linenr" = "fg_dim"
+              "ui.linenr.selected" = "accent"
+              "ui.statusline" = { fg = "bg", bg = "accent" }
+              "ui.statusline.inactive" = { fg = "fg", bg = "bg_light" }
+              "ui.statusline.normal" = { fg = "bg", bg = "accent", modifiers = ["bold"] }
+              "ui.statusline.insert" = { fg = "bg", bg = "green", modifiers = ["bold"] }
+              "ui.statusline.select" = { fg = "bg", bg = "magenta", modifiers = ["bold"] }
+              "ui.popup" = { fg = "fg", bg = "bg_light" }
+              "ui.help" = { fg = "fg", bg = "bg_light" }
+              "ui.menu" = { fg = "fg", bg = "bg_light" }
+              "ui.menu.selected" = { fg = "bg", bg = "accent" }
+              "ui.window" = "fg_dim"
+              "ui.virtual.whitespace" = "fg_dim"
+              "ui.virtual.ruler" = { bg = "bg_light" }
+              "ui.virtual.inlay-hint" = "fg_dim"
+              
+              # Syntax
+              "comment" = { fg = "fg_dim", modifiers = ["italic"] }
+              "string" = "green"
+              "keyword" = "blue"
+              "keyword.control" = "blue"
+              "keyword.directive" = "purple"
+              "function" = "cyan"
+              "type" = "yellow"
+              "namespace" = "yellow"
+              "constant" = "purple"
+              "constant.numeric" = "purple"
+              "constant.builtin.boolean" = "blue"
+              "constant.character.escape" = "cyan"
+              "attribute" = "purple"
+              "function.builtin" = "cyan"
+              "variable" = "fg"
+              "variable.builtin" = "cyan"
+              "variable.parameter" = "fg"
+              "variable.other.member" = "blue"
+              "operator" = "fg"
+              "punctuation" = "fg_dim"
+              "tag" = "orange"
+              "label" = "orange"
+              
+              # Diagnostics
+              "error" = "red"
+              "warning" = "yellow"
+              "info" = "blue"
+              "hint" = "cyan"
+              "diagnostic.error" = { underline = { color = "red", style = "curl" } }
+              "diagnostic.warning" = { underline = { color = "yellow", style = "curl" } }
+              "diagnostic.info" = { underline = { color = "blue", style = "curl" } }
+              "diagnostic.hint" = { underline = { color = "cyan", style = "curl" } }
+              "diff.plus" = "green"
+              "diff.minus" = "red"
+              "diff.delta" = "yellow"
+              
+              # Markup
+              "markup.heading" = { fg = "accent", modifiers = ["bold"] }
+              "markup.list" = "cyan"
+              "markup.bold" = { modifiers = ["bold"] }
+              "markup.italic" = { modifiers = ["italic"] }
+              "markup.strikethrough" = { modifiers = ["crossed_out"] }
+              "markup.link.url" = { fg = "cyan", modifiers = ["underlined"] }
+              "markup.link.text" = "blue"
+              "markup.quote" = { fg = "fg_dim", modifiers = ["italic"] }
+              "markup.raw" = "green"
+              
+              [palette]
+              bg = "#282828"
+              fg = "#ebdbb2"
+              accent = "#fe8019"
+              red = "#cc241d"
+              green = "#98971a"
+              yellow = "#d79921"
+              blue = "#458588"
+              magenta = "#b16286"
+              cyan = "#689d6a"
+              orange = "#d65d0e"
+              purple = "#b16286"
+              bg_light = "#323232"
+              fg_dim = "#8d836a"
+            '';
+          };
+        starship = { config, lib, pkgs, ... }:
+          {
+            programs.starship = {
+              enable = true;
+              settings = {
+                format = {
+                  all = "$all";
+                };
+                palette = {
+                  aqua = "#689d6a";
+                  blue = "#458588";
+                  green = "#98971a";
+                  orange = "#d65d0e";
+                  purple = "#b16286";
+                  red = "#cc241d";
+                  yellow = "#d79921";
+                };
+                palettes = {
+                  default = {
+                    bg1 = "#282828";
+                    bg3 = "#3d3d3d";
+                    fg0 = "#ebdbb2";
+                  };
+                };
+              };
+            };
+          };
+        mako = { config, lib, pkgs, ... }:
+          {
+            services.mako = {
+              enable = true;
+              settings = {
+                background-color = "#282828";
+                border-color = "#fe8019";
+                text-color = "#ebdbb2";
+                "urgency=low" = {
+                  border-color = "#b16286";
+                };
+                "urgency=normal" = {
+                  border-color = "#fe8019";
+                };
+                "urgency=warning" = {
+                  border-color = "#d65d0e";
+                  text-color = "#d65d0e";
+                };
+                "urgency=critical" = {
+                  border-color = "#cc241d";
+                  background-color = "#cc241d";
+                  text-color = "#282828";
+                };
+              };
+            };
+          };
+        dunst = { config, lib, pkgs, ... }:
+          {
+            services.dunst = {
+              enable = true;
+              settings = {
+                global = {
+                  frame_width = 2;
+                  corner_radius = 0;
+                  frame_color = "#fe8019";
+                  separator_color = "frame";
+                  highlight = "#fe8019";
+                };
+                urgency_low = {
+                  background = "#282828";
+                  foreground = "#ebdbb2";
+                  frame_color = "#b16286";
+                };
+                urgency_normal = {
+                  background = "#282828";
+                  foreground = "#ebdbb2";
+                  frame_color = "#fe8019";
+                };
+                urgency_critical = {
+                  background = "#cc241d";
+                  foreground = "#282828";
+                  frame_color = "#cc241d";
+                };
+              };
+            };
+          };
+        swaync = { config, lib, pkgs, ... }:
+          {
+            services.swaync = {
+              enable = true;
+              style = ''
+                /* SwayNotificationCenter theme: gruvbox-dark */
+                /* Generated by themectl */
+                
+                @define-color bg #282828;
+                @define-color bg-alt #323232;
+                @define-color fg #ebdbb2;
+                @define-color fg-dim #8d836a;
+                @define-color accent #fe8019;
+                @define-color low-border #b16286;
+                @define-color low-bg #282828;
+                @define-color low-fg #ebdbb2;
+                @define-color normal-border #fe8019;
+                @define-color normal-bg #282828;
+                @define-color normal-fg #ebdbb2;
+                @define-color critical-border #cc241d;
+                @define-color critical-bg #cc241d;
+                @define-color critical-fg #282828;
+                
+                * {
+                  font-size: inherit;
+                }
+                
+                .notification-row {
+                  outline: none;
+                }
+                
+                .notification {
+                  background: @bg;
+                  color: @fg;
+                  border: 2px solid @normal-border;
+                  border-radius: 0px;
+                  margin: 2px;
+                  box-shadow: none;
+                }
+                
+                .notification.low {
+                  background: @low-bg;
+                  color: @low-fg;
+                  border-color: @low-border;
+                }
+                
+                .notification.normal {
+                  background: @normal-bg;
+                  color: @normal-fg;
+                  border-color: @normal-border;
+                }
+                
+                .notification.critical {
+                  background: @critical-bg;
+                  color: @critical-fg;
+                  border-color: @critical-border;
+                }
+                
+                .notification.critical .summary,
+                .notification.critical .body,
+                .notification.critical .time {
+                  color: @critical-fg;
+                }
+                
+                .notification-content {
+                  padding: 4px;
+                }
+                
+                .notification-default-action,
+                .notification-action {
+                  background: transparent;
+                  color: inherit;
+                  border-radius: 0px;
+                }
+                
+                .notification-default-action:hover,
+                .notification-action:hover {
+                  background: @bg-alt;
+                }
+                
+                .summary {
+                  color: @fg;
+                  font-weight: bold;
+                }
+                
+                .body {
+                  color: @fg;
+                }
+                
+                .time {
+                  color: @fg-dim;
+                }
+                
+                .close-button {
+                  background: @bg-alt;
+                  color: @fg;
+                  border-radius: 0px;
+                }
+                
+                .close-button:hover {
+                  background: @critical-border;
+                  color: @bg;
+                }
+                
+                .control-center {
+                  background: @bg;
+                  color: @fg;
+                  border: 2px solid @accent;
+                  border-radius: 0px;
+                }
+                
+                .control-center-list {
+                  background: transparent;
+                }
+                
+                .floating-notifications {
+                  background: transparent;
+                }
+                
+                .blank-window {
+                  background: transparent;
+                }
+                
+                .widget-title {
+                  color: @fg;
+                  margin: 4px;
+                  font-weight: bold;
+                }
+                
+                .widget-title > button {
+                  background: @bg-alt;
+                  color: @fg;
+                  border-radius: 0px;
+                }
+                
+                .widget-title > button:hover {
+                  background: @accent;
+                  color: @bg;
+                }
+                
+                .widget-dnd {
+                  color: @fg;
+                  margin: 4px;
+                }
+                
+                .widget-dnd > switch {
+                  background: @bg-alt;
+                  border-radius: 0px;
+                }
+                
+                .widget-dnd > switch:checked {
+                  background: @accent;
+                }
+                
+                .widget-dnd > switch slider {
+                  background: @fg;
+                }
+                
+                .widget-label > label {
+                  color: @fg-dim;
+                }
+                
+                .widget-mpris-player {
+                  background: @bg-alt;
+                  border-radius: 0px;
+                  padding: 4px;
+                }
+                
+                .widget-volume,
+                .widget-backlight {
+                  background: @bg-alt;
+                  border-radius: 0px;
+                  padding: 4px;
+                }
+                
+                .widget-buttons-grid {
+                  background: @bg-alt;
+                  border-radius: 0px;
+                }
+                
+                .widget-buttons-grid > flowbox > flowboxchild > button {
+                  background: @bg;
+                  color: @fg;
+                  border-radius: 0px;
+                }
+                
+                .widget-buttons-grid > flowbox > flowboxchild > button:hover {
+                  background: @accent;
+                  color: @bg;
+                }
+              '';
+            };
+          };
+        hyprland = { config, lib, pkgs, ... }:
+          {
+            wayland.windowManager.hyprland = {
+              enable = true;
+              settings = {
+                general = {
+                  border_size = 2;
+                  gaps_in = 4;
+                  gaps_out = 8;
+                  "col.active_border" = "rgba(fe8019ee) rgba(b16286ee) 45deg";
+                  "col.inactive_border" = "rgba(323232aa)";
+                };
+                decoration = {
+                  rounding = 0;
+                  shadow = {
+                    enabled = true;
+                    range = 10;
+                    render_power = 3;
+                    color = "rgba(2828284d)";
+                  };
+                  blur = {
+                    enabled = true;
+                    size = 6;
+                    passes = 2;
+                    new_optimizations = true;
+                  };
+                };
+                group = {
+                  "col.border_active" = "rgba(fe8019ee) rgba(b16286ee) 45deg";
+                  "col.border_inactive" = "rgba(323232aa)";
+                  "col.border_locked_active" = "rgba(d79921ee)";
+                  groupbar = {
+                    "col.active" = "rgba(fe8019ee)";
+                    "col.inactive" = "rgba(323232aa)";
+                    "col.locked_active" = "rgba(d79921ee)";
+                    text_color = "rgba(ebdbb2ff)";
+                  };
+                };
+                misc = {
+                  background_color = "rgba(282828ff)";
+                  disable_hyprland_logo = true;
+                };
+                animations = {
+                  enabled = true;
+                  bezier = "themectl, 0.05, 0.9, 0.1, 1.05";
+                  animation = [ "windows, 1, 2, themectl" "windowsOut, 1, 2, default, popin 80%" "border, 1, 4, default" "fade, 1, 2, default" "workspaces, 1, 2, default" ];
+                };
+              };
+            };
+          };
+        sway = { config, lib, pkgs, ... }:
+          {
+            wayland.windowManager.sway = {
+              enable = true;
+              config = {
+                colors = {
+                  focused = { border = "#fe8019"; background = "#fe8019"; text = "#282828"; indicator = "#b16286"; childBorder = "#fe8019"; };
+                  focusedInactive = { border = "#323232"; background = "#323232"; text = "#ebdbb2"; indicator = "#323232"; childBorder = "#323232"; };
+                  unfocused = { border = "#282828"; background = "#282828"; text = "#8d836a"; indicator = "#282828"; childBorder = "#282828"; };
+                  urgent = { border = "#cc241d"; background = "#cc241d"; text = "#282828"; indicator = "#cc241d"; childBorder = "#cc241d"; };
+                  placeholder = { border = "#282828"; background = "#282828"; text = "#8d836a"; indicator = "#282828"; childBorder = "#282828"; };
+                };
+                window = { border = 2; titlebar = false; };
+                floating = { border = 2; titlebar = false; };
+                gaps.inner = 4;
+                # Bar colors: add them to the bar in your own config.bars definition
+                # colors = {
+                #   background = "#282828";
+                #   statusline = "#ebdbb2";
+                #   separator = "#8d836a";
+                #   focusedWorkspace = { border = "#fe8019"; background = "#fe8019"; text = "#282828"; };
+                #   activeWorkspace = { border = "#323232"; background = "#323232"; text = "#ebdbb2"; };
+                #   inactiveWorkspace = { border = "#282828"; background = "#282828"; text = "#8d836a"; };
+                #   urgentWorkspace = { border = "#cc241d"; background = "#cc241d"; text = "#282828"; };
+                #   bindingMode = { border = "#d79921"; background = "#d79921"; text = "#282828"; };
+                # };
+              };
+            };
+          };
+        i3 = { config, lib, pkgs, ... }:
+          {
+            xsession.windowManager.i3 = {
+              enable = true;
+              config = {
+                colors = {
+                  focused = { border = "#fe8019"; background = "#fe8019"; text = "#282828"; indicator = "#b16286"; childBorder = "#fe8019"; };
+                  focusedInactive = { border = "#323232"; background = "#323232"; text = "#ebdbb2"; indicator = "#323232"; childBorder = "#323232"; };
+                  unfocused = { border = "#282828"; background = "#282828"; text = "#8d836a"; indicator = "#282828"; childBorder = "#282828"; };
+                  urgent = { border = "#cc241d"; background = "#cc241d"; text = "#282828"; indicator = "#cc241d"; childBorder = "#cc241d"; };
+                  placeholder = { border = "#282828"; background = "#282828"; text = "#8d836a"; indicator = "#282828"; childBorder = "#282828"; };
+                  background = "#282828";
+                };
+                window = { border = 2; titlebar = false; };
+                floating = { border = 2; titlebar = false; };
+                gaps.inner = 4;
+                # Bar colors: add them to the bar in your own config.bars definition
+                # colors = {
+                #   background = "#282828";
+                #   statusline = "#ebdbb2";
+                #   separator = "#8d836a";
+                #   focusedWorkspace = { border = "#fe8019"; background = "#fe8019"; text = "#282828"; };
+                #   activeWorkspace = { border = "#323232"; background = "#323232"; text = "#ebdbb2"; };
+                #   inactiveWorkspace = { border = "#282828"; background = "#282828"; text = "#8d836a"; };
+                #   urgentWorkspace = { border = "#cc241d"; background = "#cc241d"; text = "#282828"; };
+                #   bindingMode = { border = "#d79921"; background = "#d79921"; text = "#282828"; };
+                # };
+              };
+            };
+          };
+        hyprlock = { config, lib, pkgs, ... }:
+          {
+            programs.hyprlock = {
+              enable = true;
+              settings = {
+                general = {
+                  hide_cursor = true;
+                };
+                background = [
+                  {
+                    monitor = "";
+                    color = "rgb(282828)";
+                    blur_passes = 2;
+                    blur_size = 10;
+                  }
+                ];
+                input-field = [
+                  {
+                    monitor = "";
+                    size = "300, 50";
+                    outline_thickness = 2;
+                    rounding = 0;
+                    outer_color = "rgb(fe8019)";
+                    inner_color = "rgb(323232)";
+                    font_color = "rgb(ebdbb2)";
+                    check_color = "rgb(d79921)";
+                    fail_color = "rgb(cc241d)";
+                    capslock_color = "rgb(d65d0e)";
+                    placeholder_text = "<i>Password...</i>";
+                    fade_on_empty = false;
+                    position = "0, -20";
+                    halign = "center";
+                    valign = "center";
+                  }
+                ];
+                label = [
+                  {
+                    monitor = "";
+                    text = "$TIME";
+                    color = "rgb(ebdbb2)";
+                    font_size = 64;
+                    position = "0, 120";
+                    halign = "center";
+                    valign = "center";
+                  }
+                  {
+                    monitor = "";
+                    text = "$USER";
+                    color = "rgb(8d836a)";
+                    font_size = 18;
+                    position = "0, 50";
+                    halign = "center";
+                    valign = "center";
+                  }
+                ];
+              };
+            };
+          };
+        swaylock = { config, lib, pkgs, ... }:
+          {
+            programs.swaylock = {
+              enable = true;
+              settings = {
+                color = "282828";
+                indicator-thickness = 10;
+                key-hl-color = "98971a";
+                bs-hl-color = "cc241d";
+                caps-lock-key-hl-color = "98971a";
+                caps-lock-bs-hl-color = "cc241d";
+                separator-color = "00000000";
+                layout-bg-color = "282828";
+                layout-border-color = "fe8019";
+                layout-text-color = "ebdbb2";
+                ring-color = "fe8019";
+                inside-color = "282828";
+                line-color = "282828";
+                text-color = "ebdbb2";
+                ring-clear-color = "d79921";
+                inside-clear-color = "282828";
+                line-clear-color = "282828";
+                text-clear-color = "d79921";
+                ring-ver-color = "458588";
+                inside-ver-color = "282828";
+                line-ver-color = "282828";
+                text-ver-color = "458588";
+                ring-wrong-color = "cc241d";
+                inside-wrong-color = "282828";
+                line-wrong-color = "282828";
+                text-wrong-color = "cc241d";
+                ring-caps-lock-color = "d65d0e";
+                inside-caps-lock-color = "282828";
+                line-caps-lock-color = "282828";
+                text-caps-lock-color = "d65d0e";
+              };
+            };
+          };
+        hyprpaper = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/hypr/hyprpaper.conf".text = ''
+              # Hyprpaper theme: gruvbox-dark
+              # Generated by themectl
+              
+              # Default wallpaper for this theme
+              # Replace with your wallpaper path
+              # Example: preload = ~/Pictures/wallpapers/gruvbox-dark.jpg
+              preload = 
+              
+              # Set wallpaper for all outputs
+              # Format: wallpaper = OUTPUT,/path/to/wallpaper
+              # Use empty OUTPUT (,) to set for all outputs
+              wallpaper = ,
+              
+              # Wallpaper Tinting/Filtering
+              # Hyprpaper doesn't support direct color tinting, but you can:
+              # 1. Use hyprshade for color overlays:
+              #    hyprshade on <shader-name>
+              # 2. Pre-process wallpapers with theme colors
+              # 3. Use a compositor shader for color adjustment
+              
+              # Theme Color Reference (for wallpaper selection/filtering)
+              # Use these colors when selecting or processing wallpapers:
+              # Background: #282828
+              # Foreground: #ebdbb2
+              # Accent: #fe8019
+              # Background RGB: 40 40 40
+              # Accent RGB: 254 128 25
+              
+              # Example: Apply subtle tint using theme accent color
+              # You can create a script that applies a color overlay to wallpapers
+              # using imagemagick or similar tools with the theme colors above
+            '';
+          };
+        wofi = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/wofi/style.css".text = ''
+              # Wofi theme: gruvbox-dark
+              # Generated by themectl
+              
+              window {
+                background-color: #282828;
+                border: 2px solid #fe8019;
+              }
+              
+              #input {
+                color: #ebdbb2;
+                background-color: #282828;
+                padding: 4px;
+              }
+              
+              #entry {
+                color: #ebdbb2;
+                padding: 4px;
+              }
+              
+              #entry:hover {
+                background-color: #242424;
+              }
+              
+              #entry:selected {
+                background-color: #fe8019;
+                color: #282828;
+              }
+            '';
+          };
+        rofi = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/rofi/themes/gruvbox-dark.rasi".text = ''
+              /* Rofi theme: gruvbox-dark */
+              /* Generated by themectl */
+              /* Use it from ~/.config/rofi/config.rasi:
+               *   @theme "~/.config/rofi/themes/gruvbox-dark.rasi"
+               */
+              
+              * {
+                  bg: #282828;
+                  bg-alt: #323232;
+                  fg: #ebdbb2;
+                  fg-dim: #8d836a;
+                  accent: #fe8019;
+                  urgent: #cc241d;
+                  active: #98971a;
+                  background-color: transparent;
+                  text-color: @fg;
+              }
+              
+              window {
+                  background-color: @bg;
+                  border: 2px;
+                  border-color: @accent;
+                  border-radius: 0px;
+              }
+              
+              mainbox {
+                  spacing: 4px;
+                  padding: 4px;
+              }
+              
+              inputbar {
+                  background-color: @bg-alt;
+                  padding: 4px;
+                  spacing: 4px;
+                  border-radius: 0px;
+                  children: [ prompt, entry ];
+              }
+              
+              prompt {
+                  text-color: @accent;
+              }
+              
+              entry {
+                  placeholder-color: @fg-dim;
+              }
+              
+              listview {
+                  spacing: 2px;
+                  scrollbar: false;
+              }
+              
+              element {
+                  padding: 2px;
+                  spacing: 4px;
+                  border-radius: 0px;
+              }
+              
+              element normal.normal {
+                  background-color: @bg;
+                  text-color: @fg;
+              }
+              
+              element normal.urgent {
+                  background-color: @bg;
+                  text-color: @urgent;
+              }
+              
+              element normal.active {
+                  background-color: @bg;
+                  text-color: @active;
+              }
+              
+              element alternate.normal {
+                  background-color: @bg;
+                  text-color: @fg;
+              }
+              
+              element alternate.urgent {
+                  background-color: @bg;
+                  text-color: @urgent;
+              }
+              
+              element alternate.active {
+                  background-color: @bg;
+                  text-color: @active;
+              }
+              
+              element selected.normal {
+                  background-color: @accent;
+                  text-color: @bg;
+              }
+              
+              element selected.urgent {
+                  background-color: @urgent;
+                  text-color: @bg;
+              }
+              
+              element selected.active {
+                  background-color: @active;
+                  text-color: @bg;
+              }
+              
+              element-icon, element-text {
+                  background-color: inherit;
+                  text-color: inherit;
+              }
+              
+              message {
+                  background-color: @bg-alt;
+                  padding: 4px;
+                  border-radius: 0px;
+              }
+            '';
+          };
+        fuzzel = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/fuzzel/themes/gruvbox-dark.ini".text = ''
+              # Fuzzel theme: gruvbox-dark
+              # Generated by themectl
+              # Include it from ~/.config/fuzzel/fuzzel.ini:
+              #   [main]
+              #   include=~/.config/fuzzel/themes/gruvbox-dark.ini
+              
+              [colors]
+              background=282828ff
+              text=ebdbb2ff
+              prompt=fe8019ff
+              placeholder=8d836aff
+              input=ebdbb2ff
+              match=fe8019ff
+              selection=fe8019ff
+              selection-text=282828ff
+              selection-match=282828ff
+              counter=8d836aff
+              border=fe8019ff
+              
+              [border]
+              width=2
+              radius=0
+            '';
+          };
+        tofi = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/tofi/themes/gruvbox-dark".text = ''
+              # Tofi theme: gruvbox-dark
+              # Generated by themectl
+              # Include it from ~/.config/tofi/config:
+              #   include = themes/gruvbox-dark
+              
+              background-color = #282828
+              text-color = #ebdbb2
+              prompt-color = #fe8019
+              placeholder-color = #8d836a
+              input-color = #ebdbb2
+              default-result-color = #ebdbb2
+              selection-color = #282828
+              selection-background = #fe8019
+              selection-match-color = #282828
+              border-color = #fe8019
+              outline-color = #282828
+              
+              border-width = 2
+              outline-width = 0
+              corner-radius = 0
+              selection-background-corner-radius = 0
+              selection-background-padding = 2
+              result-spacing = 4
+              padding-top = 8
+              padding-bottom = 8
+              padding-left = 8
+              padding-right = 8
+            '';
+          };
+        wlogout = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/wlogout/style.css".text = ''
+              # Wlogout theme: gruvbox-dark
+              # Generated by themectl
+              
+              window {
+                background-color: #282828;
+                border: 2px solid #fe8019;
+              }
+              
+              button {
+                color: #ebdbb2;
+                background-color: #282828;
+                border: 1px solid #cb6614;
+                padding: 4px;
+              }
+              
+              button:hover {
+                background-color: #fe8019;
+                color: #282828;
+              }
+            '';
+          };
+        fastfetch = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/fastfetch/config.jsonc".text = ''
+              # Fastfetch theme: gruvbox-dark
+              # Generated by themectl
+              
+              colorTitle="#d65d0e"
+              colorDefault="#ebdbb2"
+              colorSeparator="#689d6a"
+              colorKeys="#b16286"
+            '';
+          };
+        yazi = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/yazi/yazi.toml".text = ''
+              # Yazi theme: gruvbox-dark
+              # Generated by themectl
+              
+              [manager]
+              # Cwd (current working directory)
+              cwd = ["#ebdbb2"]
+              
+              # Hovered
+              hovered = ["#d65d0e", "#282828"]
+              
+              # Find (search)
+              find_keyword = ["#d79921", "bold"]
+              find_position = ["#fe8019", "bold", "underline"]
+              
+              # Marker (selection)
+              marker_copied = ["#98971a", "#282828"]
+              marker_cut = ["#cc241d", "#282828"]
+              marker_selected = ["#b16286", "#282828"]
+              
+              [manager.tab]
+              # Active tab
+              active = ["#282828", "#fe8019"]
+              # Inactive tab
+              inactive = ["#8d836a", "#282828"]
+              
+              [status]
+              # Status bar background
+              separator_open = ["#fe8019", "#282828"]
+              separator_close = ["#fe8019", "#282828"]
+              # Status bar text
+              code = ["#ebdbb2", "#282828"]
+              # Status bar info
+              info = ["#689d6a", "#282828"]
+              # Status bar warning
+              warn = ["#d79921", "#282828"]
+              # Status bar error
+              error = ["#cc241d", "#282828"]
+              
+              [tasks]
+              # Task bar background
+              border = "#fe8019"
+              # Task bar title
+              title = "#ebdbb2"
+              # Task bar percentage
+              percentage = "#689d6a"
+              # Task bar partial
+              partial = "#fe8019"
+              
+              [notify]
+              # Notification title
+              title = "#ebdbb2"
+              # Notification info
+              info = "#689d6a"
+              # Notification warning
+              warn = "#d79921"
+              # Notification error
+              error = "#cc241d"
+              
+              [input]
+              # Input border
+              border = "#fe8019"
+              # Input title
+              title = "#ebdbb2"
+              # Input value
+              value = "#ebdbb2"
+              # Input selected
+              selected = ["#fe8019", "reverse"]
+              
+              [select]
+              # Select border
+              border = "#fe8019"
+              # Select active
+              active = ["#fe8019", "#282828"]
+              # Select inactive
+              inactive = ["#ebdbb2", "#282828"]
+            '';
+          };
+        lscolors = { config, lib, pkgs, ... }:
+          {
+            programs.dircolors = {
+              enable = true;
+              settings = {
+                DIR = "01;38;2;69;133;136";
+                LINK = "38;2;104;157;106";
+                MULTIHARDLINK = "04;38;2;235;219;178";
+                FIFO = "38;2;215;153;33";
+                SOCK = "38;2;177;98;134";
+                DOOR = "38;2;215;153;33";
+                BLK = "01;38;2;215;153;33";
+                CHR = "01;38;2;215;153;33";
+                ORPHAN = "01;38;2;204;36;29";
+                MISSING = "01;38;2;204;36;29";
+                SETUID = "38;2;40;40;40;48;2;204;36;29";
+                SETGID = "38;2;40;40;40;48;2;215;153;33";
+                CAPABILITY = "38;2;40;40;40;48;2;177;98;134";
+                STICKY_OTHER_WRITABLE = "38;2;40;40;40;48;2;152;151;26";
+                OTHER_WRITABLE = "38;2;69;133;136;48;2;152;151;26";
+                STICKY = "38;2;40;40;40;48;2;69;133;136";
+                EXEC = "01;38;2;152;151;26";
+                ".tar" = "01;38;2;204;36;29";
+                ".tgz" = "01;38;2;204;36;29";
+                ".tbz2" = "01;38;2;204;36;29";
+                ".txz" = "01;38;2;204;36;29";
+                ".zip" = "01;38;2;204;36;29";
+                ".7z" = "01;38;2;204;36;29";
+                ".rar" = "01;38;2;204;36;29";
+                ".gz" = "01;38;2;204;36;29";
+                ".bz2" = "01;38;2;204;36;29";
+                ".xz" = "01;38;2;204;36;29";
+                ".zst" = "01;38;2;204;36;29";
+                ".lz4" = "01;38;2;204;36;29";
+                ".lzma" = "01;38;2;204;36;29";
+                ".deb" = "01;38;2;204;36;29";
+                ".rpm" = "01;38;2;204;36;29";
+                ".jar" = "01;38;2;204;36;29";
+                ".apk" = "01;38;2;204;36;29";
+                ".dmg" = "01;38;2;204;36;29";
+                ".iso" = "01;38;2;204;36;29";
+                ".jpg" = "38;2;177;98;134";
+                ".jpeg" = "38;2;177;98;134";
+                ".png" = "38;2;177;98;134";
+                ".gif" = "38;2;177;98;134";
+                ".bmp" = "38;2;177;98;134";
+                ".svg" = "38;2;177;98;134";
+                ".webp" = "38;2;177;98;134";
+                ".tif" = "38;2;177;98;134";
+                ".tiff" = "38;2;177;98;134";
+                ".ico" = "38;2;177;98;134";
+                ".heic" = "38;2;177;98;134";
+                ".avif" = "38;2;177;98;134";
+                ".xcf" = "38;2;177;98;134";
+                ".psd" = "38;2;177;98;134";
+                ".mp3" = "38;2;104;157;106";
+                ".flac" = "38;2;104;157;106";
+                ".ogg" = "38;2;104;157;106";
+                ".opus" = "38;2;104;157;106";
+                ".wav" = "38;2;104;157;106";
+                ".m4a" = "38;2;104;157;106";
+                ".aac" = "38;2;104;157;106";
+                ".mid" = "38;2;104;157;106";
+                ".mp4" = "38;2;177;98;134";
+                ".mkv" = "38;2;177;98;134";
+                ".webm" = "38;2;177;98;134";
+                ".avi" = "38;2;177;98;134";
+                ".mov" = "38;2;177;98;134";
+                ".flv" = "38;2;177;98;134";
+                ".wmv" = "38;2;177;98;134";
+                ".m4v" = "38;2;177;98;134";
+                ".pdf" = "38;2;215;153;33";
+                ".epub" = "38;2;215;153;33";
+                ".djvu" = "38;2;215;153;33";
+                ".doc" = "38;2;215;153;33";
+                ".docx" = "38;2;215;153;33";
+                ".odt" = "38;2;215;153;33";
+                ".xls" = "38;2;215;153;33";
+                ".xlsx" = "38;2;215;153;33";
+                ".ods" = "38;2;215;153;33";
+                ".ppt" = "38;2;215;153;33";
+                ".pptx" = "38;2;215;153;33";
+                ".md" = "38;2;215;153;33";
+                ".rst" = "38;2;215;153;33";
+                ".tex" = "38;2;215;153;33";
+                ".json" = "38;2;214;93;14";
+                ".yaml" = "38;2;214;93;14";
+                ".yml" = "38;2;214;93;14";
+                ".toml" = "38;2;214;93;14";
+                ".ini" = "38;2;214;93;14";
+                ".conf" = "38;2;214;93;14";
+                ".xml" = "38;2;214;93;14";
+                ".csv" = "38;2;214;93;14";
+                ".nix" = "38;2;214;93;14";
+                ".lock" = "38;2;214;93;14";
+                ".rs" = "38;2;254;128;25";
+                ".c" = "38;2;254;128;25";
+                ".h" = "38;2;254;128;25";
+                ".cpp" = "38;2;254;128;25";
+                ".hpp" = "38;2;254;128;25";
+                ".cc" = "38;2;254;128;25";
+                ".go" = "38;2;254;128;25";
+                ".zig" = "38;2;254;128;25";
+                ".java" = "38;2;254;128;25";
+                ".kt" = "38;2;254;128;25";
+                ".swift" = "38;2;254;128;25";
+                ".hs" = "38;2;254;128;25";
+                ".py" = "38;2;152;151;26";
+                ".rb" = "38;2;152;151;26";
+                ".pl" = "38;2;152;151;26";
+                ".lua" = "38;2;152;151;26";
+                ".sh" = "38;2;152;151;26";
+                ".bash" = "38;2;152;151;26";
+                ".zsh" = "38;2;152;151;26";
+                ".fish" = "38;2;152;151;26";
+                ".js" = "38;2;152;151;26";
+                ".ts" = "38;2;152;151;26";
+                ".jsx" = "38;2;152;151;26";
+                ".tsx" = "38;2;152;151;26";
+                ".html" = "38;2;69;133;136";
+                ".htm" = "38;2;69;133;136";
+                ".css" = "38;2;69;133;136";
+                ".scss" = "38;2;69;133;136";
+                ".sass" = "38;2;69;133;136";
+                ".vue" = "38;2;69;133;136";
+                ".svelte" = "38;2;69;133;136";
+                ".bak" = "38;2;141;131;106";
+                ".tmp" = "38;2;141;131;106";
+                ".swp" = "38;2;141;131;106";
+                ".old" = "38;2;141;131;106";
+                ".orig" = "38;2;141;131;106";
+                ".log" = "38;2;141;131;106";
+              };
+            };
+
+            home.sessionVariables.EZA_COLORS = "ur=38;2;215;153;33:uw=38;2;204;36;29:ux=01;38;2;152;151;26:ue=38;2;152;151;26:gr=38;2;215;153;33:gw=38;2;204;36;29:gx=38;2;152;151;26:tr=38;2;215;153;33:tw=38;2;204;36;29:tx=38;2;152;151;26:sn=38;2;152;151;26:sb=38;2;141;131;106:uu=38;2;215;153;33:un=38;2;141;131;106:gu=38;2;215;153;33:gn=38;2;141;131;106:da=38;2;69;133;136:lp=38;2;104;157;106:hd=04;38;2;235;219;178:ga=38;2;152;151;26:gm=38;2;215;153;33:gd=38;2;204;36;29:gv=38;2;177;98;134:gt=38;2;104;157;106";
+          };
+        fzf = { config, lib, pkgs, ... }:
+          {
+            programs.fzf = {
+              enable = true;
+              colors = {
+                "fg" = "#ebdbb2";
+                "bg" = "#282828";
+                "hl" = "#fe8019";
+                "fg+" = "#ebdbb2";
+                "bg+" = "#3c3a36";
+                "hl+" = "#fe8019";
+                "info" = "#689d6a";
+                "prompt" = "#458588";
+                "pointer" = "#fe8019";
+                "marker" = "#98971a";
+                "spinner" = "#b16286";
+                "border" = "#8d836a";
+              };
+            };
+          };
+        lazygit = { config, lib, pkgs, ... }:
+          {
+            programs.lazygit = {
+              enable = true;
+              settings.gui.theme = {
+                activeBorderColor = [ "#fe8019" "bold" ];
+                inactiveBorderColor = [ "#8d836a" ];
+                searchingActiveBorderColor = [ "#d79921" "bold" ];
+                optionsTextColor = [ "#458588" ];
+                selectedLineBgColor = [ "#3c3a36" ];
+                inactiveViewSelectedLineBgColor = [ "#32312f" ];
+                cherryPickedCommitFgColor = [ "#282828" ];
+                cherryPickedCommitBgColor = [ "#689d6a" ];
+                markedBaseCommitFgColor = [ "#282828" ];
+                markedBaseCommitBgColor = [ "#d79921" ];
+                unstagedChangesColor = [ "#cc241d" ];
+                defaultFgColor = [ "#ebdbb2" ];
+              };
+            };
+          };
+        fish = { config, lib, pkgs, ... }:
+          {
+            programs.fish = {
+              enable = true;
+              interactiveShellInit = ''
+                set -g fish_color_normal ebdbb2
+                set -g fish_color_command 689d6a
+                set -g fish_color_keyword 458588
+                set -g fish_color_quote 98971a
+                set -g fish_color_redirection ebdbb2
+                set -g fish_color_end ebdbb2
+                set -g fish_color_error cc241d
+                set -g fish_color_param ebdbb2
+                set -g fish_color_valid_path --underline
+                set -g fish_color_option 458588
+                set -g fish_color_comment 8d836a
+                set -g fish_color_operator 689d6a
+                set -g fish_color_escape 689d6a
+                set -g fish_color_autosuggestion 8d836a
+                set -g fish_color_selection --background=45433d
+                set -g fish_color_search_match --background=45433d
+                set -g fish_color_history_current --bold
+                set -g fish_color_cancel -r cc241d
+                set -g fish_color_host 98971a
+                set -g fish_color_host_remote d79921
+                set -g fish_color_user 458588
+                set -g fish_color_cwd fe8019
+                set -g fish_color_cwd_root cc241d
+                set -g fish_color_status cc241d
+                set -g fish_pager_color_progress fe8019
+                set -g fish_pager_color_prefix fe8019 --bold
+                set -g fish_pager_color_completion ebdbb2
+                set -g fish_pager_color_description 8d836a
+                set -g fish_pager_color_selected_background --background=45433d
+              '';
+            };
+          };
+        zsh = { config, lib, pkgs, ... }:
+          {
+            programs.zsh = {
+              enable = true;
+              syntaxHighlighting = {
+                enable = true;
+                styles = {
+                  "default" = "fg=#ebdbb2";
+                  "unknown-token" = "fg=#cc241d,bold";
+                  "reserved-word" = "fg=#458588";
+                  "alias" = "fg=#689d6a";
+                  "suffix-alias" = "fg=#689d6a";
+                  "global-alias" = "fg=#689d6a";
+                  "builtin" = "fg=#689d6a";
+                  "function" = "fg=#689d6a";
+                  "command" = "fg=#689d6a";
+                  "precommand" = "fg=#689d6a,underline";
+                  "hashed-command" = "fg=#689d6a";
+                  "commandseparator" = "fg=#ebdbb2";
+                  "autodirectory" = "fg=#458588,underline";
+                  "path" = "underline";
+                  "path_pathseparator" = "fg=#8d836a,underline";
+                  "path_prefix" = "underline";
+                  "globbing" = "fg=#689d6a";
+                  "history-expansion" = "fg=#689d6a";
+                  "command-substitution-delimiter" = "fg=#8d836a";
+                  "process-substitution-delimiter" = "fg=#8d836a";
+                  "back-quoted-argument-delimiter" = "fg=#8d836a";
+                  "single-hyphen-option" = "fg=#458588";
+                  "double-hyphen-option" = "fg=#458588";
+                  "single-quoted-argument" = "fg=#98971a";
+                  "double-quoted-argument" = "fg=#98971a";
+                  "dollar-quoted-argument" = "fg=#98971a";
+                  "dollar-double-quoted-argument" = "fg=#ebdbb2";
+                  "back-double-quoted-argument" = "fg=#689d6a";
+                  "back-dollar-quoted-argument" = "fg=#689d6a";
+                  "assign" = "fg=#ebdbb2";
+                  "redirection" = "fg=#ebdbb2";
+                  "comment" = "fg=#8d836a";
+                  "named-fd" = "fg=#b16286";
+                  "numeric-fd" = "fg=#b16286";
+                  "arg0" = "fg=#689d6a";
+                };
+              };
+            };
+          };
+        gtk = { config, lib, pkgs, ... }:
+          {
+            home.file.".config/gtk-4.0/settings.ini".text = ''
+              # GTK theme configuration: gruvbox-dark
+              # Place this file at: ~/.config/gtk-4.0/settings.ini
+              
+              [Settings]
+              gtk-application-prefer-dark-theme=true
+              gtk-interface-color-scheme=dark
+              gtk-theme-name=Adwaita-dark
+              gtk-icon-theme-name=Adwaita
+              gtk-cursor-theme-name=Adwaita
+              gtk-cursor-theme-size=24
+              
+              # Theme: gruvbox-dark - Gruvbox dark theme
+            '';
+
+            home.file.".config/gtk-3.0/settings.ini".text = ''
+              # GTK theme configuration: gruvbox-dark
+              # Place this file at: ~/.config/gtk-3.0/settings.ini
+              
+              [Settings]
+              gtk-application-prefer-dark-theme=true
+              gtk-theme-name=Adwaita-dark
+              gtk-icon-theme-name=Adwaita
+              gtk-cursor-theme-name=Adwaita
+              gtk-cursor-theme-size=24
+              
+              # Theme: gruvbox-dark - Gruvbox dark theme
+            '';
+
+            home.file.".config/gtk-3.0/gtk.css".text = ''
+              /* GTK CSS theme: gruvbox-dark */
+              /* Place this file at: ~/.config/gtk-3.0/gtk.css and ~/.config/gtk-4.0/gtk.css */
+              
+              @define-color theme_bg_color #282828;
+              @define-color theme_fg_color #ebdbb2;
+              @define-color theme_base_color #222222;
+              @define-color theme_text_color #ebdbb2;
+              @define-color theme_selected_bg_color #fe8019;
+              @define-color theme_selected_fg_color #282828;
+              @define-color theme_unfocused_bg_color #282828;
+              @define-color theme_unfocused_fg_color #8d836a;
+              @define-color insensitive_fg_color #8d836a;
+              @define-color borders alpha(#ebdbb2, 0.15);
+              @define-color theme_hover_bg_color #3d3d3d;
+              @define-color theme_active_bg_color #242424;
+              @define-color accent_hover_color #fe8c30;
+              
+              @define-color accent_color #fe8019;
+              @define-color accent_bg_color #fe8019;
+              @define-color accent_fg_color #282828;
+              @define-color destructive_color #cc241d;
+              @define-color destructive_bg_color #cc241d;
+              @define-color destructive_fg_color #282828;
+              @define-color success_color #98971a;
+              @define-color success_bg_color #98971a;
+              @define-color success_fg_color #282828;
+              @define-color warning_color #d79921;
+              @define-color warning_bg_color #d79921;
+              @define-color warning_fg_color #282828;
+              @define-color error_color #cc241d;
+              @define-color error_bg_color #cc241d;
+              @define-color error_fg_color #282828;
+              @define-color window_bg_color #282828;
+              @define-color window_fg_color #ebdbb2;
+              @define-color view_bg_color #222222;
+              @define-color view_fg_color #ebdbb2;
+              @define-color headerbar_bg_color #393939;
+              @define-color headerbar_fg_color #ebdbb2;
+              @define-color headerbar_border_color #ebdbb2;
+              @define-color headerbar_backdrop_color #282828;
+              @define-color headerbar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color headerbar_darker_shade_color rgba(0, 0, 0, 0.9);
+              @define-color sidebar_bg_color #393939;
+              @define-color sidebar_fg_color #ebdbb2;
+              @define-color sidebar_backdrop_color #282828;
+              @define-color sidebar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color sidebar_border_color alpha(#ebdbb2, 0.15);
+              @define-color secondary_sidebar_bg_color #303030;
+              @define-color secondary_sidebar_fg_color #ebdbb2;
+              @define-color secondary_sidebar_backdrop_color #282828;
+              @define-color secondary_sidebar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color secondary_sidebar_border_color alpha(#ebdbb2, 0.15);
+              @define-color card_bg_color #343434;
+              @define-color card_fg_color #ebdbb2;
+              @define-color card_shade_color rgba(0, 0, 0, 0.36);
+              @define-color dialog_bg_color #414141;
+              @define-color dialog_fg_color #ebdbb2;
+              @define-color popover_bg_color #414141;
+              @define-color popover_fg_color #ebdbb2;
+              @define-color popover_shade_color rgba(0, 0, 0, 0.36);
+              @define-color thumbnail_bg_color #414141;
+              @define-color thumbnail_fg_color #ebdbb2;
+              @define-color shade_color rgba(0, 0, 0, 0.36);
+              @define-color scrollbar_outline_color rgba(0, 0, 0, 0.5);
+              
+              window {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+              }
+              
+              button {
+                border-radius: 0px;
+                border-width: 2px;
+                border-color: alpha(@theme_fg_color, 0.2);
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                transition: all 200ms ease-in-out;
+              }
+              
+              button:hover {
+                background-color: @theme_hover_bg_color;
+                border-color: @accent_color;
+              }
+              
+              button:active {
+                background-color: @theme_active_bg_color;
+              }
+              
+              button:checked {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              entry {
+                border-radius: 0px;
+                border-width: 2px;
+                border-color: alpha(@theme_fg_color, 0.3);
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                padding: 6px 12px;
+              }
+              
+              entry:focus {
+                border-color: @accent_color;
+                box-shadow: 0 0 0 5px alpha(@accent_color, 0.2);
+              }
+              
+              notebook > header > tabs > tab {
+                border-radius: 0px 0px 0 0;
+                background-color: alpha(@theme_fg_color, 0.1);
+                color: @theme_fg_color;
+                padding: 6px 12px;
+              }
+              
+              notebook > header > tabs > tab:checked {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              progressbar > trough {
+                border-radius: 0px;
+                background-color: alpha(@theme_fg_color, 0.1);
+                min-height: 4px;
+              }
+              
+              progressbar > trough > progress {
+                border-radius: 0px;
+                background-color: @accent_color;
+              }
+              
+              scrollbar > trough {
+                background-color: alpha(@theme_fg_color, 0.05);
+                min-width: 12px;
+                min-height: 12px;
+              }
+              
+              scrollbar > trough > slider {
+                border-radius: 0px;
+                background-color: alpha(@theme_fg_color, 0.3);
+                min-width: 8px;
+                min-height: 8px;
+              }
+              
+              scrollbar > trough > slider:hover {
+                background-color: alpha(@theme_fg_color, 0.5);
+              }
+              
+              menu {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                border-radius: 0px;
+                box-shadow: 0 2px 10px alpha(@theme_fg_color, 0.2);
+              }
+              
+              menuitem {
+                padding: 6px 12px;
+                border-radius: 0px;
+              }
+              
+              menuitem:hover {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              tooltip {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                border-radius: 0px;
+                border: 1px solid alpha(@theme_fg_color, 0.2);
+                box-shadow: 0 2px 10px alpha(@theme_fg_color, 0.3);
+                padding: 6px 12px;
+              }
+              
+              .error {
+                background-color: alpha(@error_color, 0.1);
+                color: @error_color;
+                border: 1px solid @error_color;
+              }
+              
+              .warning {
+                background-color: alpha(@warning_color, 0.1);
+                color: @warning_color;
+                border: 1px solid @warning_color;
+              }
+              
+              .success {
+                background-color: alpha(@success_color, 0.1);
+                color: @success_color;
+                border: 1px solid @success_color;
+              }
+            '';
+
+            home.file.".config/gtk-4.0/gtk.css".text = ''
+              /* GTK CSS theme: gruvbox-dark */
+              /* Place this file at: ~/.config/gtk-3.0/gtk.css and ~/.config/gtk-4.0/gtk.css */
+              
+              @define-color theme_bg_color #282828;
+              @define-color theme_fg_color #ebdbb2;
+              @define-color theme_base_color #222222;
+              @define-color theme_text_color #ebdbb2;
+              @define-color theme_selected_bg_color #fe8019;
+              @define-color theme_selected_fg_color #282828;
+              @define-color theme_unfocused_bg_color #282828;
+              @define-color theme_unfocused_fg_color #8d836a;
+              @define-color insensitive_fg_color #8d836a;
+              @define-color borders alpha(#ebdbb2, 0.15);
+              @define-color theme_hover_bg_color #3d3d3d;
+              @define-color theme_active_bg_color #242424;
+              @define-color accent_hover_color #fe8c30;
+              
+              @define-color accent_color #fe8019;
+              @define-color accent_bg_color #fe8019;
+              @define-color accent_fg_color #282828;
+              @define-color destructive_color #cc241d;
+              @define-color destructive_bg_color #cc241d;
+              @define-color destructive_fg_color #282828;
+              @define-color success_color #98971a;
+              @define-color success_bg_color #98971a;
+              @define-color success_fg_color #282828;
+              @define-color warning_color #d79921;
+              @define-color warning_bg_color #d79921;
+              @define-color warning_fg_color #282828;
+              @define-color error_color #cc241d;
+              @define-color error_bg_color #cc241d;
+              @define-color error_fg_color #282828;
+              @define-color window_bg_color #282828;
+              @define-color window_fg_color #ebdbb2;
+              @define-color view_bg_color #222222;
+              @define-color view_fg_color #ebdbb2;
+              @define-color headerbar_bg_color #393939;
+              @define-color headerbar_fg_color #ebdbb2;
+              @define-color headerbar_border_color #ebdbb2;
+              @define-color headerbar_backdrop_color #282828;
+              @define-color headerbar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color headerbar_darker_shade_color rgba(0, 0, 0, 0.9);
+              @define-color sidebar_bg_color #393939;
+              @define-color sidebar_fg_color #ebdbb2;
+              @define-color sidebar_backdrop_color #282828;
+              @define-color sidebar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color sidebar_border_color alpha(#ebdbb2, 0.15);
+              @define-color secondary_sidebar_bg_color #303030;
+              @define-color secondary_sidebar_fg_color #ebdbb2;
+              @define-color secondary_sidebar_backdrop_color #282828;
+              @define-color secondary_sidebar_shade_color rgba(0, 0, 0, 0.36);
+              @define-color secondary_sidebar_border_color alpha(#ebdbb2, 0.15);
+              @define-color card_bg_color #343434;
+              @define-color card_fg_color #ebdbb2;
+              @define-color card_shade_color rgba(0, 0, 0, 0.36);
+              @define-color dialog_bg_color #414141;
+              @define-color dialog_fg_color #ebdbb2;
+              @define-color popover_bg_color #414141;
+              @define-color popover_fg_color #ebdbb2;
+              @define-color popover_shade_color rgba(0, 0, 0, 0.36);
+              @define-color thumbnail_bg_color #414141;
+              @define-color thumbnail_fg_color #ebdbb2;
+              @define-color shade_color rgba(0, 0, 0, 0.36);
+              @define-color scrollbar_outline_color rgba(0, 0, 0, 0.5);
+              
+              window {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+              }
+              
+              button {
+                border-radius: 0px;
+                border-width: 2px;
+                border-color: alpha(@theme_fg_color, 0.2);
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                transition: all 200ms ease-in-out;
+              }
+              
+              button:hover {
+                background-color: @theme_hover_bg_color;
+                border-color: @accent_color;
+              }
+              
+              button:active {
+                background-color: @theme_active_bg_color;
+              }
+              
+              button:checked {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              entry {
+                border-radius: 0px;
+                border-width: 2px;
+                border-color: alpha(@theme_fg_color, 0.3);
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                padding: 6px 12px;
+              }
+              
+              entry:focus {
+                border-color: @accent_color;
+                box-shadow: 0 0 0 5px alpha(@accent_color, 0.2);
+              }
+              
+              notebook > header > tabs > tab {
+                border-radius: 0px 0px 0 0;
+                background-color: alpha(@theme_fg_color, 0.1);
+                color: @theme_fg_color;
+                padding: 6px 12px;
+              }
+              
+              notebook > header > tabs > tab:checked {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              progressbar > trough {
+                border-radius: 0px;
+                background-color: alpha(@theme_fg_color, 0.1);
+                min-height: 4px;
+              }
+              
+              progressbar > trough > progress {
+                border-radius: 0px;
+                background-color: @accent_color;
+              }
+              
+              scrollbar > trough {
+                background-color: alpha(@theme_fg_color, 0.05);
+                min-width: 12px;
+                min-height: 12px;
+              }
+              
+              scrollbar > trough > slider {
+                border-radius: 0px;
+                background-color: alpha(@theme_fg_color, 0.3);
+                min-width: 8px;
+                min-height: 8px;
+              }
+              
+              scrollbar > trough > slider:hover {
+                background-color: alpha(@theme_fg_color, 0.5);
+              }
+              
+              menu {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                border-radius: 0px;
+                box-shadow: 0 2px 10px alpha(@theme_fg_color, 0.2);
+              }
+              
+              menuitem {
+                padding: 6px 12px;
+                border-radius: 0px;
+              }
+              
+              menuitem:hover {
+                background-color: @accent_color;
+                color: @theme_selected_fg_color;
+              }
+              
+              tooltip {
+                background-color: @theme_bg_color;
+                color: @theme_fg_color;
+                border-radius: 0px;
+                border: 1px solid alpha(@theme_fg_color, 0.2);
+                box-shadow: 0 2px 10px alpha(@theme_fg_color, 0.3);
+                padding: 6px 12px;
+              }
+              
+              .error {
+                background-color: alpha(@error_color, 0.1);
+                color: @error_color;
+                border: 1px solid @error_color;
+              }
+              
+              .warning {
+                background-color: alpha(@warning_color, 0.1);
+                color: @warning_color;
+                border: 1px solid @warning_color;
+              }
+              
+              .success {
+                background-color: alpha(@success_color, 0.1);
+                color: @success_color;
+                border: 1px solid @success_color;
+              }
+            '';
+
+            dconf.settings."org/gnome/desktop/interface".color-scheme = "prefer-dark";
+          };
+        qt = { config, lib, pkgs, ... }:
+          {
+            qt = {
+              enable = true;
+              platformTheme.name = "qtct";
+            };
+
+            xdg.configFile."qt5ct/colors/gruvbox-dark.conf".text = ''
+              # Qt color scheme: gruvbox-dark
+              # Generated by themectl
+              # Used by qt5ct/qt6ct via color_scheme_path in qt5ct.conf/qt6ct.conf
+              
+              [ColorScheme]
+              active_colors=#ffebdbb2, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ffebdbb2, #ffefe2c1, #ffebdbb2, #ff282828, #ff282828, #ff0f0f0f, #fffe8019, #ff282828, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+              disabled_colors=#ff8d836a, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ff8d836a, #ff8d836a, #ff8d836a, #ff282828, #ff282828, #ff0f0f0f, #ff393939, #ff8d836a, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+              inactive_colors=#ffebdbb2, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ffebdbb2, #ffefe2c1, #ffebdbb2, #ff282828, #ff282828, #ff0f0f0f, #fffe8019, #ff282828, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+            '';
+
+            xdg.configFile."qt5ct/qt5ct.conf".text = ''
+              [Appearance]
+              color_scheme_path=${config.xdg.configHome}/qt5ct/colors/gruvbox-dark.conf
+              custom_palette=true
+            '';
+
+            xdg.configFile."qt6ct/colors/gruvbox-dark.conf".text = ''
+              # Qt color scheme: gruvbox-dark
+              # Generated by themectl
+              # Used by qt5ct/qt6ct via color_scheme_path in qt5ct.conf/qt6ct.conf
+              
+              [ColorScheme]
+              active_colors=#ffebdbb2, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ffebdbb2, #ffefe2c1, #ffebdbb2, #ff282828, #ff282828, #ff0f0f0f, #fffe8019, #ff282828, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+              disabled_colors=#ff8d836a, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ff8d836a, #ff8d836a, #ff8d836a, #ff282828, #ff282828, #ff0f0f0f, #ff393939, #ff8d836a, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+              inactive_colors=#ffebdbb2, #ff393939, #ff535353, #ff414141, #ff1c1c1c, #ff222222, #ffebdbb2, #ffefe2c1, #ffebdbb2, #ff282828, #ff282828, #ff0f0f0f, #fffe8019, #ff282828, #ff458588, #ffb16286, #ff393939, #ff282828, #ff393939, #ffebdbb2, #ff8d836a
+            '';
+
+            xdg.configFile."qt6ct/qt6ct.conf".text = ''
+              [Appearance]
+              color_scheme_path=${config.xdg.configHome}/qt6ct/colors/gruvbox-dark.conf
+              custom_palette=true
+            '';
+          };
+        emacs = { config, lib, pkgs, ... }:
+          {
+            xdg.configFile."emacs/themes/gruvbox-dark-theme.el".text = ''
+              ;;; gruvbox-dark-theme.el --- gruvbox-dark theme -*- lexical-binding: t; -*-
+              ;; Generated by themectl
+              ;; Load with:
+              ;;   (add-to-list 'custom-theme-load-path "~/.config/emacs/themes/")
+              ;;   (load-theme 'gruvbox-dark t)
+              
+              (deftheme gruvbox-dark "Generated by themectl.")
+              
+              (custom-theme-set-faces
+               'gruvbox-dark
+               '(default ((t (:foreground "#ebdbb2" :background "#282828"))))
+               '(cursor ((t (:background "#fe8019"))))
+               '(fringe ((t (:background "#282828"))))
+               '(region ((t (:background "#684224" :extend t))))
+               '(hl-line ((t (:background "#3c3a36" :extend t))))
+               '(highlight ((t (:background "#533a25"))))
+               '(shadow ((t (:foreground "#8d836a"))))
+               '(minibuffer-prompt ((t (:foreground "#fe8019" :weight bold))))
+               '(link ((t (:foreground "#458588" :underline t))))
+               '(error ((t (:foreground "#cc241d" :weight bold))))
+               '(warning ((t (:foreground "#d79921" :weight bold))))
+               '(success ((t (:foreground "#98971a" :weight bold))))
+               '(line-number ((t (:foreground "#8d836a" :background "#282828"))))
+               '(line-number-current-line ((t (:foreground "#fe8019" :background "#3c3a36" :weight bold))))
+               '(vertical-border ((t (:foreground "#3c3a36"))))
+               '(window-divider ((t (:foreground "#3c3a36"))))
+               '(mode-line ((t (:foreground "#ebdbb2" :background "#3c3a36" :box (:line-width 1 :color "#fe8019")))))
+               '(mode-line-inactive ((t (:foreground "#8d836a" :background "#282828" :box (:line-width 1 :color "#3c3a36")))))
+               '(mode-line-buffer-id ((t (:foreground "#fe8019" :weight bold))))
+               '(header-line ((t (:foreground "#ebdbb2" :background "#3c3a36"))))
+               '(isearch ((t (:foreground "#282828" :background "#fe8019" :weight bold))))
+               '(lazy-highlight ((t (:foreground "#282828" :background "#d79921"))))
+               '(show-paren-match ((t (:foreground "#fe8019" :background "#3c3a36" :weight bold))))
+               '(show-paren-mismatch ((t (:foreground "#282828" :background "#cc241d"))))
+               '(trailing-whitespace ((t (:background "#6a2624"))))
+               '(font-lock-comment-face ((t (:foreground "#8d836a" :slant italic))))
+               '(font-lock-comment-delimiter-face ((t (:foreground "#8d836a" :slant italic))))
+               '(font-lock-doc-face ((t (:foreground "#8d836a" :slant italic))))
+               '(font-lock-string-face ((t (:foreground "#98971a"))))
+               '(font-lock-keyword-face ((t (:foreground "#458588"))))
+               '(font-lock-builtin-face ((t (:foreground "#689d6a"))))
+               '(font-lock-function-name-face ((t (:foreground "#689d6a"))))
+               '(font-lock-function-call-face ((t (:foreground "#689d6a"))))
+               '(font-lock-variable-name-face ((t (:foreground "#ebdbb2"))))
+               '(font-lock-variable-use-face ((t (:foreground "#ebdbb2"))))
+               '(font-lock-type-face ((t (:foreground "#d79921"))))
+               '(font-lock-constant-face ((t (:foreground "#b16286"))))
+               '(font-lock-number-face ((t (:foreground "#b16286"))))
+               '(font-lock-preprocessor-face ((t (:foreground "#b16286"))))
+               '(font-lock-escape-face ((t (:foreground "#689d6a"))))
+               '(font-lock-regexp-grouping-backslash ((t (:foreground "#689d6a"))))
+               '(font-lock-regexp-grouping-construct ((t (:foreground "#689d6a"))))
+               '(font-lock-negation-char-face ((t (:foreground "#ebdbb2"))))
+               '(font-lock-operator-face ((t (:foreground "#ebdbb2"))))
+               '(font-lock-property-name-face ((t (:foreground "#458588"))))
+               '(font-lock-property-use-face ((t (:foreground "#458588"))))
+               '(font-lock-punctuation-face ((t (:foreground "#8d836a"))))
+               '(font-lock-bracket-face ((t (:foreground "#8d836a"))))
+               '(font-lock-delimiter-face ((t (:foreground "#8d836a"))))
+               '(font-lock-warning-face ((t (:foreground "#d79921"))))
+               '(diff-added ((t (:foreground "#98971a" :background "#3e3e25" :extend t))))
+               '(diff-removed ((t (:foreground "#cc241d" :background "#492726" :extend t))))
+               '(diff-changed ((t (:foreground "#d79921" :background "#4b3f27" :extend t))))
+               '(diff-refine-added ((t (:background "#555422"))))
+               '(diff-refine-removed ((t (:background "#6a2624"))))
+               '(diff-refine-changed ((t (:background "#6e5525"))))
+               '(diff-header ((t (:foreground "#ebdbb2" :background "#3c3a36"))))
+               '(diff-file-header ((t (:foreground "#fe8019" :background "#3c3a36" :weight bold))))
+               '(diff-hunk-header ((t (:foreground "#458588" :background "#3c3a36"))))
+               '(magit-section-heading ((t (:foreground "#fe8019" :weight bold))))
+               '(magit-section-highlight ((t (:background "#3c3a36" :extend t))))
+               '(magit-branch-local ((t (:foreground "#458588"))))
+               '(magit-branch-remote ((t (:foreground "#98971a"))))
+               '(magit-branch-current ((t (:foreground "#458588" :box t))))
+               '(magit-hash ((t (:foreground "#8d836a"))))
+               '(magit-tag ((t (:foreground "#d79921"))))
+               '(magit-diff-context ((t (:foreground "#8d836a" :extend t))))
+               '(magit-diff-context-highlight ((t (:foreground "#ebdbb2" :background "#3c3a36" :extend t))))
+               '(magit-diff-added ((t (:foreground "#98971a" :background "#393926" :extend t))))
+               '(magit-diff-added-highlight ((t (:foreground "#98971a" :background "#444425" :extend t))))
+               '(magit-diff-removed ((t (:foreground "#cc241d" :background "#412726" :extend t))))
+               '(magit-diff-removed-highlight ((t (:foreground "#cc241d" :background "#512725" :extend t))))
+               '(magit-diff-hunk-heading ((t (:foreground "#8d836a" :background "#3c3a36" :extend t))))
+               '(magit-diff-hunk-heading-highlight ((t (:foreground "#282828" :background "#fe8019" :extend t))))
+               '(magit-diff-file-heading ((t (:foreground "#ebdbb2" :weight bold))))
+              )
+              
+              (custom-theme-set-variables
+               'gruvbox-dark
+               '(ansi-color-names-vector ["#282828" "#cc241d" "#98971a" "#d79921" "#458588" "#b16286" "#689d6a" "#ebdbb2"]))
+              
+              (provide-theme 'gruvbox-dark)
+              
+              ;;; gruvbox-dark-theme.el ends here
+            '';
+
+            programs.emacs.extraConfig = ''
+              (add-to-list 'custom-theme-load-path "${config.xdg.configHome}/emacs/themes/")
+              (load-theme 'gruvbox-dark t)
+            '';
+          };
+        vim = { config, lib, pkgs, ... }:
+          {
+            home.file.".vim/colors/gruvbox-dark.vim".text = ''
+              " Vim colorscheme: gruvbox-dark
+              " Generated by themectl
+              " Copy to ~/.vim/colors/ (Vim 9.1+ also reads ~/.config/vim/colors/), then run:
+              "   :colorscheme gruvbox-dark
+              
+              set background=dark
+              hi clear
+              if exists("syntax_on")
+                syntax reset
+              endif
+              let g:colors_name = "gruvbox-dark"
+              
+              let g:terminal_ansi_colors = ["#282828", "#cc241d", "#98971a", "#d79921", "#458588", "#b16286", "#689d6a", "#ebdbb2", "#3d3d3d", "#d64f4a", "#acab47", "#dfad4d", "#6a9d9f", "#c0819e", "#86b087", "#efe2c1"]
+              
+              " Editor
+              hi Normal guifg=#ebdbb2 ctermfg=187 guibg=#282828 ctermbg=235 gui=NONE cterm=NONE
+              hi Cursor guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi CursorLine guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi CursorColumn guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi ColorColumn guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi CursorLineNr guifg=#fe8019 ctermfg=208 gui=bold cterm=bold
+              hi LineNr guifg=#8d836a ctermfg=101 gui=NONE cterm=NONE
+              hi SignColumn guibg=#282828 ctermbg=235 gui=NONE cterm=NONE
+              hi FoldColumn guifg=#8d836a ctermfg=101 guibg=#282828 ctermbg=235 gui=NONE cterm=NONE
+              hi Folded guifg=#8d836a ctermfg=101 guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi VertSplit guifg=#8d836a ctermfg=101 guibg=#282828 ctermbg=235 gui=NONE cterm=NONE
+              hi NonText guifg=#8d836a ctermfg=101 gui=NONE cterm=NONE
+              hi SpecialKey guifg=#8d836a ctermfg=101 gui=NONE cterm=NONE
+              hi EndOfBuffer guifg=#282828 ctermfg=235 gui=NONE cterm=NONE
+              hi Directory guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Title guifg=#fe8019 ctermfg=208 gui=bold cterm=bold
+              hi Visual guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi MatchParen guifg=#fe8019 ctermfg=208 guibg=#3c3a36 ctermbg=237 gui=bold cterm=bold
+              hi Search guifg=#282828 ctermfg=235 guibg=#d79921 ctermbg=172 gui=NONE cterm=NONE
+              hi IncSearch guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi StatusLine guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi StatusLineNC guifg=#ebdbb2 ctermfg=187 guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi StatusLineTerm guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi StatusLineTermNC guifg=#ebdbb2 ctermfg=187 guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi TabLine guifg=#8d836a ctermfg=101 guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi TabLineSel guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi TabLineFill guibg=#282828 ctermbg=235 gui=NONE cterm=NONE
+              hi WildMenu guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi Pmenu guifg=#ebdbb2 ctermfg=187 guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi PmenuSel guifg=#282828 ctermfg=235 guibg=#fe8019 ctermbg=208 gui=NONE cterm=NONE
+              hi PmenuSbar guibg=#3c3a36 ctermbg=237 gui=NONE cterm=NONE
+              hi PmenuThumb guibg=#8d836a ctermbg=101 gui=NONE cterm=NONE
+              hi SpellBad guifg=#cc241d ctermfg=160 gui=undercurl cterm=underline
+              hi SpellCap guifg=#d79921 ctermfg=172 gui=undercurl cterm=underline
+              hi Underlined guifg=#458588 ctermfg=66 gui=underline cterm=underline
+              
+              " Syntax
+              hi Comment guifg=#8d836a ctermfg=101 gui=NONE cterm=NONE
+              hi String guifg=#98971a ctermfg=100 gui=NONE cterm=NONE
+              hi Character guifg=#98971a ctermfg=100 gui=NONE cterm=NONE
+              hi Number guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Float guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Boolean guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Constant guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Identifier guifg=#ebdbb2 ctermfg=187 gui=NONE cterm=NONE
+              hi Function guifg=#689d6a ctermfg=71 gui=NONE cterm=NONE
+              hi Statement guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Conditional guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Repeat guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Label guifg=#689d6a ctermfg=71 gui=NONE cterm=NONE
+              hi Operator guifg=#ebdbb2 ctermfg=187 gui=NONE cterm=NONE
+              hi Keyword guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Exception guifg=#cc241d ctermfg=160 gui=NONE cterm=NONE
+              hi PreProc guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Include guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Define guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Macro guifg=#b16286 ctermfg=132 gui=NONE cterm=NONE
+              hi Type guifg=#d79921 ctermfg=172 gui=NONE cterm=NONE
+              hi StorageClass guifg=#458588 ctermfg=66 gui=NONE cterm=NONE
+              hi Structure guifg=#d79921 ctermfg=172 gui=NONE cterm=NONE
+              hi Typedef guifg=#d79921 ctermfg=172 gui=NONE cterm=NONE
+              hi Special guifg=#689d6a ctermfg=71 gui=NONE cterm=NONE
+              hi SpecialChar guifg=#689d6a ctermfg=71 gui=NONE cterm=NONE
+              hi Delimiter guifg=#8d836a ctermfg=101 gui=NONE cterm=NONE
+              hi Error guifg=#cc241d ctermfg=160 gui=NONE cterm=NONE
+              hi ErrorMsg guifg=#cc241d ctermfg=160 gui=NONE cterm=NONE
+              hi WarningMsg guifg=#d79921 ctermfg=172 gui=NONE cterm=NONE
+              hi MoreMsg guifg=#98971a ctermfg=100 gui=NONE cterm=NONE
+              hi Question guifg=#689d6a ctermfg=71 gui=NONE cterm=NONE
+              hi diffAdded guifg=#98971a ctermfg=100 gui=NONE cterm=NONE
+              hi diffRemoved guifg=#cc241d ctermfg=160 gui=NONE cterm=NONE
+              hi diffChanged guifg=#d79921 ctermfg=172 gui=NONE cterm=NONE
+              hi Todo guifg=#282828 ctermfg=235 guibg=#d65d0e ctermbg=166 gui=bold cterm=bold
+              hi Tag guifg=#d65d0e ctermfg=166 gui=NONE cterm=NONE
+              
+              " Diff
+              hi DiffAdd guibg=#444425 ctermbg=237 gui=NONE cterm=NONE
+              hi DiffDelete guifg=#cc241d ctermfg=160 guibg=#512725 ctermbg=236 gui=NONE cterm=NONE
+              hi DiffChange guibg=#544426 ctermbg=238 gui=NONE cterm=NONE
+              hi DiffText guibg=#2f3f40 ctermbg=237 gui=bold cterm=bold
+            '';
+
+            programs.vim = {
+              enable = true;
+              extraConfig = "colorscheme gruvbox-dark";
+            };
+          };
+        zathura = { config, lib, pkgs, ... }:
+          {
+            programs.zathura = {
+              enable = true;
+              options = {
+                default-bg = "#282828";
+                default-fg = "#ebdbb2";
+                statusbar-bg = "#3c3a36";
+                statusbar-fg = "#ebdbb2";
+                inputbar-bg = "#282828";
+                inputbar-fg = "#ebdbb2";
+                notification-bg = "#282828";
+                notification-fg = "#ebdbb2";
+                notification-error-bg = "#cc241d";
+                notification-error-fg = "#282828";
+                notification-warning-bg = "#d79921";
+                notification-warning-fg = "#282828";
+                highlight-color = "rgba(215,153,33,0.5)";
+                highlight-fg = "#282828";
+                highlight-active-color = "rgba(254,128,25,0.5)";
+                completion-bg = "#3c3a36";
+                completion-fg = "#ebdbb2";
+                completion-group-bg = "#282828";
+                completion-group-fg = "#8d836a";
+                completion-highlight-bg = "#fe8019";
+                completion-highlight-fg = "#282828";
+                index-bg = "#282828";
+                index-fg = "#ebdbb2";
+                index-active-bg = "#fe8019";
+                index-active-fg = "#282828";
+                render-loading-bg = "#282828";
+                render-loading-fg = "#ebdbb2";
+                recolor-lightcolor = "#282828";
+                recolor-darkcolor = "#ebdbb2";
+                recolor-keephue = true;
+              };
+            };
+          };
+        tty = { config, lib, pkgs, ... }:
+          # The console palette is system configuration; on NixOS set:
+          #   console.colors = [ "282828" "cc241d" "98971a" "d79921" "458588" "b16286" "689d6a" "ebdbb2" "3d3d3d" "d64f4a" "acab47" "dfad4d" "6a9d9f" "c0819e" "86b087" "efe2c1" ];
+          # or add the kernel parameters: vt.default_red=0x28,0xcc,0x98,0xd7,0x45,0xb1,0x68,0xeb,0x3d,0xd6,0xac,0xdf,0x6a,0xc0,0x86,0xef vt.default_grn=0x28,0x24,0x97,0x99,0x85,0x62,0x9d,0xdb,0x3d,0x4f,0xab,0xad,0x9d,0x81,0xb0,0xe2 vt.default_blu=0x28,0x1d,0x1a,0x21,0x88,0x86,0x6a,0xb2,0x3d,0x4a,0x47,0x4d,0x9f,0x9e,0x87,0xc1
+          {
+            xdg.configFile."tty/themectl.sh".text = ''
+              #!/bin/sh
+              # Linux console palette: gruvbox-dark
+              # Generated by themectl
+              # Run from a virtual console: sh ~/.config/tty/themectl.sh
+              # setvtrgb changes every console (as root); otherwise only the current one is recolored.
+              # To apply the palette at boot, add to the kernel command line:
+              #   vt.default_red=0x28,0xcc,0x98,0xd7,0x45,0xb1,0x68,0xeb,0x3d,0xd6,0xac,0xdf,0x6a,0xc0,0x86,0xef vt.default_grn=0x28,0x24,0x97,0x99,0x85,0x62,0x9d,0xdb,0x3d,0x4f,0xab,0xad,0x9d,0x81,0xb0,0xe2 vt.default_blu=0x28,0x1d,0x1a,0x21,0x88,0x86,0x6a,0xb2,0x3d,0x4a,0x47,0x4d,0x9f,0x9e,0x87,0xc1
+              
+              if [ "$(id -u)" -eq 0 ] && command -v setvtrgb >/dev/null 2>&1; then
+                  setvtrgb - <<'EOF'
+              40,204,152,215,69,177,104,235,61,214,172,223,106,192,134,239
+              40,36,151,153,133,98,157,219,61,79,171,173,157,129,176,226
+              40,29,26,33,136,134,106,178,61,74,71,77,159,158,135,193
+              EOF
+              else
+                  printf '\033]P0282828'
+                  printf '\033]P1cc241d'
+                  printf '\033]P298971a'
+                  printf '\033]P3d79921'
+                  printf '\033]P4458588'
+                  printf '\033]P5b16286'
+                  printf '\033]P6689d6a'
+                  printf '\033]P7ebdbb2'
+                  printf '\033]P83d3d3d'
+                  printf '\033]P9d64f4a'
+                  printf '\033]PAacab47'
+                  printf '\033]PBdfad4d'
+                  printf '\033]PC6a9d9f'
+                  printf '\033]PDc0819e'
+                  printf '\033]PE86b087'
+                  printf '\033]PFefe2c1'
+                  clear
+              fi
+            '';
+          };
+        xresources = { config, lib, pkgs, ... }:
+          {
+            xresources.properties = {
+              "*.foreground" = "#ebdbb2";
+              "*.background" = "#282828";
+              "*.cursorColor" = "#fe8019";
+              "*.color0" = "#282828";
+              "*.color1" = "#cc241d";
+              "*.color2" = "#98971a";
+              "*.color3" = "#d79921";
+              "*.color4" = "#458588";
+              "*.color5" = "#b16286";
+              "*.color6" = "#689d6a";
+              "*.color7" = "#ebdbb2";
+              "*.color8" = "#3d3d3d";
+              "*.color9" = "#d64f4a";
+              "*.color10" = "#acab47";
+              "*.color11" = "#dfad4d";
+              "*.color12" = "#6a9d9f";
+              "*.color13" = "#c0819e";
+              "*.color14" = "#86b087";
+              "*.color15" = "#efe2c1";
+            };
+          };
+        cava = { config, lib, pkgs, ... }:
+          {
+            programs.cava = {
+              enable = true;
+              settings.color = {
+                background = "'#282828'";
+                foreground = "'#fe8019'";
+                gradient = 1;
+                gradient_count = 6;
+                gradient_color_1 = "'#fe8019'";
+                gradient_color_2 = "'#de755a'";
+                gradient_color_3 = "'#c0697a'";
+                gradient_color_4 = "'#b75a75'";
+                gradient_color_5 = "'#c2454f'";
+                gradient_color_6 = "'#cc241d'";
+              };
+            };
+          };
+        mpv = { config, lib, pkgs, ... }:
+          {
+            programs.mpv = {
+              enable = true;
+              config = {
+                osd-color = "#ebdbb2";
+                osd-border-color = "#282828";
+                sub-color = "#ebdbb2";
+                sub-border-color = "#282828";
+              };
+              # Only read when the uosc or ModernX script is installed
+              scriptOpts = {
+                uosc = {
+                  color = "foreground=fe8019,foreground_text=282828,background=282828,background_text=ebdbb2,curtain=282828,success=98971a,error=cc241d";
+                };
+                modernx = {
+                  osc_color = "#282828";
+                  seekbarfg_color = "#fe8019";
+                  seekbarbg_color = "#8d836a";
+                  seekbar_cache_color = "#8d836a";
+                  title_color = "#ebdbb2";
+                  time_color = "#ebdbb2";
+                  side_buttons_color = "#ebdbb2";
+                  middle_buttons_color = "#ebdbb2";
+                  playpause_color = "#ebdbb2";
+                  hover_effect_color = "#fe8019";
+                  thumbnail_border_color = "#282828";
+                };
+              };
+            };
+          };
+        imv = { config, lib, pkgs, ... }:
+          {
+            programs.imv = {
+              enable = true;
+              settings.options = {
+                background = "282828";
+                overlay_text_color = "ebdbb2";
+                overlay_background_color = "282828";
+                overlay_background_alpha = "cc";
+              };
+            };
+          };
+        firefox = { config, lib, pkgs, ... }:
+          {
+            programs.firefox.enable = true;
+
+            # Rename "default" to match an existing Home Manager profile
+            programs.firefox.profiles.default = {
+              settings."toolkit.legacyUserProfileCustomizations.stylesheets" = true;
+              userChrome = ''
+                /* Firefox userChrome.css: gruvbox-dark */
+                /* Generated by themectl */
+                /* Requires toolkit.legacyUserProfileCustomizations.stylesheets = true in user.js */
+                
+                :root {
+                  --lwt-accent-color: #282828 !important;
+                  --lwt-accent-color-inactive: #282828 !important;
+                  --lwt-text-color: #ebdbb2 !important;
+                  --toolbox-bgcolor: #282828 !important;
+                  --toolbox-textcolor: #ebdbb2 !important;
+                  --toolbar-bgcolor: #302f2e !important;
+                  --toolbar-color: #ebdbb2 !important;
+                  --toolbar-field-background-color: #383633 !important;
+                  --toolbar-field-color: #ebdbb2 !important;
+                  --toolbar-field-border-color: #4b4841 !important;
+                  --toolbar-field-focus-background-color: #3f3d39 !important;
+                  --toolbar-field-focus-color: #ebdbb2 !important;
+                  --toolbar-field-focus-border-color: #fe8019 !important;
+                  --tab-selected-bgcolor: #302f2e !important;
+                  --tab-selected-textcolor: #ebdbb2 !important;
+                  --tab-loading-fill: #fe8019 !important;
+                  --tab-hover-background-color: #43413b !important;
+                  --tabpanel-background-color: #282828 !important;
+                  --tabs-navbar-separator-color: #4b4841 !important;
+                  --urlbar-box-bgcolor: #383633 !important;
+                  --urlbar-box-text-color: #ebdbb2 !important;
+                  --urlbarView-highlight-background: #fe8019 !important;
+                  --urlbarView-highlight-color: #282828 !important;
+                  --urlbarView-separator-color: #4b4841 !important;
+                  --arrowpanel-background: #302f2e !important;
+                  --arrowpanel-color: #ebdbb2 !important;
+                  --arrowpanel-border-color: #4b4841 !important;
+                  --sidebar-background-color: #302f2e !important;
+                  --sidebar-text-color: #ebdbb2 !important;
+                  --sidebar-border-color: #4b4841 !important;
+                  --lwt-sidebar-background-color: #302f2e !important;
+                  --lwt-sidebar-text-color: #ebdbb2 !important;
+                  --chrome-content-separator-color: #4b4841 !important;
+                  --focus-outline-color: #fe8019 !important;
+                  --button-hover-bgcolor: #43413b !important;
+                  --checkbox-checked-bgcolor: #fe8019 !important;
+                  --link-color: #458588 !important;
+                  --text-color-deemphasized: #8d836a !important;
+                }
+                
+                .tab-background[selected] {
+                  background-color: #302f2e !important;
+                  border-top: 2px solid #fe8019 !important;
+                }
+                
+                #urlbar[focused] > #urlbar-background {
+                  outline-color: #fe8019 !important;
+                }
+              '';
+              userContent = ''
+                /* Firefox userContent.css: gruvbox-dark */
+                /* Generated by themectl */
+                
+                @-moz-document url-prefix("about:newtab"), url-prefix("about:home"), url-prefix("about:privatebrowsing"), url("about:blank") {
+                  :root {
+                    --newtab-background-color: #282828 !important;
+                    --newtab-background-color-secondary: #383633 !important;
+                    --newtab-text-primary-color: #ebdbb2 !important;
+                    --newtab-text-secondary-color: #8d836a !important;
+                    --newtab-primary-action-background: #fe8019 !important;
+                    --newtab-element-hover-color: #43413b !important;
+                    --newtab-element-active-color: #3f3d39 !important;
+                    --newtab-border-color: #4b4841 !important;
+                    --newtab-wordmark-color: #ebdbb2 !important;
+                    --in-content-page-background: #282828 !important;
+                    --in-content-page-color: #ebdbb2 !important;
+                  }
+                
+                  body {
+                    background-color: #282828 !important;
+                    color: #ebdbb2 !important;
+                  }
+                }
+                
+                @-moz-document url-prefix("about:") {
+                  :root {
+                    scrollbar-color: #8d836a #282828 !important;
+                  }
+                }
+              '';
+            };
+          };
+        btop = { config, lib, pkgs, ... }:
+          {
+            programs.btop = {
+              enable = true;
+              settings.color_theme = "gruvbox-dark";
+            };
+
+            xdg.configFile."btop/themes/gruvbox-dark.theme".text = ''
+              # Btop theme: gruvbox-dark
+              # Generated by themectl
+              # Colors should be in 6 or 2 character hexadecimal or single spaced RGB decimal: "#RRGGBB", "#BW" or "0-255 0-255 0-255"
+              
+              [main_bg] = "#282828"
+              [main_fg] = "#ebdbb2"
+              [title] = "#fe8019"
+              [hi_fg] = "#d79921"
+              [selected_bg] = "#fe8019"
+              [selected_fg] = "#282828"
+              [inactive_fg] = "#8d836a"
+              [graph_text] = "#ebdbb2"
+              [meter_bg] = "#202020"
+              [proc_misc] = "#b16286"
+              [cpu_box] = "#458588"
+              [mem_box] = "#98971a"
+              [net_box] = "#689d6a"
+              [proc_box] = "#fe8019"
+              [div_line] = "#756d59"
+              
+              # Temperature gradient
+              [temp_start] = "#98971a"
+              [temp_mid] = "#d79921"
+              [temp_end] = "#cc241d"
+              
+              # CPU usage gradient
+              [cpu_start] = "#98971a"
+              [cpu_mid] = "#d79921"
+              [cpu_end] = "#cc241d"
+              
+              # Free memory/disk gradient
+              [free_start] = "#98971a"
+              [free_mid] = "#acab47"
+              [free_end] = "#98971a"
+              
+              # Cached memory gradient
+              [cached_start] = "#689d6a"
+              [cached_mid] = "#86b087"
+              [cached_end] = "#689d6a"
+              
+              # Available memory gradient
+              [available_start] = "#458588"
+              [available_mid] = "#6a9d9f"
+              [available_end] = "#458588"
+              
+              # Used memory/disk gradient
+              [used_start] = "#98971a"
+              [used_mid] = "#d79921"
+              [used_end] = "#cc241d"
+              
+              # Download gradient
+              [download_start] = "#98971a"
+              [download_mid] = "#689d6a"
+              [download_end] = "#86b087"
+              
+              # Upload gradient
+              [upload_start] = "#458588"
+              [upload_mid] = "#b16286"
+              [upload_end] = "#c0819e"
+              
+              # Process resource usage gradient
+              [process_start] = "#fe8019"
+              [process_mid] = "#fe9947"
+              [process_end] = "#feb275"
+              
+              # Virtual memory gradient
+              [virtual_start] = "#b16286"
+              [virtual_mid] = "#c0819e"
+              [virtual_end] = "#b16286"
+            '';
+          };
+        tmtheme = { config, lib, pkgs, ... }:
+          {
+            programs.bat = {
+              enable = true;
+              config.theme = "gruvbox-dark";
+              themes.gruvbox-dark.src = pkgs.writeText "gruvbox-dark.tmTheme" ''
+                <?xml version="1.0" encoding="UTF-8"?>
+                <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+                <!-- TextMate theme: gruvbox-dark -->
+                <!-- Generated by themectl -->
+                <!-- Copy to ~/.config/bat/themes/ and run: bat cache --build -->
+                <plist version="1.0">
+                <dict>
+                  <key>name</key>
+                  <string>gruvbox-dark</string>
+                  <key>settings</key>
+                  <array>
+                    <dict>
+                      <key>settings</key>
+                      <dict>
+                        <key>background</key>
+                        <string>#282828</string>
+                        <key>foreground</key>
+                        <string>#ebdbb2</string>
+                        <key>caret</key>
+                        <string>#fe8019</string>
+                        <key>selection</key>
+                        <string>#684224</string>
+                        <key>lineHighlight</key>
+                        <string>#383633</string>
+                        <key>gutter</key>
+                        <string>#282828</string>
+                        <key>gutterForeground</key>
+                        <string>#8d836a</string>
+                        <key>invisibles</key>
+                        <string>#8d836a</string>
+                        <key>findHighlight</key>
+                        <string>#6e5525</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Comment</string>
+                      <key>scope</key>
+                      <string>comment, punctuation.definition.comment</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#8d836a</string>
+                        <key>fontStyle</key>
+                        <string>italic</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>String</string>
+                      <key>scope</key>
+                      <string>string, punctuation.definition.string</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#98971a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Escape</string>
+                      <key>scope</key>
+                      <string>constant.character.escape, string.regexp</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#689d6a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Number</string>
+                      <key>scope</key>
+                      <string>constant.numeric</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#b16286</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Boolean</string>
+                      <key>scope</key>
+                      <string>constant.language.boolean</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Constant</string>
+                      <key>scope</key>
+                      <string>constant.language, constant.character, constant.other, support.constant</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#b16286</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Keyword</string>
+                      <key>scope</key>
+                      <string>keyword, storage.type, storage.modifier</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Operator</string>
+                      <key>scope</key>
+                      <string>keyword.operator</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#ebdbb2</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Preprocessor</string>
+                      <key>scope</key>
+                      <string>meta.preprocessor, keyword.control.import, keyword.other.import</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#b16286</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Function</string>
+                      <key>scope</key>
+                      <string>entity.name.function, support.function, meta.function-call</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#689d6a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Builtin</string>
+                      <key>scope</key>
+                      <string>support.function.builtin, variable.language</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#689d6a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Type</string>
+                      <key>scope</key>
+                      <string>entity.name.type, entity.name.class, support.type, support.class</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d79921</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Namespace</string>
+                      <key>scope</key>
+                      <string>entity.name.namespace, entity.name.module</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d79921</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Variable</string>
+                      <key>scope</key>
+                      <string>variable</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#ebdbb2</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Parameter</string>
+                      <key>scope</key>
+                      <string>variable.parameter</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#ebdbb2</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Property</string>
+                      <key>scope</key>
+                      <string>variable.other.member, variable.other.property, meta.object-literal.key, support.type.property-name, entity.other.attribute-name</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Tag</string>
+                      <key>scope</key>
+                      <string>entity.name.tag</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d65d0e</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Punctuation</string>
+                      <key>scope</key>
+                      <string>punctuation</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#8d836a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Invalid</string>
+                      <key>scope</key>
+                      <string>invalid</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#cc241d</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Diff inserted</string>
+                      <key>scope</key>
+                      <string>markup.inserted, meta.diff.header.to-file</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#98971a</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Diff deleted</string>
+                      <key>scope</key>
+                      <string>markup.deleted, meta.diff.header.from-file</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#cc241d</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Diff changed</string>
+                      <key>scope</key>
+                      <string>markup.changed</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d79921</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Diff range</string>
+                      <key>scope</key>
+                      <string>meta.diff.range, meta.diff.header</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Heading</string>
+                      <key>scope</key>
+                      <string>markup.heading</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                        <key>fontStyle</key>
+                        <string>bold</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Bold</string>
+                      <key>scope</key>
+                      <string>markup.bold</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d79921</string>
+                        <key>fontStyle</key>
+                        <string>bold</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Italic</string>
+                      <key>scope</key>
+                      <string>markup.italic</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#d79921</string>
+                        <key>fontStyle</key>
+                        <string>italic</string>
+                      </dict>
+                    </dict>
+                    <dict>
+                      <key>name</key>
+                      <string>Link</string>
+                      <key>scope</key>
+                      <string>markup.underline.link</string>
+                      <key>settings</key>
+                      <dict>
+                        <key>foreground</key>
+                        <string>#458588</string>
+                        <key>fontStyle</key>
+                        <string>underline</string>
+                      </dict>
+                    </dict>
+                  </array>
+                </dict>
+                </plist>
+              '';
+            };
+
+            programs.git.delta = {
+              enable = true;
+              options = {
+                syntax-theme = "gruvbox-dark";
+                plus-style = "syntax #3e3e25";
+                plus-emph-style = "syntax #555422";
+                minus-style = "syntax #492726";
+                minus-emph-style = "syntax #6a2624";
+                line-numbers-plus-style = "#98971a";
+                line-numbers-minus-style = "#cc241d";
+                line-numbers-zero-style = "#8d836a";
+                file-style = "#fe8019 bold";
+                file-decoration-style = "#fe8019 ul";
+                hunk-header-style = "file line-number syntax";
+                hunk-header-decoration-style = "#458588 box";
+                commit-style = "#d79921 bold";
+              };
+            };
+          };
+        git = { config, lib, pkgs, ... }:
+          {
+            programs.git = {
+              enable = true;
+              extraConfig.color = {
+                ui = "auto";
+                diff = {
+                  meta = "#d79921 bold";
+                  frag = "#b16286 bold";
+                  old = "#cc241d bold";
+                  new = "#98971a bold";
+                  whitespace = "#cc241d reverse";
+                  commit = "#689d6a bold";
+                  func = "#458588 bold";
+                };
+                branch = {
+                  current = "#d79921 reverse";
+                  local = "#d79921";
+                  remote = "#98971a";
+                  upstream = "#689d6a";
+                  plain = "#ebdbb2";
+                };
+                status = {
+                  added = "#98971a bold";
+                  changed = "#d79921 bold";
+                  untracked = "#cc241d";
+                  deleted = "#cc241d bold";
+                  branch = "#689d6a bold";
+                  header = "#fe8019 bold";
+                  nobranch = "#cc241d";
+                };
+                interactive = {
+                  prompt = "#fe8019 bold";
+                  header = "#689d6a bold";
+                  help = "#d79921 bold";
+                  error = "#cc241d bold";
+                  reset = "normal";
+                };
+                grep = {
+                  match = "#d79921 bold";
+                  context = "#ebdbb2";
+                  filename = "#689d6a bold";
+                  function = "#458588 bold";
+                  lineNumber = "#b16286 bold";
+                  column = "#458588 bold";
+                  selected = "#fe8019 reverse";
+                  separator = "#ebdbb2";
+                };
+              };
+            };
+          };
+      };
+    };
+  };
+in
+{
+  options.themectl = {
+    theme = lib.mkOption {
+      type = lib.types.enum (builtins.attrNames themes);
+      description = "The themectl theme to apply.";
+    };
+    apps = lib.mkOption {
+      type = lib.types.listOf (lib.types.enum [ "kitty" "alacritty" "foot" "wezterm" "ghostty" "tmux" "zellij" "waybar" "neovim" "lualine" "helix" "starship" "mako" "dunst" "swaync" "hyprland" "sway" "i3" "hyprlock" "swaylock" "hyprpaper" "wofi" "rofi" "fuzzel" "tofi" "wlogout" "fastfetch" "yazi" "lscolors" "fzf" "lazygit" "fish" "zsh" "gtk" "qt" "emacs" "vim" "zathura" "tty" "xresources" "cava" "mpv" "imv" "firefox" "btop" "tmtheme" "git" ]);
+      default = [ ];
+      example = [ "kitty" "waybar" "hyprland" ];
+      description = "Applications themectl configures with the selected theme.";
+    };
+    specialisations = lib.mkEnableOption "a Home Manager specialisation for every theme";
+    colors = lib.mkOption {
+      type = lib.types.attrsOf lib.types.str;
+      readOnly = true;
+      default = themes.${cfg.theme}.colors;
+      description = "Palette of the selected theme, for use elsewhere in the configuration.";
+    };
+  };
+
+  config = lib.mkMerge ([
+    (lib.mkIf cfg.specialisations {
+      specialisation = lib.genAttrs (builtins.attrNames themes)
+        (name: { configuration.themectl.theme = lib.mkForce name; });
+    })
+  ] ++ lib.concatLists (lib.mapAttrsToList (name: theme:
+    lib.mapAttrsToList (app: module:
+      lib.mkIf (builtins.elem app cfg.apps && cfg.theme == name) (module moduleArgs)
+    ) theme.apps
+  ) themes));
+}
@@ -37,7 +37,7 @@ pub enum Commands {
         /// Override config directory
         config_dir: Option<PathBuf>,
         #[arg(long)]
-        /// Apply to specific applications only (comma-separated)
+        /// Apply to specific applications only (comma-separated, "shell" for all shell tools)
         apps: Option<String>,
         #[arg(long)]
         /// Apply specific variant (dark/light)
@@ -59,10 +59,12 @@ pub enum Commands {
     Export {
         /// Theme name
         theme: String,
-        /// Format (nix, kitty, waybar, neovim, etc.) or "all" for all formats
+        /// Format (nix, kitty, waybar, neovim, etc.), "all" for all formats,
+        /// "nix-module" for one Home Manager module with every theme (this theme as default),
+        /// or "stylix" for a base16 scheme (plus a Nix snippet when --output is a directory)
         format: String,
         #[arg(long)]
-        /// Output file path (or directory if --all is used)
+        /// Output file path (or directory if --all, nix-module or stylix is used)
         output: Option<PathBuf>,
         #[arg(long)]
         /// Export all formats at once
@@ -129,7 +131,8 @@ pub enum Commands {
     },
     /// Export all themes
     ExportAll {
-        /// Format (nix, kitty, waybar, etc.) or "all" for all formats
+        /// Format (nix, kitty, waybar, etc.), "all" for all formats, or "nix-lib"
+        /// for the theme library imported by the flake
         format: String,
         /// Output directory
         output_dir: PathBuf,
@@ -202,6 +205,21 @@ pub enum ConfigCommands {
         /// Path where Nix modules should be written
         path: PathBuf,
     },
+    /// Set the Hyprland version to generate config syntax for
+    SetHyprlandVersion {
+        /// Hyprland version, e.g. 0.45
+        version: String,
+    },
+    /// Set how git colors are written
+    SetGitColors {
+        /// auto, truecolor or 16
+        mode: String,
+    },
+    /// Set the Firefox profile to theme (default: every profile in profiles.ini)
+    SetFirefoxProfile {
+        /// Firefox profile directory
+        path: PathBuf,
+    },
 }
 
 #[derive(Subcommand)]
@@ -250,6 +268,10 @@ impl Cli {
             Commands::Export { theme, format, output, all } => {
                 if *all || format == "all" {
                     self.export_all_formats(theme, output, &themes_dir)?;
+                } else if format == "nix-module" {
+                    self.export_nix_module(theme, output, &themes_dir)?;
+                } else if format == "stylix" {
+                    self.export_stylix(theme, output, &themes_dir)?;
                 } else {
                     self.export_theme(theme, format, output, &themes_dir)?;
                 }
@@ -330,6 +352,15 @@ impl Cli {
                     ConfigCommands::SetNixPath { path } => {
                         self.config_set_nix_path(path.clone())?;
                     }
+                    ConfigCommands::SetHyprlandVersion { version } => {
+                        self.config_set_hyprland_version(version)?;
+                    }
+                    ConfigCommands::SetGitColors { mode } => {
+                        self.config_set_git_colors(mode)?;
+                    }
+                    ConfigCommands::SetFirefoxProfile { path } => {
+                        self.config_set_firefox_profile(path.clone())?;
+                    }
                 }
             }
             Commands::ExportAll { format, output_dir } => {
@@ -504,6 +535,8 @@ impl Cli {
                 gray,
             },
             properties: ThemeProperties::default(),
+            syntax: Default::default(),
+            wallpaper: None,
         };
         
         // Validate theme
@@ -614,8 +647,9 @@ impl Cli {
     fn export_theme(&self, theme_name: &str, format: &str, output: &Option<PathBuf>, themes_dir: &PathBuf) -> Result<()> {
         let theme_path = themes_dir.join(format!("{}.toml", theme_name));
         let theme = parser::parse_theme_file(&theme_path)?;
+        let config = Self::generator_config();
         
-        let content = generators::generate(&theme, format)?;
+        let content = generators::generate_with_config(&theme, format, &config)?;
         
         if let Some(output_path) = output {
             std::fs::write(output_path, content)?;
@@ -627,14 +661,29 @@ impl Cli {
         Ok(())
     }
     
+    /// Settings for generators in an export, with git's "auto" color mode
+    /// resolved once so all exported files agree
+    ///
+    /// Exports don't need a config file, so one that fails to load only warns.
+    fn generator_config() -> ThemectlConfig {
+        let mut config = match ThemectlConfig::load() {
+            Ok(config) => config.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("{} Ignoring themectl config, using defaults: {}", "⚠".yellow(), e);
+                ThemectlConfig::default()
+            }
+        };
+        config.git.resolve_auto();
+        config
+    }
+    
     fn export_all_formats(&self, theme_name: &str, output: &Option<PathBuf>, themes_dir: &PathBuf) -> Result<()> {
         let theme_path = themes_dir.join(format!("{}.toml", theme_name));
         let theme = parser::parse_theme_file(&theme_path)?;
         
         println!("{} Exporting all formats for theme: {}", "→".cyan(), theme.name.bold());
         
-        // Use generate_all() function
-        let results = generators::generate_all(&theme)?;
+        let results = generators::generate_all_with_config(&theme, &Self::generator_config())?;
         
         if let Some(output_dir) = output {
             // Export to directory
@@ -655,6 +704,79 @@ impl Cli {
         Ok(())
     }
     
+    fn export_nix_module(&self, default_theme: &str, output: &Option<PathBuf>, themes_dir: &PathBuf) -> Result<()> {
+        let default_path = themes_dir.join(format!("{}.toml", default_theme));
+        let default_name = parser::parse_theme_file(&default_path)?.name;
+        let themes = parser::find_theme_files(themes_dir)?
+            .iter()
+            .map(parser::parse_theme_file)
+            .collect::<Result<Vec<_>>>()?;
+        let config = Self::generator_config();
+        
+        let content = generators::generate_theme_switching_module(&themes, Some(&default_name), &config)?;
+        
+        if let Some(output_path) = output {
+            // A directory (or a path without extension) gets a default.nix inside it
+            let file_path = if output_path.is_dir() || output_path.extension().is_none() {
+                std::fs::create_dir_all(output_path)?;
+                output_path.join("default.nix")
+            } else {
+                output_path.clone()
+            };
+            std::fs::write(&file_path, content)?;
+            println!("{} Exported {} theme(s) to {:?}", "✓".green(), themes.len(), file_path);
+            println!("  Import it in home.nix and set themectl.theme and themectl.apps");
+        } else {
+            print!("{}", content);
+        }
+        
+        Ok(())
+    }
+    
+    fn export_stylix(&self, theme_name: &str, output: &Option<PathBuf>, themes_dir: &PathBuf) -> Result<()> {
+        // A file path or stdout gets only the scheme
+        let output_dir = match output {
+            Some(path) if path.is_dir() || path.extension().is_none() => path,
+            _ => return self.export_theme(theme_name, "stylix", output, themes_dir),
+        };
+        
+        let theme_path = themes_dir.join(format!("{}.toml", theme_name));
+        let mut theme = parser::parse_theme_file(&theme_path)?;
+        
+        std::fs::create_dir_all(output_dir)?;
+        
+        // The snippet refers to the wallpaper by file name, so it is copied next to it,
+        // resolved against the theme file when relative
+        if let Some(wallpaper) = theme.wallpaper.clone() {
+            match generators::stylix_wallpaper_file(&theme) {
+                Some(file) => {
+                    let source = theme_path.parent().unwrap_or(themes_dir).join(&wallpaper);
+                    let target = output_dir.join(&file);
+                    if let Err(e) = std::fs::copy(&source, &target) {
+                        eprintln!("{} Could not copy wallpaper {:?}: {}; stylix.image is left unset", "⚠".yellow(), source, e);
+                        theme.wallpaper = None;
+                    } else {
+                        println!("{} Copied wallpaper to {:?}", "✓".green(), target);
+                    }
+                }
+                None => eprintln!(
+                    "{} Wallpaper {} is home-relative, which pure flake evaluation rejects; \
+                    use a path relative to the theme file to set stylix.image",
+                    "⚠".yellow(), wallpaper
+                ),
+            }
+        }
+        
+        let scheme_path = output_dir.join(format!("{}.yaml", theme.name));
+        let snippet_path = output_dir.join(format!("{}.nix", theme.name));
+        std::fs::write(&scheme_path, generators::generate(&theme, "stylix")?)?;
+        std::fs::write(&snippet_path, generators::generate(&theme, "stylix-nix")?)?;
+        println!("{} Exported base16 scheme to {:?}", "✓".green(), scheme_path);
+        println!("{} Exported Stylix configuration to {:?}", "✓".green(), snippet_path);
+        
+        Ok(())
+    }
+    
     fn show_theme(&self, theme_name: &str, themes_dir: &PathBuf) -> Result<()> {
         let theme_path = themes_dir.join(format!("{}.toml", theme_name));
         let theme = parser::parse_theme_file(&theme_path)?;
@@ -1678,6 +1800,8 @@ impl Cli {
                 gray: None,
             },
             properties: ThemeProperties::default(),
+            syntax: Default::default(),
+            wallpaper: None,
         };
 
         let output_path = if let Some(p) = path {
@@ -1714,8 +1838,36 @@ impl Cli {
         Ok(())
     }
 
+    fn config_set_hyprland_version(&self, version: &str) -> Result<()> {
+        let mut config = ThemectlConfig::load()?.unwrap_or_default();
+        config.set_hyprland_version(version)?;
+        config.save()?;
+        println!("{} Hyprland config syntax set to version: {}", "✓".green(), version);
+        Ok(())
+    }
+
+    fn config_set_git_colors(&self, mode: &str) -> Result<()> {
+        let mut config = ThemectlConfig::load()?.unwrap_or_default();
+        config.set_git_colors(mode)?;
+        config.save()?;
+        println!("{} Git colors set to: {}", "✓".green(), config.git.colors);
+        Ok(())
+    }
+
+    fn config_set_firefox_profile(&self, path: PathBuf) -> Result<()> {
+        let mut config = ThemectlConfig::load()?.unwrap_or_default();
+        config.set_firefox_profile(path.clone());
+        config.save()?;
+        println!("{} Firefox profile set to: {}", "✓".green(), path.display());
+        Ok(())
+    }
+
     // Batch operations
     fn export_all_themes(&self, format: &str, output_dir: &PathBuf, themes_dir: &PathBuf) -> Result<()> {
+        if format == "nix-lib" {
+            return self.export_nix_lib(output_dir, themes_dir);
+        }
+        
         let themes = parser::find_theme_files(themes_dir)?;
         
         if themes.is_empty() {
@@ -1724,9 +1876,10 @@ impl Cli {
         }
 
         std::fs::create_dir_all(output_dir)?;
+        let config = Self::generator_config();
         let all_formats = format == "all";
         let formats = if all_formats {
-            vec!["kitty", "waybar", "neovim", "starship", "mako", "hyprland", "wofi", "wlogout", "fastfetch", "yazi", "hyprpaper", "nix", "gtk", "btop", "git"]
+            generators::formats().to_vec()
         } else {
             vec![format]
         };
@@ -1753,19 +1906,11 @@ impl Cli {
                                 
                                 if all_formats {
                                     // Use parallel generation for all formats
-                                    let gen_results = generators::generate_all_parallel(&theme);
+                                    let gen_results = generators::generate_all_parallel_with_config(&theme, &config);
                                     for (fmt, result) in gen_results {
                                         match result {
                                             Ok(content) => {
-                                                let ext = match fmt.as_str() {
-                                                    "neovim" => "lua",
-                                                    "starship" => "toml",
-                                                    "fastfetch" => "jsonc",
-                                                    "yazi" => "toml",
-                                                    "nix" => "nix",
-                                                    "btop" => "theme",
-                                                    _ => "conf",
-                                                };
+                                                let ext = generators::file_extension(&fmt);
                                                 let file_path = theme_dir.join(format!("{}.{}", fmt, ext));
                                                 if let Err(e) = std::fs::write(&file_path, content) {
                                                     errors.push(format!("Failed to write {}: {}", fmt, e));
@@ -1779,17 +1924,9 @@ impl Cli {
                                 } else {
                                     // Single format
                                     for fmt in &formats {
-                                        match generators::generate(&theme, fmt) {
+                                        match generators::generate_with_config(&theme, fmt, &config) {
                                             Ok(content) => {
-                                                let ext = match *fmt {
-                                                    "neovim" => "lua",
-                                                    "starship" => "toml",
-                                                    "fastfetch" => "jsonc",
-                                                    "yazi" => "toml",
-                                                    "nix" => "nix",
-                                                    "btop" => "theme",
-                                                    _ => "conf",
-                                                };
+                                                let ext = generators::file_extension(fmt);
                                                 let file_path = theme_dir.join(format!("{}.{}", fmt, ext));
                                                 if let Err(e) = std::fs::write(&file_path, content) {
                                                     errors.push(format!("Failed to write {}: {}", fmt, e));
@@ -1839,6 +1976,38 @@ impl Cli {
         Ok(())
     }
 
+    /// Write the theme library the flake imports: one palette per theme from the
+    /// nix generator, the `default.nix` index and the theme-switching module
+    fn export_nix_lib(&self, output_dir: &PathBuf, themes_dir: &PathBuf) -> Result<()> {
+        let themes = parser::find_theme_files(themes_dir)?
+            .iter()
+            .map(parser::parse_theme_file)
+            .collect::<Result<Vec<_>>>()?;
+        
+        if themes.is_empty() {
+            println!("No themes found in {:?}", themes_dir);
+            return Ok(());
+        }
+        
+        std::fs::create_dir_all(output_dir)?;
+        let index = generators::generate_theme_index(&themes)?;
+        for theme in &themes {
+            let content = generators::generate(theme, "nix")?;
+            std::fs::write(output_dir.join(format!("{}.nix", theme.name)), content)?;
+        }
+        std::fs::write(output_dir.join("default.nix"), index)?;
+        
+        // The library is committed and built in the sandbox, so pin the
+        // terminal-dependent git color mode
+        let mut config = ThemectlConfig::default();
+        config.git.colors = "truecolor".to_string();
+        let module = generators::generate_theme_switching_module(&themes, None, &config)?;
+        std::fs::write(output_dir.join("home-manager.nix"), module)?;
+        
+        println!("{} Exported {} theme(s) to {:?}", "✓".green(), themes.len(), output_dir);
+        Ok(())
+    }
+    
     fn validate_all_themes(&self, themes_dir: &PathBuf) -> Result<()> {
         let themes = parser::find_theme_files(themes_dir)?;
         
@@ -16,6 +16,12 @@ pub struct ThemectlConfig {
     pub search_paths: Vec<PathBuf>,
     #[serde(default)]
     pub nix: NixConfig,
+    #[serde(default)]
+    pub hyprland: HyprlandConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub firefox: FirefoxConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,10 +30,60 @@ pub struct NixConfig {
     pub output_path: Option<PathBuf>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyprlandConfig {
+    /// Hyprland release whose config syntax is generated (e.g. "0.45")
+    #[serde(default = "default_hyprland_version")]
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Git color output: "auto" (truecolor when the terminal supports it), "truecolor" or "16"
+    #[serde(default = "default_git_colors")]
+    pub colors: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FirefoxConfig {
+    /// Profile directory to theme; every profile in profiles.ini when unset
+    #[serde(default)]
+    pub profile: Option<PathBuf>,
+}
+
 fn default_deployment_method() -> String {
     "nix".to_string()
 }
 
+fn default_hyprland_version() -> String {
+    "0.45".to_string()
+}
+
+fn default_git_colors() -> String {
+    "auto".to_string()
+}
+
+/// Whether the terminal advertises 24-bit color through COLORTERM
+pub fn terminal_supports_truecolor() -> bool {
+    std::env::var("COLORTERM")
+        .map(|value| value == "truecolor" || value == "24bit")
+        .unwrap_or(false)
+}
+
+/// Parse a Hyprland version such as "0.45" or "0.45.2" into (major, minor)
+pub fn parse_hyprland_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    if let Some(patch) = parts.next() {
+        patch.parse::<u32>().ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor))
+}
+
 impl Default for NixConfig {
     fn default() -> Self {
         Self {
@@ -36,6 +92,41 @@ impl Default for NixConfig {
     }
 }
 
+impl Default for HyprlandConfig {
+    fn default() -> Self {
+        Self {
+            version: default_hyprland_version(),
+        }
+    }
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            colors: default_git_colors(),
+        }
+    }
+}
+
+impl GitConfig {
+    /// Whether git colors are written as #rrggbb instead of the nearest named color
+    pub fn use_truecolor(&self) -> bool {
+        match self.colors.as_str() {
+            "truecolor" => true,
+            "16" => false,
+            _ => terminal_supports_truecolor(),
+        }
+    }
+    
+    /// Settle "auto" on "truecolor" or "16" for the current terminal, so every
+    /// file written in one run uses the same git colors
+    pub fn resolve_auto(&mut self) {
+        if self.colors == "auto" {
+            self.colors = if terminal_supports_truecolor() { "truecolor" } else { "16" }.to_string();
+        }
+    }
+}
+
 impl Default for ThemectlConfig {
     fn default() -> Self {
         Self {
@@ -44,6 +135,9 @@ impl Default for ThemectlConfig {
             nixos_mode: false,
             search_paths: Vec::new(),
             nix: NixConfig::default(),
+            hyprland: HyprlandConfig::default(),
+            git: GitConfig::default(),
+            firefox: FirefoxConfig::default(),
         }
     }
 }
@@ -221,4 +315,49 @@ impl ThemectlConfig {
     pub fn set_nix_output_path(&mut self, path: PathBuf) {
         self.nix.output_path = Some(path);
     }
+
+    /// Set the Hyprland version whose config syntax should be generated
+    pub fn set_hyprland_version(&mut self, version: &str) -> Result<()> {
+        if parse_hyprland_version(version).is_none() {
+            anyhow::bail!(
+                "Invalid Hyprland version: '{}'.\n\
+                \n\
+                Expected a release number such as '0.45' or '0.41.2' (see `hyprctl version`).\n\
+                \n\
+                The version selects the config syntax themectl generates:\n\
+                - 0.45 and later: decoration:shadow {{ }} block\n\
+                - Older releases: drop_shadow, shadow_range and col.shadow\n\
+                \n\
+                To fix: Run 'themectl config set-hyprland-version <version>' with a valid version.",
+                version
+            );
+        }
+        self.hyprland.version = version.trim().to_string();
+        Ok(())
+    }
+
+    /// Set the Firefox profile directory to theme
+    pub fn set_firefox_profile(&mut self, profile: PathBuf) {
+        self.firefox.profile = Some(profile);
+    }
+
+    /// Set how git colors are written: auto, truecolor or 16
+    pub fn set_git_colors(&mut self, mode: &str) -> Result<()> {
+        let mode = mode.trim().to_lowercase();
+        if !matches!(mode.as_str(), "auto" | "truecolor" | "16") {
+            anyhow::bail!(
+                "Invalid git color mode: '{}'.\n\
+                \n\
+                Valid modes:\n\
+                - auto: exact #rrggbb colors when COLORTERM is truecolor or 24bit, named colors otherwise\n\
+                - truecolor: always write exact #rrggbb colors\n\
+                - 16: nearest of git's named colors, for terminals without truecolor\n\
+                \n\
+                To fix: Run 'themectl config set-git-colors <auto|truecolor|16>'.",
+                mode
+            );
+        }
+        self.git.colors = mode;
+        Ok(())
+    }
 }
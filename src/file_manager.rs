@@ -63,6 +63,9 @@ impl FileManager {
                         println!("  {} {} (dry-run)", "✓".yellow(), app);
                     } else {
                         println!("  {} {}", "✓".green(), app);
+                        if let Some(hint) = generators::reload_hint_for_theme(&theme, &app) {
+                            println!("    Reload: {}", hint);
+                        }
                     }
                 }
                 Err(e) => {
@@ -76,12 +79,27 @@ impl FileManager {
 
     pub fn apply_theme_filtered(&self, theme: &Theme, apps: &[&str]) -> Result<()> {
         let configs = self.detect_config_files(theme)?;
-        let app_set: std::collections::HashSet<&str> = apps.iter().cloned().collect();
+        // Expand group names such as "shell" into their apps
+        let mut app_set: std::collections::HashSet<&str> = std::collections::HashSet::new();
+        for app in apps {
+            match generators::app_group(app) {
+                Some(group) => app_set.extend(group.iter().copied()),
+                None => {
+                    app_set.insert(app);
+                }
+            }
+        }
         
-        let filtered: Vec<_> = configs.into_iter()
+        let mut filtered: Vec<_> = configs.into_iter()
             .filter(|(app, _)| app_set.contains(app.as_str()))
             .collect();
         
+        // System-level theming (console, GRUB, greeter, Plymouth) needs root, so it
+        // is only applied when asked for by name
+        if app_set.contains("nixos") && self.themectl_config.as_ref().map(|c| c.nixos_mode).unwrap_or(false) {
+            filtered.push(("nixos".to_string(), self.nixos_module_path()));
+        }
+        
         println!("\n{} Applying theme to {} config files...\n", "→".cyan(), filtered.len());
         
         // Use parallel processing for better performance
@@ -102,6 +120,9 @@ impl FileManager {
                         println!("  {} {} (dry-run)", "✓".yellow(), app);
                     } else {
                         println!("  {} {}", "✓".green(), app);
+                        if let Some(hint) = generators::reload_hint_for_theme(&theme, &app) {
+                            println!("    Reload: {}", hint);
+                        }
                     }
                 }
                 Err(e) => {
@@ -114,6 +135,10 @@ impl FileManager {
     }
     
     fn apply_to_file(&self, theme: &Theme, app: &str, path: &Path) -> Result<()> {
+        if app == "nixos" {
+            return self.apply_nixos(theme, path);
+        }
+        
         let deployment_method = self.themectl_config.as_ref()
             .map(|c| c.get_deployment_method())
             .unwrap_or("nix");
@@ -145,8 +170,31 @@ impl FileManager {
         Ok(())
     }
     
+    /// Write the NixOS system module, which is the same for both deployment methods
+    ///
+    /// A dry run prints the module for review instead, since writing to
+    /// /etc/nixos usually needs root.
+    fn apply_nixos(&self, theme: &Theme, path: &Path) -> Result<()> {
+        let content = generators::generate(theme, "nixos")?;
+        
+        if self.dry_run {
+            println!("    Would write NixOS module to: {:?}\n", path);
+            print!("{}", content);
+            return Ok(());
+        }
+        
+        self.write_config(theme, "nixos", path, &content)
+            .with_context(|| format!(
+                "Failed to write the NixOS system module to {:?}.\n\
+                \n\
+                Writing to /etc/nixos usually needs root. Run themectl with sudo, or use\n\
+                --dry-run to print the module and copy it yourself.",
+                path
+            ))
+    }
+    
     fn apply_standard(&self, theme: &Theme, app: &str, path: &Path) -> Result<()> {
-        let content = generators::generate(theme, app)
+        let content = generators::generate_with_config(theme, app, &self.generator_config())
             .with_context(|| format!(
                 "Failed to generate configuration for application '{}'.\n\
                 \n\
@@ -163,6 +211,127 @@ impl FileManager {
                 app, theme.name, app, path, app
             ))?;
         
+        // cava and imv are themed in the user's main config, so only the color section is replaced
+        let content = match app {
+            "cava" | "imv" => generators::update_config_colors(theme, app, &fs::read_to_string(path).unwrap_or_default())?,
+            _ => content,
+        };
+        
+        self.write_config(theme, app, path, &content)?;
+        
+        for (extra_path, extra_content) in self.companion_files(theme, app, path)? {
+            self.write_config(theme, app, &extra_path, &extra_content)?;
+        }
+        
+        // The preference is desktop-wide, so leave it alone when writing to another config dir
+        if app == "gtk" && !self.dry_run && self.config_dir.is_none() {
+            Self::set_gtk_color_scheme(theme);
+        }
+        
+        Ok(())
+    }
+    
+    /// Set the desktop-wide color-scheme preference, which libadwaita follows
+    /// instead of settings.ini. A missing gsettings (no GNOME schemas) only warns.
+    fn set_gtk_color_scheme(theme: &Theme) {
+        let scheme = generators::gtk_color_scheme(theme);
+        let result = std::process::Command::new("gsettings")
+            .args(["set", "org.gnome.desktop.interface", "color-scheme", scheme])
+            .output();
+        
+        match result {
+            Ok(output) if output.status.success() => {}
+            Ok(output) => eprintln!(
+                "    {} gsettings could not set color-scheme to {}: {}",
+                "⚠".yellow(), scheme, String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            Err(e) => eprintln!(
+                "    {} Could not run gsettings to set color-scheme to {}: {}",
+                "⚠".yellow(), scheme, e
+            ),
+        }
+    }
+    
+    /// Extra files an app needs next to its main config, e.g. the GTK3 copies of
+    /// the GTK settings or the qt5ct.conf/qt6ct.conf entries selecting a Qt palette
+    fn companion_files(&self, theme: &Theme, app: &str, path: &Path) -> Result<Vec<(PathBuf, String)>> {
+        let base_dir = self.config_base_dir();
+        
+        match app {
+            "gtk" => {
+                let css = generators::generate(theme, "gtk-css")?;
+                Ok(vec![
+                    (base_dir.join("gtk-3.0").join("settings.ini"), generators::generate(theme, "gtk3-settings")?),
+                    (base_dir.join("gtk-3.0").join("gtk.css"), css.clone()),
+                    (base_dir.join("gtk-4.0").join("gtk.css"), css),
+                ])
+            }
+            "firefox" => {
+                let mut files = vec![(path.with_file_name("userContent.css"), generators::generate(theme, "firefox-content")?)];
+                if let Some(profile) = path.parent().and_then(Path::parent) {
+                    let user_js = profile.join("user.js");
+                    let existing = fs::read_to_string(&user_js).unwrap_or_default();
+                    files.push((user_js, generators::firefox_update_user_js(&existing)));
+                }
+                Ok(files)
+            }
+            "lscolors" => {
+                let dir = base_dir.join("lscolors");
+                Ok(vec![
+                    (dir.join("lscolors.fish"), generators::generate(theme, "lscolors-fish")?),
+                    (dir.join("dircolors"), generators::generate(theme, "dircolors")?),
+                ])
+            }
+            "tty" => {
+                let dir = base_dir.join("tty");
+                Ok(vec![
+                    (dir.join("vtrgb"), generators::generate(theme, "tty-vtrgb")?),
+                    (dir.join("kernel-params"), generators::generate(theme, "tty-kernel")?),
+                ])
+            }
+            "mpv" => {
+                // OSC script colors only for scripts that are installed or already configured
+                let dir = base_dir.join("mpv");
+                let mut files = Vec::new();
+                for (script, installed) in [("uosc", ["uosc", "uosc.lua"]), ("modernx", ["modernx.lua", "modernx"])] {
+                    let opts = dir.join("script-opts").join(format!("{}.conf", script));
+                    if opts.exists() || installed.iter().any(|name| dir.join("scripts").join(name).exists()) {
+                        let existing = fs::read_to_string(&opts).unwrap_or_default();
+                        files.push((opts, generators::mpv_update_script_opts(theme, script, &existing)?));
+                    }
+                }
+                Ok(files)
+            }
+            "tmtheme" => {
+                // The [delta] section sits next to the git color config, included the same way
+                let git_themes = base_dir.join("git").join("themes");
+                Ok(vec![(git_themes.join(format!("{}-delta.conf", theme.name)), generators::generate(theme, "delta")?)])
+            }
+            "qt" => {
+                let qt6_scheme = base_dir.join("qt6ct").join("colors").join(format!("{}.conf", theme.name));
+                let mut files = vec![(qt6_scheme.clone(), generators::generate(theme, "qt")?)];
+                
+                for (tool, scheme) in [("qt5ct", path.to_path_buf()), ("qt6ct", qt6_scheme)] {
+                    let conf = base_dir.join(tool).join(format!("{}.conf", tool));
+                    let existing = fs::read_to_string(&conf).unwrap_or_default();
+                    let updated = generators::qt_select_color_scheme(&existing, &scheme.to_string_lossy());
+                    files.push((conf, updated));
+                }
+                
+                // Kvantum is opt-in: only theme it when the user already has a Kvantum config
+                let kvantum_dir = base_dir.join("Kvantum");
+                if kvantum_dir.is_dir() {
+                    let kvconfig = kvantum_dir.join(&theme.name).join(format!("{}.kvconfig", theme.name));
+                    files.push((kvconfig, generators::generate(theme, "qt-kvantum")?));
+                }
+                
+                Ok(files)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+    
+    fn write_config(&self, theme: &Theme, app: &str, path: &Path, content: &str) -> Result<()> {
         if self.dry_run {
             println!("    Would write to: {:?}", path);
             return Ok(());
@@ -170,7 +339,7 @@ impl FileManager {
         
         // Check if incremental update should skip this file
         if let Some(ref incremental) = self.incremental {
-            if let Ok(false) = incremental.should_update(path, &theme.name, &content) {
+            if let Ok(false) = incremental.should_update(path, &theme.name, content) {
                 // File is up to date, skip writing
                 return Ok(());
             }
@@ -200,7 +369,7 @@ impl FileManager {
         }
         
         // Write new content
-        fs::write(path, &content)
+        fs::write(path, content)
             .with_context(|| format!(
                 "Failed to write configuration for application '{}' to {:?}.\n\
                 \n\
@@ -217,12 +386,47 @@ impl FileManager {
         
         // Update incremental metadata
         if let Some(ref incremental) = self.incremental {
-            incremental.update_metadata(path, &theme.name, &content)?;
+            incremental.update_metadata(path, &theme.name, content)?;
         }
         
         Ok(())
     }
     
+    /// Firefox profiles to theme: the configured profile, or every existing profile in profiles.ini
+    ///
+    /// Profiles are looked up in ~/.mozilla/firefox, or in .mozilla/firefox under an
+    /// overridden config dir so that it never touches the real profiles.
+    fn firefox_profiles(&self) -> Vec<PathBuf> {
+        if let Some(profile) = self.themectl_config.as_ref().and_then(|c| c.firefox.profile.clone()) {
+            return vec![profile];
+        }
+        
+        let firefox_dir = match self.config_dir.clone().or_else(dirs::home_dir) {
+            Some(base) => base.join(".mozilla").join("firefox"),
+            None => return Vec::new(),
+        };
+        fs::read_to_string(firefox_dir.join("profiles.ini"))
+            .map(|ini| generators::firefox_profile_dirs(&ini, &firefox_dir))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|dir| dir.is_dir())
+            .collect()
+    }
+    
+    /// Base directory for app configs (~/.config unless overridden)
+    fn config_base_dir(&self) -> PathBuf {
+        self.config_dir.clone().unwrap_or_else(|| {
+            dirs::home_dir()
+                .unwrap_or_else(|| PathBuf::from("~"))
+                .join(".config")
+        })
+    }
+    
+    /// User settings passed to the generators, falling back to defaults without a config file
+    fn generator_config(&self) -> crate::config::ThemectlConfig {
+        self.themectl_config.clone().unwrap_or_default()
+    }
+    
     fn apply_nix(&self, theme: &Theme, app: &str) -> Result<()> {
         let nix_path = if let Some(config) = &self.themectl_config {
             config.get_nix_output_path()
@@ -236,7 +440,7 @@ impl FileManager {
         };
         
         // Generate Nix module for this application
-        let nix_content = crate::generators::generate_home_manager_module(theme, app)
+        let nix_content = crate::generators::generate_home_manager_module_with_config(theme, app, &self.generator_config())
             .with_context(|| format!(
                 "Failed to generate Nix Home Manager module for application '{}'.\n\
                 \n\
@@ -248,10 +452,8 @@ impl FileManager {
                 - Theme data is invalid or incomplete\n\
                 - Generator encountered an internal error\n\
                 \n\
-                To fix: Check if the application is supported. Supported applications include: \
-                kitty, waybar, neovim, starship, mako, hyprland, wofi, wlogout, fastfetch, yazi, \
-                hyprpaper, gtk, btop, git",
-                app, theme.name, app, app
+                To fix: Check if the application is supported. Supported applications include: {}",
+                app, theme.name, app, app, generators::apps().collect::<Vec<_>>().join(", ")
             ))?;
         let module_path = nix_path.join(format!("{}.nix", app));
         
@@ -342,23 +544,31 @@ impl FileManager {
         }
     }
     
+    /// Where the NixOS system module goes: /etc/nixos, or under an overridden config dir
+    fn nixos_module_path(&self) -> PathBuf {
+        self.config_dir.as_ref()
+            .map(|dir| dir.join("nixos"))
+            .unwrap_or_else(|| PathBuf::from("/etc/nixos"))
+            .join("themectl")
+            .join("system.nix")
+    }
+    
     fn detect_standard_files(&self, theme: &Theme) -> Result<Vec<(String, PathBuf)>> {
         let mut configs = Vec::new();
         
-        let base_dir = self.config_dir.clone().unwrap_or_else(|| {
-            dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("~"))
-                .join(".config")
-        });
-        
-        let apps = vec![
-            "kitty", "waybar", "neovim", "starship", "mako", 
-            "hyprland", "wofi", "wlogout", "fastfetch", "yazi", "hyprpaper", "gtk", "btop", "git"
-        ];
+        let base_dir = self.config_base_dir();
         
-        for app in apps {
+        for app in generators::apps() {
+            if app == "firefox" {
+                // One userChrome.css per profile; userContent.css and user.js follow as companions
+                for profile in self.firefox_profiles() {
+                    configs.push((app.to_string(), profile.join("chrome").join("userChrome.css")));
+                }
+                continue;
+            }
+            
             // Try to discover config file using enhanced detection
-            if let Some(path) = Self::discover_config_file(app, self.themectl_config.as_ref(), theme) {
+            if let Some(path) = Self::discover_config_file(app, self.themectl_config.as_ref(), theme, &base_dir) {
                 if path.exists() || !self.dry_run {
                     configs.push((app.to_string(), path));
                     continue;
@@ -366,38 +576,8 @@ impl FileManager {
             }
             
             // Fallback to standard paths if discovery didn't find anything
-            let standard_path = match app {
-                "kitty" => base_dir.join("kitty").join("kitty.conf"),
-                "waybar" => base_dir.join("waybar").join("style.css"),
-                "neovim" => base_dir.join("nvim").join("colors").join(format!("{}.lua", theme.name)),
-                "starship" => base_dir.join("starship.toml"),
-                "mako" => base_dir.join("mako").join("config"),
-                "hyprland" => base_dir.join("hypr").join("hyprland.conf"),
-                "wofi" => base_dir.join("wofi").join("style.css"),
-                "wlogout" => base_dir.join("wlogout").join("style.css"),
-                "fastfetch" => base_dir.join("fastfetch").join("config.jsonc"),
-                "yazi" => base_dir.join("yazi").join("yazi.toml"),
-                "hyprpaper" => base_dir.join("hypr").join("hyprpaper.conf"),
-                "gtk" => base_dir.join("gtk-4.0").join("settings.ini"),
-                "btop" => {
-                    // Btop themes are typically in ~/.config/btop/themes/ or alongside btop executable
-                    // For now, use a standard location
-                    if let Some(home) = dirs::home_dir() {
-                        home.join(".config").join("btop").join("themes").join(format!("{}.theme", theme.name))
-                    } else {
-                        base_dir.join("btop").join("themes").join(format!("{}.theme", theme.name))
-                    }
-                },
-                "git" => {
-                    // Git config can be included via include.path in ~/.gitconfig
-                    // Store in ~/.config/git/themes/
-                    if let Some(home) = dirs::home_dir() {
-                        home.join(".config").join("git").join("themes").join(format!("{}.conf", theme.name))
-                    } else {
-                        base_dir.join("git").join("themes").join(format!("{}.conf", theme.name))
-                    }
-                },
-                _ => continue,
+            let Some(standard_path) = generators::config_path(app, theme, &base_dir) else {
+                continue;
             };
             
             if standard_path.exists() || standard_path.parent().map(|p| p.exists()).unwrap_or(false) || !self.dry_run {
@@ -422,12 +602,8 @@ impl FileManager {
                 .join("themectl")
         };
         
-        let apps = vec![
-            "kitty", "waybar", "neovim", "starship", "mako", 
-            "hyprland", "wofi", "wlogout", "fastfetch", "yazi", "hyprpaper", "gtk", "btop", "git"
-        ];
         
-        for app in apps {
+        for app in generators::apps() {
             let module_path = nix_path.join(format!("{}.nix", app));
             // Always include Nix modules (they'll be created if they don't exist)
             configs.push((app.to_string(), module_path));
@@ -479,7 +655,7 @@ impl FileManager {
     }
 
     /// Discover config file for an application using multiple search strategies
-    pub fn discover_config_file(app: &str, config: Option<&ThemectlConfig>, theme: &Theme) -> Option<PathBuf> {
+    pub fn discover_config_file(app: &str, config: Option<&ThemectlConfig>, theme: &Theme, base_dir: &Path) -> Option<PathBuf> {
         // 1. Check custom path from config first
         if let Some(cfg) = config {
             if let Some(custom_path) = cfg.get_app_path(app) {
@@ -490,40 +666,7 @@ impl FileManager {
         }
         
         // 2. Check standard locations
-        let base_dir = dirs::home_dir()?.join(".config");
-        let standard_path = match app {
-            "kitty" => Some(base_dir.join("kitty").join("kitty.conf")),
-            "waybar" => Some(base_dir.join("waybar").join("style.css")),
-            "neovim" => Some(base_dir.join("nvim").join("colors").join(format!("{}.lua", theme.name))),
-            "starship" => Some(base_dir.join("starship.toml")),
-            "mako" => Some(base_dir.join("mako").join("config")),
-            "hyprland" => Some(base_dir.join("hypr").join("hyprland.conf")),
-            "wofi" => Some(base_dir.join("wofi").join("style.css")),
-            "wlogout" => Some(base_dir.join("wlogout").join("style.css")),
-            "fastfetch" => Some(base_dir.join("fastfetch").join("config.jsonc")),
-            "yazi" => Some(base_dir.join("yazi").join("yazi.toml")),
-            "hyprpaper" => Some(base_dir.join("hypr").join("hyprpaper.conf")),
-            "gtk" => Some(base_dir.join("gtk-4.0").join("settings.ini")),
-            "btop" => {
-                // Btop themes are in ~/.config/btop/themes/
-                if let Some(home) = dirs::home_dir() {
-                    Some(home.join(".config").join("btop").join("themes").join(format!("{}.theme", theme.name)))
-                } else {
-                    Some(base_dir.join("btop").join("themes").join(format!("{}.theme", theme.name)))
-                }
-            },
-            "git" => {
-                // Git config can be included via include.path in ~/.gitconfig
-                if let Some(home) = dirs::home_dir() {
-                    Some(home.join(".config").join("git").join("themes").join(format!("{}.conf", theme.name)))
-                } else {
-                    Some(base_dir.join("git").join("themes").join(format!("{}.conf", theme.name)))
-                }
-            },
-            _ => None,
-        };
-        
-        if let Some(path) = standard_path {
+        if let Some(path) = generators::config_path(app, theme, base_dir) {
             if path.exists() || path.parent().map(|p| p.exists()).unwrap_or(false) {
                 return Some(path);
             }
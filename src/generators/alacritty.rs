@@ -0,0 +1,81 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::palette::ansi_palette;
+
+/// A `[colors.<section>]` table as (section, [(key, color)])
+pub(crate) type ColorSection = (&'static str, Vec<(&'static str, String)>);
+
+const ANSI_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Alacritty theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Import it from ~/.config/alacritty/alacritty.toml:\n");
+    output.push_str("#   [general]\n");
+    output.push_str("#   import = [\"~/.config/alacritty/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".toml\"]\n");
+
+    for (section, entries) in color_sections(theme)? {
+        output.push_str("\n[colors.");
+        output.push_str(section);
+        output.push_str("]\n");
+        for (key, color) in entries {
+            output.push_str(&format!("{} = \"{}\"\n", key, color));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Alacritty color sections, shared by the TOML output and the Home Manager
+/// `programs.alacritty.settings.colors` module
+pub(crate) fn color_sections(theme: &Theme) -> Result<Vec<ColorSection>> {
+    let palette = ansi_palette(theme);
+    let normal = ANSI_NAMES.iter()
+        .enumerate()
+        .map(|(i, name)| (*name, palette[i].clone()))
+        .collect();
+    let bright = ANSI_NAMES.iter()
+        .enumerate()
+        .map(|(i, name)| (*name, palette[i + 8].clone()))
+        .collect();
+
+    let focused_match = theme.get_color("orange").unwrap_or(&theme.colors.accent).to_string();
+
+    Ok(vec![
+        ("primary", vec![
+            ("background", theme.colors.bg.clone()),
+            ("foreground", theme.colors.fg.clone()),
+        ]),
+        ("normal", normal),
+        ("bright", bright),
+        ("cursor", vec![
+            ("text", theme.colors.bg.clone()),
+            ("cursor", theme.colors.accent.clone()),
+        ]),
+        ("selection", vec![
+            ("text", theme.colors.bg.clone()),
+            ("background", theme.colors.accent.clone()),
+        ]),
+        ("search.matches", vec![
+            ("foreground", theme.colors.bg.clone()),
+            ("background", theme.colors.yellow.clone()),
+        ]),
+        ("search.focused_match", vec![
+            ("foreground", theme.colors.bg.clone()),
+            ("background", focused_match),
+        ]),
+        ("hints.start", vec![
+            ("foreground", theme.colors.bg.clone()),
+            ("background", theme.colors.yellow.clone()),
+        ]),
+        ("hints.end", vec![
+            ("foreground", theme.colors.bg.clone()),
+            ("background", theme.colors.cyan.clone()),
+        ]),
+    ])
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::oklab_gradient;
+use super::ini::update_section;
+
+/// Number of gradient colors written; cava accepts 2 to 8
+pub(crate) const GRADIENT_COUNT: usize = 6;
+
+/// Bar gradient from accent through magenta to red, interpolated in OKLab
+pub(crate) fn gradient(theme: &Theme, count: usize) -> Vec<String> {
+    let c = &theme.colors;
+    let count = count.clamp(2, 8);
+    oklab_gradient(&[&c.accent, &c.magenta, &c.red], count)
+        .unwrap_or_else(|| vec![c.accent.clone(); count])
+}
+
+/// `[color]` keys with their quoted values
+fn color_options(theme: &Theme) -> Vec<(String, String)> {
+    let c = &theme.colors;
+    let colors = gradient(theme, GRADIENT_COUNT);
+    let mut options = vec![
+        ("background".to_string(), format!("'{}'", c.bg)),
+        ("foreground".to_string(), format!("'{}'", c.accent)),
+        ("gradient".to_string(), "1".to_string()),
+        ("gradient_count".to_string(), colors.len().to_string()),
+    ];
+    for (i, color) in colors.iter().enumerate() {
+        options.push((format!("gradient_color_{}", i + 1), format!("'{}'", color)));
+    }
+    options
+}
+
+/// Generate a cava config with theme colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Cava configuration: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Reload colors in a running cava with: pkill -USR2 cava\n\n");
+
+    output.push_str("[color]\n");
+    for (key, value) in color_options(theme) {
+        output.push_str(&format!("{} = {}\n", key, value));
+    }
+
+    Ok(output)
+}
+
+/// Set the theme colors in an existing cava config, keeping its other sections
+pub fn update_config(theme: &Theme, existing: &str) -> Result<String> {
+    if existing.trim().is_empty() {
+        return generate(theme);
+    }
+    let options = color_options(theme);
+    let updates: Vec<(&str, String)> = options.iter().map(|(key, value)| (key.as_str(), value.clone())).collect();
+    Ok(update_section(existing, "color", &updates, " = "))
+}
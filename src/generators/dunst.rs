@@ -0,0 +1,52 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::mako::urgency_colors;
+
+/// A dunstrc section as (section, [(key, value)]), with values already quoted
+/// where dunst expects strings so they can be reused as Nix literals
+pub(crate) type DunstSection = (&'static str, Vec<(&'static str, String)>);
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Dunst theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Dunst reads drop-in files from ~/.config/dunst/dunstrc.d/ after dunstrc\n");
+
+    for (section, entries) in sections(theme) {
+        output.push_str(&format!("\n[{}]\n", section));
+        for (key, value) in entries {
+            output.push_str(&format!("    {} = {}\n", key, value));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Dunst sections, shared by the dunstrc output and the Home Manager
+/// `services.dunst.settings` module
+pub(crate) fn sections(theme: &Theme) -> Vec<DunstSection> {
+    let quoted = |color: &str| format!("\"{}\"", color);
+
+    let mut sections = vec![
+        ("global", vec![
+            ("frame_width", theme.properties.border_width.unwrap_or(2).to_string()),
+            ("corner_radius", theme.properties.border_radius.unwrap_or(0).to_string()),
+            ("frame_color", quoted(&theme.colors.accent)),
+            ("separator_color", quoted("frame")),
+            ("highlight", quoted(&theme.colors.accent)),
+        ]),
+    ];
+
+    for (section, urgency) in [("urgency_low", "low"), ("urgency_normal", "normal"), ("urgency_critical", "critical")] {
+        let colors = urgency_colors(theme, urgency);
+        sections.push((section, vec![
+            ("background", quoted(&colors.background)),
+            ("foreground", quoted(&colors.text)),
+            ("frame_color", quoted(&colors.border)),
+        ]));
+    }
+
+    sections
+}
@@ -0,0 +1,141 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{mix_colors, theme_slug};
+use super::palette::ansi_palette;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+/// font-lock faces colored directly from a syntax role
+const FONT_LOCK_FACES: &[(&str, &str)] = &[
+    ("font-lock-comment-face", "comment"),
+    ("font-lock-comment-delimiter-face", "comment"),
+    ("font-lock-doc-face", "comment"),
+    ("font-lock-string-face", "string"),
+    ("font-lock-keyword-face", "keyword"),
+    ("font-lock-builtin-face", "builtin"),
+    ("font-lock-function-name-face", "function"),
+    ("font-lock-function-call-face", "function"),
+    ("font-lock-variable-name-face", "variable"),
+    ("font-lock-variable-use-face", "variable"),
+    ("font-lock-type-face", "type"),
+    ("font-lock-constant-face", "constant"),
+    ("font-lock-number-face", "number"),
+    ("font-lock-preprocessor-face", "preproc"),
+    ("font-lock-escape-face", "special"),
+    ("font-lock-regexp-grouping-backslash", "special"),
+    ("font-lock-regexp-grouping-construct", "special"),
+    ("font-lock-negation-char-face", "operator"),
+    ("font-lock-operator-face", "operator"),
+    ("font-lock-property-name-face", "property"),
+    ("font-lock-property-use-face", "property"),
+    ("font-lock-punctuation-face", "punctuation"),
+    ("font-lock-bracket-face", "punctuation"),
+    ("font-lock-delimiter-face", "punctuation"),
+    ("font-lock-warning-face", "warning"),
+];
+
+/// Generate an Emacs `deftheme` file (`<theme>-theme.el`)
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let c = &theme.colors;
+    // deftheme needs a symbol, and Emacs finds the theme by its <symbol>-theme.el file name
+    let name = &theme_slug(&theme.name);
+    let roles = SyntaxRoles::from_theme(theme);
+    let (_, fg_dim) = derived_colors(theme);
+    let role = |role: &str| resolve(theme, roles.key(role));
+    let mix = |top: &str, weight: f32| mix_colors(top, &c.bg, weight).unwrap_or_else(|| c.bg.clone());
+    let tint = |role_name: &str, weight: f32| mix(&role(role_name), weight);
+    let surface = mix(&c.fg, 0.1);
+
+    output.push_str(&format!(";;; {}-theme.el --- {} theme -*- lexical-binding: t; -*-\n", name, theme.name));
+    output.push_str(";; Generated by themectl\n");
+    output.push_str(";; Load with:\n");
+    output.push_str(";;   (add-to-list 'custom-theme-load-path \"~/.config/emacs/themes/\")\n");
+    output.push_str(&format!(";;   (load-theme '{} t)\n\n", name));
+
+    output.push_str(&format!("(deftheme {} \"Generated by themectl.\")\n\n", name));
+
+    let mut faces: Vec<(String, String)> = vec![
+        ("default".into(), format!(":foreground \"{}\" :background \"{}\"", c.fg, c.bg)),
+        ("cursor".into(), format!(":background \"{}\"", c.accent)),
+        ("fringe".into(), format!(":background \"{}\"", c.bg)),
+        ("region".into(), format!(":background \"{}\" :extend t", mix(&c.accent, 0.3))),
+        ("hl-line".into(), format!(":background \"{}\" :extend t", surface)),
+        ("highlight".into(), format!(":background \"{}\"", mix(&c.accent, 0.2))),
+        ("shadow".into(), format!(":foreground \"{}\"", fg_dim)),
+        ("minibuffer-prompt".into(), format!(":foreground \"{}\" :weight bold", c.accent)),
+        ("link".into(), format!(":foreground \"{}\" :underline t", c.blue)),
+        ("error".into(), format!(":foreground \"{}\" :weight bold", role("error"))),
+        ("warning".into(), format!(":foreground \"{}\" :weight bold", role("warning"))),
+        ("success".into(), format!(":foreground \"{}\" :weight bold", c.green)),
+        ("line-number".into(), format!(":foreground \"{}\" :background \"{}\"", fg_dim, c.bg)),
+        ("line-number-current-line".into(), format!(":foreground \"{}\" :background \"{}\" :weight bold", c.accent, surface)),
+        ("vertical-border".into(), format!(":foreground \"{}\"", surface)),
+        ("window-divider".into(), format!(":foreground \"{}\"", surface)),
+        ("mode-line".into(), format!(":foreground \"{}\" :background \"{}\" :box (:line-width 1 :color \"{}\")", c.fg, surface, c.accent)),
+        ("mode-line-inactive".into(), format!(":foreground \"{}\" :background \"{}\" :box (:line-width 1 :color \"{}\")", fg_dim, c.bg, surface)),
+        ("mode-line-buffer-id".into(), format!(":foreground \"{}\" :weight bold", c.accent)),
+        ("header-line".into(), format!(":foreground \"{}\" :background \"{}\"", c.fg, surface)),
+        ("isearch".into(), format!(":foreground \"{}\" :background \"{}\" :weight bold", c.bg, c.accent)),
+        ("lazy-highlight".into(), format!(":foreground \"{}\" :background \"{}\"", c.bg, c.yellow)),
+        ("show-paren-match".into(), format!(":foreground \"{}\" :background \"{}\" :weight bold", c.accent, surface)),
+        ("show-paren-mismatch".into(), format!(":foreground \"{}\" :background \"{}\"", c.bg, role("error"))),
+        ("trailing-whitespace".into(), format!(":background \"{}\"", tint("error", 0.4))),
+    ];
+
+    for (face, role_name) in FONT_LOCK_FACES {
+        let slant = if role_name == &"comment" { " :slant italic" } else { "" };
+        faces.push((face.to_string(), format!(":foreground \"{}\"{}", role(role_name), slant)));
+    }
+
+    // diff-mode, also used by magit and vc
+    faces.extend([
+        ("diff-added".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_add"), tint("diff_add", 0.2))),
+        ("diff-removed".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_delete"), tint("diff_delete", 0.2))),
+        ("diff-changed".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_change"), tint("diff_change", 0.2))),
+        ("diff-refine-added".into(), format!(":background \"{}\"", tint("diff_add", 0.4))),
+        ("diff-refine-removed".into(), format!(":background \"{}\"", tint("diff_delete", 0.4))),
+        ("diff-refine-changed".into(), format!(":background \"{}\"", tint("diff_change", 0.4))),
+        ("diff-header".into(), format!(":foreground \"{}\" :background \"{}\"", c.fg, surface)),
+        ("diff-file-header".into(), format!(":foreground \"{}\" :background \"{}\" :weight bold", c.accent, surface)),
+        ("diff-hunk-header".into(), format!(":foreground \"{}\" :background \"{}\"", role("diff_text"), surface)),
+    ]);
+
+    // magit basics
+    faces.extend([
+        ("magit-section-heading".into(), format!(":foreground \"{}\" :weight bold", c.accent)),
+        ("magit-section-highlight".into(), format!(":background \"{}\" :extend t", surface)),
+        ("magit-branch-local".into(), format!(":foreground \"{}\"", c.blue)),
+        ("magit-branch-remote".into(), format!(":foreground \"{}\"", c.green)),
+        ("magit-branch-current".into(), format!(":foreground \"{}\" :box t", c.blue)),
+        ("magit-hash".into(), format!(":foreground \"{}\"", fg_dim)),
+        ("magit-tag".into(), format!(":foreground \"{}\"", c.yellow)),
+        ("magit-diff-context".into(), format!(":foreground \"{}\" :extend t", fg_dim)),
+        ("magit-diff-context-highlight".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", c.fg, surface)),
+        ("magit-diff-added".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_add"), tint("diff_add", 0.15))),
+        ("magit-diff-added-highlight".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_add"), tint("diff_add", 0.25))),
+        ("magit-diff-removed".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_delete"), tint("diff_delete", 0.15))),
+        ("magit-diff-removed-highlight".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", role("diff_delete"), tint("diff_delete", 0.25))),
+        ("magit-diff-hunk-heading".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", fg_dim, surface)),
+        ("magit-diff-hunk-heading-highlight".into(), format!(":foreground \"{}\" :background \"{}\" :extend t", c.bg, c.accent)),
+        ("magit-diff-file-heading".into(), format!(":foreground \"{}\" :weight bold", c.fg)),
+    ]);
+
+    output.push_str("(custom-theme-set-faces\n");
+    output.push_str(&format!(" '{}\n", name));
+    for (face, attributes) in &faces {
+        output.push_str(&format!(" '({} ((t ({}))))\n", face, attributes));
+    }
+    output.push_str(")\n\n");
+
+    // ansi-color and term use the 8 normal colors
+    let palette = ansi_palette(theme);
+    let ansi: Vec<String> = palette[..8].iter().map(|color| format!("\"{}\"", color)).collect();
+    output.push_str("(custom-theme-set-variables\n");
+    output.push_str(&format!(" '{}\n", name));
+    output.push_str(&format!(" '(ansi-color-names-vector [{}]))\n\n", ansi.join(" ")));
+
+    output.push_str(&format!("(provide-theme '{})\n\n", name));
+    output.push_str(&format!(";;; {}-theme.el ends here\n", name));
+
+    Ok(output)
+}
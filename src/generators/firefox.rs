@@ -0,0 +1,218 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use crate::theme::Theme;
+use crate::utils::mix_colors;
+use super::syntax::derived_colors;
+
+const BLOCK_START: &str = "// BEGIN themectl";
+const BLOCK_END: &str = "// END themectl";
+
+/// Browser surface colors shared by userChrome.css and userContent.css
+struct Surfaces {
+    frame: String,
+    toolbar: String,
+    field: String,
+    field_focus: String,
+    border: String,
+    hover: String,
+}
+
+fn surfaces(theme: &Theme) -> Surfaces {
+    let c = &theme.colors;
+    let mix = |weight: f32| mix_colors(&c.fg, &c.bg, weight).unwrap_or_else(|| c.bg.clone());
+    Surfaces {
+        frame: c.bg.clone(),
+        toolbar: mix(0.04),
+        field: mix(0.08),
+        field_focus: mix(0.12),
+        border: mix(0.18),
+        hover: mix(0.14),
+    }
+}
+
+/// Generate userChrome.css overriding the browser UI colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let c = &theme.colors;
+    let s = surfaces(theme);
+    let (_, fg_dim) = derived_colors(theme);
+
+    output.push_str("/* Firefox userChrome.css: ");
+    output.push_str(&theme.name);
+    output.push_str(" */\n/* Generated by themectl */\n");
+    output.push_str("/* Requires toolkit.legacyUserProfileCustomizations.stylesheets = true in user.js */\n\n");
+
+    let variables = [
+        ("--lwt-accent-color", s.frame.clone()),
+        ("--lwt-accent-color-inactive", s.frame.clone()),
+        ("--lwt-text-color", c.fg.clone()),
+        ("--toolbox-bgcolor", s.frame.clone()),
+        ("--toolbox-textcolor", c.fg.clone()),
+        ("--toolbar-bgcolor", s.toolbar.clone()),
+        ("--toolbar-color", c.fg.clone()),
+        ("--toolbar-field-background-color", s.field.clone()),
+        ("--toolbar-field-color", c.fg.clone()),
+        ("--toolbar-field-border-color", s.border.clone()),
+        ("--toolbar-field-focus-background-color", s.field_focus.clone()),
+        ("--toolbar-field-focus-color", c.fg.clone()),
+        ("--toolbar-field-focus-border-color", c.accent.clone()),
+        ("--tab-selected-bgcolor", s.toolbar.clone()),
+        ("--tab-selected-textcolor", c.fg.clone()),
+        ("--tab-loading-fill", c.accent.clone()),
+        ("--tab-hover-background-color", s.hover.clone()),
+        ("--tabpanel-background-color", c.bg.clone()),
+        ("--tabs-navbar-separator-color", s.border.clone()),
+        ("--urlbar-box-bgcolor", s.field.clone()),
+        ("--urlbar-box-text-color", c.fg.clone()),
+        ("--urlbarView-highlight-background", c.accent.clone()),
+        ("--urlbarView-highlight-color", c.bg.clone()),
+        ("--urlbarView-separator-color", s.border.clone()),
+        ("--arrowpanel-background", s.toolbar.clone()),
+        ("--arrowpanel-color", c.fg.clone()),
+        ("--arrowpanel-border-color", s.border.clone()),
+        ("--sidebar-background-color", s.toolbar.clone()),
+        ("--sidebar-text-color", c.fg.clone()),
+        ("--sidebar-border-color", s.border.clone()),
+        ("--lwt-sidebar-background-color", s.toolbar.clone()),
+        ("--lwt-sidebar-text-color", c.fg.clone()),
+        ("--chrome-content-separator-color", s.border.clone()),
+        ("--focus-outline-color", c.accent.clone()),
+        ("--button-hover-bgcolor", s.hover.clone()),
+        ("--checkbox-checked-bgcolor", c.accent.clone()),
+        ("--link-color", c.blue.clone()),
+        ("--text-color-deemphasized", fg_dim),
+    ];
+
+    output.push_str(":root {\n");
+    for (name, value) in variables {
+        output.push_str(&format!("  {}: {} !important;\n", name, value));
+    }
+    output.push_str("}\n\n");
+
+    output.push_str(".tab-background[selected] {\n");
+    output.push_str(&format!("  background-color: {} !important;\n", s.toolbar));
+    output.push_str(&format!("  border-top: {}px solid {} !important;\n", theme.properties.border_width.unwrap_or(2), c.accent));
+    output.push_str("}\n\n");
+
+    output.push_str("#urlbar[focused] > #urlbar-background {\n");
+    output.push_str(&format!("  outline-color: {} !important;\n", c.accent));
+    output.push_str("}\n");
+
+    Ok(output)
+}
+
+/// Generate userContent.css coloring the new tab and other about: pages
+pub fn generate_user_content(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let c = &theme.colors;
+    let s = surfaces(theme);
+    let (_, fg_dim) = derived_colors(theme);
+
+    output.push_str("/* Firefox userContent.css: ");
+    output.push_str(&theme.name);
+    output.push_str(" */\n/* Generated by themectl */\n\n");
+
+    output.push_str("@-moz-document url-prefix(\"about:newtab\"), url-prefix(\"about:home\"), url-prefix(\"about:privatebrowsing\"), url(\"about:blank\") {\n");
+    let variables = [
+        ("--newtab-background-color", c.bg.clone()),
+        ("--newtab-background-color-secondary", s.field.clone()),
+        ("--newtab-text-primary-color", c.fg.clone()),
+        ("--newtab-text-secondary-color", fg_dim.clone()),
+        ("--newtab-primary-action-background", c.accent.clone()),
+        ("--newtab-element-hover-color", s.hover.clone()),
+        ("--newtab-element-active-color", s.field_focus.clone()),
+        ("--newtab-border-color", s.border.clone()),
+        ("--newtab-wordmark-color", c.fg.clone()),
+        ("--in-content-page-background", c.bg.clone()),
+        ("--in-content-page-color", c.fg.clone()),
+    ];
+    output.push_str("  :root {\n");
+    for (name, value) in variables {
+        output.push_str(&format!("    {}: {} !important;\n", name, value));
+    }
+    output.push_str("  }\n\n");
+    output.push_str("  body {\n");
+    output.push_str(&format!("    background-color: {} !important;\n", c.bg));
+    output.push_str(&format!("    color: {} !important;\n", c.fg));
+    output.push_str("  }\n");
+    output.push_str("}\n\n");
+
+    output.push_str("@-moz-document url-prefix(\"about:\") {\n");
+    output.push_str("  :root {\n");
+    output.push_str(&format!("    scrollbar-color: {} {} !important;\n", fg_dim, c.bg));
+    output.push_str("  }\n");
+    output.push_str("}\n");
+
+    Ok(output)
+}
+
+/// Ensure user.js enables userChrome.css/userContent.css inside a managed block
+///
+/// The block is replaced in place when present and appended otherwise, so the
+/// user's own preferences are kept.
+pub fn update_user_js(existing: &str) -> String {
+    let block = format!(
+        "{}\n// Managed by themectl; changes inside this block are overwritten\nuser_pref(\"toolkit.legacyUserProfileCustomizations.stylesheets\", true);\n{}\n",
+        BLOCK_START, BLOCK_END
+    );
+
+    if let (Some(start), Some(end)) = (existing.find(BLOCK_START), existing.find(BLOCK_END)) {
+        if start < end {
+            let after = &existing[end + BLOCK_END.len()..];
+            let after = after.strip_prefix('\n').unwrap_or(after);
+            return format!("{}{}{}", &existing[..start], block, after);
+        }
+    }
+
+    let mut output = existing.to_string();
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    output.push_str(&block);
+    output
+}
+
+/// Profile directories listed in a Firefox profiles.ini
+pub fn profile_dirs(profiles_ini: &str, firefox_dir: &Path) -> Vec<PathBuf> {
+    let mut profiles = Vec::new();
+    let mut path: Option<String> = None;
+    let mut is_relative = true;
+    let mut in_profile = false;
+
+    let mut finish = |path: &mut Option<String>, is_relative: bool| {
+        if let Some(path) = path.take() {
+            let dir = if is_relative { firefox_dir.join(&path) } else { PathBuf::from(&path) };
+            if !profiles.contains(&dir) {
+                profiles.push(dir);
+            }
+        }
+    };
+
+    for line in profiles_ini.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            if in_profile {
+                finish(&mut path, is_relative);
+            }
+            in_profile = line.starts_with("[Profile");
+            path = None;
+            is_relative = true;
+        } else if in_profile {
+            if let Some((key, value)) = line.split_once('=') {
+                match key.trim() {
+                    "Path" => path = Some(value.trim().to_string()),
+                    "IsRelative" => is_relative = value.trim() != "0",
+                    _ => {}
+                }
+            }
+        }
+    }
+    if in_profile {
+        finish(&mut path, is_relative);
+    }
+
+    profiles
+}
@@ -0,0 +1,75 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::mix_colors;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+/// fish_color_* and fish_pager_color_* variables and their values
+pub(crate) fn variables(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let roles = SyntaxRoles::from_theme(theme);
+    let (_, fg_dim) = derived_colors(theme);
+    let role = |name: &str| hex(&resolve(theme, roles.key(name)));
+    let selection_bg = hex(&mix_colors(&c.fg, &c.bg, 0.15).unwrap_or_else(|| c.bg.clone()));
+
+    vec![
+        ("fish_color_normal", hex(&c.fg)),
+        ("fish_color_command", role("function")),
+        ("fish_color_keyword", role("keyword")),
+        ("fish_color_quote", role("string")),
+        ("fish_color_redirection", role("operator")),
+        ("fish_color_end", role("operator")),
+        ("fish_color_error", role("error")),
+        ("fish_color_param", role("variable")),
+        ("fish_color_valid_path", "--underline".to_string()),
+        ("fish_color_option", role("property")),
+        ("fish_color_comment", role("comment")),
+        ("fish_color_operator", role("special")),
+        ("fish_color_escape", role("special")),
+        ("fish_color_autosuggestion", hex(&fg_dim)),
+        ("fish_color_selection", format!("--background={}", selection_bg)),
+        ("fish_color_search_match", format!("--background={}", selection_bg)),
+        ("fish_color_history_current", "--bold".to_string()),
+        ("fish_color_cancel", format!("-r {}", hex(&c.red))),
+        ("fish_color_host", hex(&c.green)),
+        ("fish_color_host_remote", hex(&c.yellow)),
+        ("fish_color_user", hex(&c.blue)),
+        ("fish_color_cwd", hex(&c.accent)),
+        ("fish_color_cwd_root", hex(&c.red)),
+        ("fish_color_status", hex(&c.red)),
+        ("fish_pager_color_progress", hex(&c.accent)),
+        ("fish_pager_color_prefix", format!("{} --bold", hex(&c.accent))),
+        ("fish_pager_color_completion", hex(&c.fg)),
+        ("fish_pager_color_description", hex(&fg_dim)),
+        ("fish_pager_color_selected_background", format!("--background={}", selection_bg)),
+    ]
+}
+
+/// `set` commands for every color variable in the given scope (`-U` or `-g`)
+pub(crate) fn set_commands(theme: &Theme, scope: &str) -> Vec<String> {
+    variables(theme).into_iter()
+        .map(|(name, value)| format!("set {} {} {}", scope, name, value))
+        .collect()
+}
+
+/// Generate fish_color_* universal variable commands
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# fish colors: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Universal variables persist, so source this once:\n");
+    output.push_str("#   source ~/.config/fish/themectl-colors.fish\n\n");
+
+    for command in set_commands(theme, "-U") {
+        output.push_str(&command);
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// fish takes colors as RRGGBB without '#'
+fn hex(color: &str) -> String {
+    color.trim_start_matches('#').to_lowercase()
+}
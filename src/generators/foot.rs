@@ -0,0 +1,44 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::palette::ansi_palette;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let palette = ansi_palette(theme);
+    
+    output.push_str("# Foot theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Include it from ~/.config/foot/foot.ini:\n");
+    output.push_str("#   include=~/.config/foot/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".ini\n\n");
+    
+    output.push_str("[colors]\n");
+    output.push_str(&format!("foreground={}\n", foot_color(&theme.colors.fg)));
+    output.push_str(&format!("background={}\n", foot_color(&theme.colors.bg)));
+    
+    // Foot expects bare RRGGBB values
+    for (i, color) in palette[..8].iter().enumerate() {
+        output.push_str(&format!("regular{}={}\n", i, foot_color(color)));
+    }
+    for (i, color) in palette[8..].iter().enumerate() {
+        output.push_str(&format!("bright{}={}\n", i, foot_color(color)));
+    }
+    
+    output.push_str(&format!("selection-foreground={}\n", foot_color(&theme.colors.bg)));
+    output.push_str(&format!("selection-background={}\n", foot_color(&theme.colors.accent)));
+    // cursor=<text color> <cursor color>
+    output.push_str(&format!(
+        "cursor={} {}\n",
+        foot_color(&theme.colors.bg),
+        foot_color(&theme.colors.accent)
+    ));
+    output.push_str(&format!("urls={}\n", foot_color(&theme.colors.cyan)));
+    
+    Ok(output)
+}
+
+fn foot_color(hex: &str) -> &str {
+    hex.trim_start_matches('#')
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let (_, fg_dim) = derived_colors(theme);
+
+    output.push_str("# Fuzzel theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Include it from ~/.config/fuzzel/fuzzel.ini:\n");
+    output.push_str("#   [main]\n");
+    output.push_str("#   include=~/.config/fuzzel/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".ini\n\n");
+
+    output.push_str("[colors]\n");
+    let colors = [
+        ("background", theme.colors.bg.as_str()),
+        ("text", theme.colors.fg.as_str()),
+        ("prompt", theme.colors.accent.as_str()),
+        ("placeholder", fg_dim.as_str()),
+        ("input", theme.colors.fg.as_str()),
+        ("match", theme.colors.accent.as_str()),
+        ("selection", theme.colors.accent.as_str()),
+        ("selection-text", theme.colors.bg.as_str()),
+        ("selection-match", theme.colors.bg.as_str()),
+        ("counter", fg_dim.as_str()),
+        ("border", theme.colors.accent.as_str()),
+    ];
+    for (key, color) in colors {
+        output.push_str(&format!("{}={}\n", key, fuzzel_color(color)));
+    }
+
+    output.push_str("\n[border]\n");
+    output.push_str(&format!("width={}\n", theme.properties.border_width.unwrap_or(2)));
+    output.push_str(&format!("radius={}\n", theme.properties.border_radius.unwrap_or(0)));
+
+    Ok(output)
+}
+
+/// Fuzzel colors are RRGGBBAA without a leading '#'
+fn fuzzel_color(hex: &str) -> String {
+    format!("{}ff", hex.trim_start_matches('#').to_lowercase())
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::mix_colors;
+use super::syntax::derived_colors;
+
+/// fzf color slots and their hex colors
+pub(crate) fn colors(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let selected_bg = mix_colors(&c.fg, &c.bg, 0.1).unwrap_or_else(|| c.bg.clone());
+
+    vec![
+        ("fg", c.fg.clone()),
+        ("bg", c.bg.clone()),
+        ("hl", c.accent.clone()),
+        ("fg+", c.fg.clone()),
+        ("bg+", selected_bg),
+        ("hl+", c.accent.clone()),
+        ("info", c.cyan.clone()),
+        ("prompt", c.blue.clone()),
+        ("pointer", c.accent.clone()),
+        ("marker", c.green.clone()),
+        ("spinner", c.magenta.clone()),
+        ("border", fg_dim),
+    ]
+}
+
+/// The `--color=` option for FZF_DEFAULT_OPTS
+pub(crate) fn color_option(theme: &Theme) -> String {
+    let colors: Vec<String> = colors(theme).iter()
+        .map(|(slot, color)| format!("{}:{}", slot, color))
+        .collect();
+    format!("--color={}", colors.join(","))
+}
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# fzf theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# bash/zsh: add to ~/.bashrc or ~/.zshrc:\n");
+    output.push_str("#   [ -f ~/.config/fzf/themectl.sh ] && . ~/.config/fzf/themectl.sh\n");
+    output.push_str("# fish: set -gx FZF_DEFAULT_OPTS with the same --color option in config.fish\n\n");
+
+    output.push_str(&format!("export FZF_DEFAULT_OPTS=\"$FZF_DEFAULT_OPTS {}\"\n", color_option(theme)));
+
+    Ok(output)
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::palette::ansi_palette;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let palette = ansi_palette(theme);
+    
+    output.push_str("# Ghostty theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Select it in ~/.config/ghostty/config:\n");
+    output.push_str("#   theme = ");
+    output.push_str(&theme.name);
+    output.push_str("\n\n");
+    
+    for (i, color) in palette.iter().enumerate() {
+        output.push_str(&format!("palette = {}={}\n", i, color));
+    }
+    output.push_str(&format!("background = {}\n", theme.colors.bg));
+    output.push_str(&format!("foreground = {}\n", theme.colors.fg));
+    output.push_str(&format!("cursor-color = {}\n", theme.colors.accent));
+    output.push_str(&format!("cursor-text = {}\n", theme.colors.bg));
+    output.push_str(&format!("selection-background = {}\n", theme.colors.accent));
+    output.push_str(&format!("selection-foreground = {}\n", theme.colors.bg));
+    
+    Ok(output)
+}
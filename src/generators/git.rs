@@ -70,12 +70,99 @@ fn hex_to_git_color(hex: &str) -> &'static str {
     }
 }
 
-pub fn generate(theme: &Theme) -> Result<String> {
+/// Git color sections: (section, [(slot, hex color or git color, attributes)])
+type GitColorSection = (&'static str, Vec<(&'static str, String, &'static str)>);
+
+fn sections(theme: &Theme) -> Vec<GitColorSection> {
+    let c = &theme.colors;
+    vec![
+        ("diff", vec![
+            ("meta", c.yellow.clone(), "bold"),
+            ("frag", c.magenta.clone(), "bold"),
+            ("old", c.red.clone(), "bold"),
+            ("new", c.green.clone(), "bold"),
+            ("whitespace", c.red.clone(), "reverse"),
+            ("commit", c.cyan.clone(), "bold"),
+            ("func", c.blue.clone(), "bold"),
+        ]),
+        ("branch", vec![
+            ("current", c.yellow.clone(), "reverse"),
+            ("local", c.yellow.clone(), ""),
+            ("remote", c.green.clone(), ""),
+            ("upstream", c.cyan.clone(), ""),
+            ("plain", c.fg.clone(), ""),
+        ]),
+        ("status", vec![
+            ("added", c.green.clone(), "bold"),
+            ("changed", c.yellow.clone(), "bold"),
+            ("untracked", c.red.clone(), ""),
+            ("deleted", c.red.clone(), "bold"),
+            ("branch", c.cyan.clone(), "bold"),
+            ("header", c.accent.clone(), "bold"),
+            ("nobranch", c.red.clone(), ""),
+        ]),
+        // Interactive colors (for git add -p, etc.)
+        ("interactive", vec![
+            ("prompt", c.accent.clone(), "bold"),
+            ("header", c.cyan.clone(), "bold"),
+            ("help", c.yellow.clone(), "bold"),
+            ("error", c.red.clone(), "bold"),
+            ("reset", "normal".to_string(), ""),
+        ]),
+        // Grep colors (for git grep)
+        ("grep", vec![
+            ("match", c.yellow.clone(), "bold"),
+            ("context", c.fg.clone(), ""),
+            ("filename", c.cyan.clone(), "bold"),
+            ("function", c.blue.clone(), "bold"),
+            ("lineNumber", c.magenta.clone(), "bold"),
+            ("column", c.blue.clone(), "bold"),
+            ("selected", c.accent.clone(), "reverse"),
+            ("separator", c.fg.clone(), ""),
+        ]),
+    ]
+}
+
+/// Exact `#rrggbb` in truecolor mode, otherwise the nearest of git's named colors
+fn git_color(color: &str, truecolor: bool) -> String {
+    if !color.starts_with('#') {
+        color.to_string()
+    } else if truecolor {
+        color.to_lowercase()
+    } else {
+        hex_to_git_color(color).to_string()
+    }
+}
+
+/// Color value with its attributes, e.g. `#d79921 bold`
+fn color_value(color: &str, attributes: &str, truecolor: bool) -> String {
+    let color = git_color(color, truecolor);
+    if attributes.is_empty() {
+        color
+    } else {
+        format!("{} {}", color, attributes)
+    }
+}
+
+/// Color slots per section with their final values, shared with the Home Manager module
+pub(crate) fn color_sections(theme: &Theme, truecolor: bool) -> Vec<(&'static str, Vec<(&'static str, String)>)> {
+    sections(theme).into_iter()
+        .map(|(section, slots)| {
+            let slots = slots.into_iter()
+                .map(|(slot, color, attributes)| (slot, color_value(&color, attributes, truecolor)))
+                .collect();
+            (section, slots)
+        })
+        .collect()
+}
+
+/// Generate a git color config with `#rrggbb` colors or the 16-color fallback
+pub fn generate_with_truecolor(theme: &Theme, truecolor: bool) -> Result<String> {
     let mut output = String::new();
     
     output.push_str("# Git color configuration: ");
     output.push_str(&theme.name);
-    output.push_str("\n");
+    output.push('\n');
     output.push_str("# Generated by themectl\n");
     output.push_str("# Add this to your ~/.gitconfig file or use: git config --global --add include.path ~/.config/git/themes/");
     output.push_str(&theme.name);
@@ -83,127 +170,32 @@ pub fn generate(theme: &Theme) -> Result<String> {
     
     // Enable color UI
     output.push_str("[color]\n");
-    output.push_str("    ui = auto\n\n");
-    
-    // Diff colors
-    output.push_str("[color \"diff\"]\n");
-    output.push_str("    meta = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\n");
-    output.push_str("    frag = ");
-    output.push_str(hex_to_git_color(&theme.colors.magenta));
-    output.push_str(" bold\n");
-    output.push_str("    old = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\n");
-    output.push_str("    new = ");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str(" bold\n");
-    output.push_str("    whitespace = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" reverse\n");
-    output.push_str("    commit = ");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\n");
-    output.push_str("    func = ");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\n\n");
-    
-    // Branch colors
-    output.push_str("[color \"branch\"]\n");
-    output.push_str("    current = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" reverse\n");
-    output.push_str("    local = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str("\n");
-    output.push_str("    remote = ");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str("\n");
-    output.push_str("    upstream = ");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str("\n");
-    output.push_str("    plain = ");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\n\n");
-    
-    // Status colors
-    output.push_str("[color \"status\"]\n");
-    output.push_str("    added = ");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str(" bold\n");
-    output.push_str("    changed = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\n");
-    output.push_str("    untracked = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str("\n");
-    output.push_str("    deleted = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\n");
-    output.push_str("    branch = ");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\n");
-    output.push_str("    header = ");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" bold\n");
-    output.push_str("    nobranch = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str("\n\n");
-    
-    // Interactive colors (for git add -p, etc.)
-    output.push_str("[color \"interactive\"]\n");
-    output.push_str("    prompt = ");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" bold\n");
-    output.push_str("    header = ");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\n");
-    output.push_str("    help = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\n");
-    output.push_str("    error = ");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\n");
-    output.push_str("    reset = normal\n\n");
-    
-    // Grep colors (for git grep)
-    output.push_str("[color \"grep\"]\n");
-    output.push_str("    match = ");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\n");
-    output.push_str("    context = ");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\n");
-    output.push_str("    filename = ");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\n");
-    output.push_str("    function = ");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\n");
-    output.push_str("    lineNumber = ");
-    output.push_str(hex_to_git_color(&theme.colors.magenta));
-    output.push_str(" bold\n");
-    output.push_str("    column = ");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\n");
-    output.push_str("    selected = ");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" reverse\n");
-    output.push_str("    separator = ");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\n");
+    output.push_str("    ui = auto\n");
+    
+    for (section, slots) in sections(theme) {
+        output.push_str(&format!("\n[color \"{}\"]\n", section));
+        for (slot, color, attributes) in slots {
+            let value = color_value(&color, attributes, truecolor);
+            // Quote hex colors, '#' would otherwise start a comment
+            let value = match value.split_once(' ') {
+                _ if !value.starts_with('#') => value,
+                Some((hex, rest)) => format!("\"{}\" {}", hex, rest),
+                None => format!("\"{}\"", value),
+            };
+            output.push_str(&format!("    {} = {}\n", slot, value));
+        }
+    }
     
     Ok(output)
 }
 
-/// Generate Home Manager-compatible Nix code for Git color configuration
-pub fn generate_nix(theme: &Theme) -> Result<String> {
+/// Generate the Home Manager git colors with `#rrggbb` colors or the 16-color fallback
+pub fn generate_nix_with_truecolor(theme: &Theme, truecolor: bool) -> Result<String> {
     let mut output = String::new();
     
     output.push_str("# Git color configuration for Home Manager: ");
     output.push_str(&theme.name);
-    output.push_str("\n");
+    output.push('\n');
     output.push_str("# Generated by themectl\n");
     output.push_str("# Add this to your Home Manager configuration:\n");
     output.push_str("#   programs.git.extraConfig = ");
@@ -216,111 +208,15 @@ pub fn generate_nix(theme: &Theme) -> Result<String> {
     output.push_str("GitColors = {\n");
     
     // Color UI
-    output.push_str("    color.ui = \"auto\";\n\n");
+    output.push_str("    color.ui = \"auto\";\n");
     
-    // Diff colors
-    output.push_str("    \"color.diff.meta\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.diff.frag\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.magenta));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.diff.old\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.diff.new\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.diff.whitespace\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" reverse\";\n");
-    output.push_str("    \"color.diff.commit\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.diff.func\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\";\n\n");
-    
-    // Branch colors
-    output.push_str("    \"color.branch.current\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" reverse\";\n");
-    output.push_str("    \"color.branch.local\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str("\";\n");
-    output.push_str("    \"color.branch.remote\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str("\";\n");
-    output.push_str("    \"color.branch.upstream\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str("\";\n");
-    output.push_str("    \"color.branch.plain\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\";\n\n");
-    
-    // Status colors
-    output.push_str("    \"color.status.added\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.green));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.status.changed\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.status.untracked\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str("\";\n");
-    output.push_str("    \"color.status.deleted\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.status.branch\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.status.header\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.status.nobranch\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str("\";\n\n");
-    
-    // Interactive colors
-    output.push_str("    \"color.interactive.prompt\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.interactive.header\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.interactive.help\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.interactive.error\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.red));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.interactive.reset\" = \"normal\";\n\n");
-    
-    // Grep colors
-    output.push_str("    \"color.grep.match\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.yellow));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.grep.context\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\";\n");
-    output.push_str("    \"color.grep.filename\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.cyan));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.grep.function\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.grep.lineNumber\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.magenta));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.grep.column\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.blue));
-    output.push_str(" bold\";\n");
-    output.push_str("    \"color.grep.selected\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.accent));
-    output.push_str(" reverse\";\n");
-    output.push_str("    \"color.grep.separator\" = \"");
-    output.push_str(hex_to_git_color(&theme.colors.fg));
-    output.push_str("\";\n");
+    for (section, slots) in sections(theme) {
+        output.push('\n');
+        for (slot, color, attributes) in slots {
+            output.push_str(&format!("    \"color.{}.{}\" = \"{}\";\n",
+                section, slot, color_value(&color, attributes, truecolor)));
+        }
+    }
     
     output.push_str("  };\n");
     output.push_str("in\n");
@@ -1,10 +1,21 @@
 use anyhow::Result;
 use crate::theme::Theme;
 use crate::utils::{hex_to_rgb, darken_color, lighten_color};
+use super::syntax::derived_colors;
 
+/// Generate GTK4 settings.ini
 pub fn generate(theme: &Theme) -> Result<String> {
-    // Determine if this is a dark or light theme
-    let is_dark = theme.get_variant()
+    Ok(settings(theme, 4))
+}
+
+/// Generate GTK3 settings.ini (without GTK4-only keys)
+pub fn generate_gtk3(theme: &Theme) -> Result<String> {
+    Ok(settings(theme, 3))
+}
+
+/// Whether the theme is dark, from its variant or else by comparing bg/fg luma
+pub(crate) fn is_dark(theme: &Theme) -> bool {
+    theme.get_variant()
         .map(|v| v == "dark")
         .unwrap_or_else(|| {
             // Heuristic: if background is darker than foreground, it's dark
@@ -16,18 +27,30 @@ pub fn generate(theme: &Theme) -> Result<String> {
             } else {
                 true // Default to dark
             }
-        });
+        })
+}
 
+/// The org.gnome.desktop.interface color-scheme value matching the theme
+pub(crate) fn color_scheme(theme: &Theme) -> &'static str {
+    if is_dark(theme) { "prefer-dark" } else { "prefer-light" }
+}
+
+fn settings(theme: &Theme, gtk_version: u8) -> String {
+    let is_dark = is_dark(theme);
     let mut output = String::new();
     
     // Generate settings.ini
     output.push_str("# GTK theme configuration: ");
     output.push_str(&theme.name);
     output.push_str("\n");
-    output.push_str("# Place this file at: ~/.config/gtk-4.0/settings.ini\n\n");
+    output.push_str(&format!("# Place this file at: ~/.config/gtk-{}.0/settings.ini\n\n", gtk_version));
     
     output.push_str("[Settings]\n");
     output.push_str(&format!("gtk-application-prefer-dark-theme={}\n", is_dark));
+    if gtk_version >= 4 {
+        // Read by GTK 4.20+; libadwaita follows the desktop color-scheme setting
+        output.push_str(&format!("gtk-interface-color-scheme={}\n", if is_dark { "dark" } else { "light" }));
+    }
     output.push_str("gtk-theme-name=Adwaita");
     if is_dark {
         output.push_str("-dark");
@@ -47,7 +70,75 @@ pub fn generate(theme: &Theme) -> Result<String> {
     }
     output.push_str("\n");
     
-    Ok(output)
+    output
+}
+
+/// libadwaita named colors, in the order libadwaita documents them
+pub(crate) fn named_colors(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let dark = is_dark(theme);
+    let lighten = |factor: f32| lighten_color(&c.bg, factor).unwrap_or_else(|| c.bg.clone());
+    let darken = |factor: f32| darken_color(&c.bg, factor).unwrap_or_else(|| c.bg.clone());
+    
+    // Dark themes raise surfaces by lightening, light themes sit on near-white surfaces
+    let (view, headerbar, sidebar, secondary_sidebar, card, popover) = if dark {
+        (darken(0.15), lighten(0.08), lighten(0.08), lighten(0.04), lighten(0.06), lighten(0.12))
+    } else {
+        (lighten(0.5), lighten(0.3), darken(0.05), darken(0.03), lighten(0.5), lighten(0.5))
+    };
+    let shade = if dark { "rgba(0, 0, 0, 0.36)" } else { "rgba(0, 0, 0, 0.07)" }.to_string();
+    let darker_shade = if dark { "rgba(0, 0, 0, 0.9)" } else { "rgba(0, 0, 0, 0.12)" }.to_string();
+    let border = format!("alpha({}, 0.15)", c.fg);
+    
+    vec![
+        ("accent_color", c.accent.clone()),
+        ("accent_bg_color", c.accent.clone()),
+        ("accent_fg_color", c.bg.clone()),
+        ("destructive_color", c.red.clone()),
+        ("destructive_bg_color", c.red.clone()),
+        ("destructive_fg_color", c.bg.clone()),
+        ("success_color", c.green.clone()),
+        ("success_bg_color", c.green.clone()),
+        ("success_fg_color", c.bg.clone()),
+        ("warning_color", c.yellow.clone()),
+        ("warning_bg_color", c.yellow.clone()),
+        ("warning_fg_color", c.bg.clone()),
+        ("error_color", c.red.clone()),
+        ("error_bg_color", c.red.clone()),
+        ("error_fg_color", c.bg.clone()),
+        ("window_bg_color", c.bg.clone()),
+        ("window_fg_color", c.fg.clone()),
+        ("view_bg_color", view),
+        ("view_fg_color", c.fg.clone()),
+        ("headerbar_bg_color", headerbar),
+        ("headerbar_fg_color", c.fg.clone()),
+        ("headerbar_border_color", c.fg.clone()),
+        ("headerbar_backdrop_color", c.bg.clone()),
+        ("headerbar_shade_color", shade.clone()),
+        ("headerbar_darker_shade_color", darker_shade),
+        ("sidebar_bg_color", sidebar),
+        ("sidebar_fg_color", c.fg.clone()),
+        ("sidebar_backdrop_color", c.bg.clone()),
+        ("sidebar_shade_color", shade.clone()),
+        ("sidebar_border_color", border.clone()),
+        ("secondary_sidebar_bg_color", secondary_sidebar),
+        ("secondary_sidebar_fg_color", c.fg.clone()),
+        ("secondary_sidebar_backdrop_color", c.bg.clone()),
+        ("secondary_sidebar_shade_color", shade.clone()),
+        ("secondary_sidebar_border_color", border),
+        ("card_bg_color", card),
+        ("card_fg_color", c.fg.clone()),
+        ("card_shade_color", shade.clone()),
+        ("dialog_bg_color", popover.clone()),
+        ("dialog_fg_color", c.fg.clone()),
+        ("popover_bg_color", popover.clone()),
+        ("popover_fg_color", c.fg.clone()),
+        ("popover_shade_color", shade.clone()),
+        ("thumbnail_bg_color", popover),
+        ("thumbnail_fg_color", c.fg.clone()),
+        ("shade_color", shade),
+        ("scrollbar_outline_color", if dark { "rgba(0, 0, 0, 0.5)" } else { "#ffffff" }.to_string()),
+    ]
 }
 
 /// Generate GTK CSS customization file
@@ -61,24 +152,27 @@ pub fn generate_css(theme: &Theme) -> Result<String> {
     output.push_str("/* GTK CSS theme: ");
     output.push_str(&theme.name);
     output.push_str(" */\n");
-    output.push_str("/* Place this file at: ~/.config/gtk-4.0/gtk.css */\n\n");
-    
-    // Define CSS variables for theme colors
-    output.push_str("@define-color theme_bg_color ");
-    output.push_str(&theme.colors.bg);
-    output.push_str(";\n");
-    
-    output.push_str("@define-color theme_fg_color ");
-    output.push_str(&theme.colors.fg);
-    output.push_str(";\n");
-    
-    output.push_str("@define-color theme_selected_bg_color ");
-    output.push_str(&theme.colors.accent);
-    output.push_str(";\n");
-    
-    output.push_str("@define-color theme_selected_fg_color ");
-    output.push_str(&theme.colors.bg);
-    output.push_str(";\n");
+    output.push_str("/* Place this file at: ~/.config/gtk-3.0/gtk.css and ~/.config/gtk-4.0/gtk.css */\n\n");
+    
+    // GTK3 (Adwaita) color names
+    let (_, fg_dim) = derived_colors(theme);
+    let named = named_colors(theme);
+    let view_bg = named.iter().find(|(name, _)| *name == "view_bg_color").map(|(_, color)| color.clone()).unwrap_or_else(|| theme.colors.bg.clone());
+    let gtk3_colors = [
+        ("theme_bg_color", theme.colors.bg.clone()),
+        ("theme_fg_color", theme.colors.fg.clone()),
+        ("theme_base_color", view_bg),
+        ("theme_text_color", theme.colors.fg.clone()),
+        ("theme_selected_bg_color", theme.colors.accent.clone()),
+        ("theme_selected_fg_color", theme.colors.bg.clone()),
+        ("theme_unfocused_bg_color", theme.colors.bg.clone()),
+        ("theme_unfocused_fg_color", fg_dim.clone()),
+        ("insensitive_fg_color", fg_dim),
+        ("borders", format!("alpha({}, 0.15)", theme.colors.fg)),
+    ];
+    for (name, color) in gtk3_colors {
+        output.push_str(&format!("@define-color {} {};\n", name, color));
+    }
     
     // Generate hover/active colors
     if let Some(hover_bg) = lighten_color(&theme.colors.bg, 0.1) {
@@ -93,29 +187,17 @@ pub fn generate_css(theme: &Theme) -> Result<String> {
         output.push_str(";\n");
     }
     
-    // Accent colors
-    output.push_str("@define-color accent_color ");
-    output.push_str(&theme.colors.accent);
-    output.push_str(";\n");
-    
     if let Some(accent_hover) = lighten_color(&theme.colors.accent, 0.1) {
         output.push_str("@define-color accent_hover_color ");
         output.push_str(&accent_hover);
         output.push_str(";\n");
     }
     
-    // Error, warning, success colors
-    output.push_str("@define-color error_color ");
-    output.push_str(&theme.colors.red);
-    output.push_str(";\n");
-    
-    output.push_str("@define-color warning_color ");
-    output.push_str(&theme.colors.yellow);
-    output.push_str(";\n");
-    
-    output.push_str("@define-color success_color ");
-    output.push_str(&theme.colors.green);
-    output.push_str(";\n");
+    // libadwaita (GTK4) named colors
+    output.push('\n');
+    for (name, color) in named {
+        output.push_str(&format!("@define-color {} {};\n", name, color));
+    }
     
     output.push_str("\n");
     
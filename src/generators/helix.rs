@@ -0,0 +1,130 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::{derived_colors, SyntaxRoles};
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let roles = SyntaxRoles::from_theme(theme);
+    let (bg_light, fg_dim) = derived_colors(theme);
+
+    output.push_str("# Helix theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Select it in ~/.config/helix/config.toml:\n");
+    output.push_str("#   theme = \"");
+    output.push_str(&theme.name);
+    output.push_str("\"\n\n");
+
+    // UI
+    let ui = [
+        ("ui.background", "{ bg = \"bg\" }".to_string()),
+        ("ui.text", "\"fg\"".to_string()),
+        ("ui.text.focus", "{ fg = \"accent\", modifiers = [\"bold\"] }".to_string()),
+        ("ui.cursor", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.cursor.primary", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.cursor.match", "{ bg = \"bg_light\", modifiers = [\"bold\"] }".to_string()),
+        ("ui.cursorline.primary", "{ bg = \"bg_light\" }".to_string()),
+        ("ui.selection", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.selection.primary", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.linenr", "\"fg_dim\"".to_string()),
+        ("ui.linenr.selected", "\"accent\"".to_string()),
+        ("ui.statusline", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.statusline.inactive", "{ fg = \"fg\", bg = \"bg_light\" }".to_string()),
+        ("ui.statusline.normal", "{ fg = \"bg\", bg = \"accent\", modifiers = [\"bold\"] }".to_string()),
+        ("ui.statusline.insert", "{ fg = \"bg\", bg = \"green\", modifiers = [\"bold\"] }".to_string()),
+        ("ui.statusline.select", "{ fg = \"bg\", bg = \"magenta\", modifiers = [\"bold\"] }".to_string()),
+        ("ui.popup", "{ fg = \"fg\", bg = \"bg_light\" }".to_string()),
+        ("ui.help", "{ fg = \"fg\", bg = \"bg_light\" }".to_string()),
+        ("ui.menu", "{ fg = \"fg\", bg = \"bg_light\" }".to_string()),
+        ("ui.menu.selected", "{ fg = \"bg\", bg = \"accent\" }".to_string()),
+        ("ui.window", "\"fg_dim\"".to_string()),
+        ("ui.virtual.whitespace", "\"fg_dim\"".to_string()),
+        ("ui.virtual.ruler", "{ bg = \"bg_light\" }".to_string()),
+        ("ui.virtual.inlay-hint", "\"fg_dim\"".to_string()),
+    ];
+
+    // Syntax, shared with the neovim generator
+    let mut syntax = vec![
+        ("comment", format!("{{ fg = \"{}\", modifiers = [\"italic\"] }}", roles.key("comment"))),
+        ("string", quoted(roles.key("string"))),
+        ("keyword", quoted(roles.key("keyword"))),
+        ("keyword.control", quoted(roles.key("statement"))),
+        ("keyword.directive", quoted(roles.key("preproc"))),
+        ("function", quoted(roles.key("function"))),
+        ("type", quoted(roles.key("type"))),
+        ("namespace", quoted(roles.key("namespace"))),
+        ("constant", quoted(roles.key("constant"))),
+        ("constant.numeric", quoted(roles.key("number"))),
+        ("constant.builtin.boolean", quoted(roles.key("boolean"))),
+        ("constant.character.escape", quoted(roles.key("special"))),
+        ("attribute", quoted(roles.key("preproc"))),
+        ("function.builtin", quoted(roles.key("builtin"))),
+        ("variable", quoted(roles.key("variable"))),
+        ("variable.builtin", quoted(roles.key("builtin"))),
+        ("variable.parameter", quoted(roles.key("parameter"))),
+        ("variable.other.member", quoted(roles.key("property"))),
+        ("operator", quoted(roles.key("operator"))),
+        ("punctuation", quoted(roles.key("punctuation"))),
+        ("tag", quoted(roles.get("tag").unwrap_or(roles.key("keyword")))),
+    ];
+    if let Some(todo) = roles.get("todo") {
+        syntax.push(("label", quoted(todo)));
+    }
+
+    // Diagnostics
+    let diagnostics = [
+        ("error", quoted(roles.key("error"))),
+        ("warning", quoted(roles.key("warning"))),
+        ("info", quoted(roles.key("info"))),
+        ("hint", quoted(roles.key("hint"))),
+        ("diagnostic.error", underline(roles.key("error"))),
+        ("diagnostic.warning", underline(roles.key("warning"))),
+        ("diagnostic.info", underline(roles.key("info"))),
+        ("diagnostic.hint", underline(roles.key("hint"))),
+        ("diff.plus", quoted(roles.key("diff_add"))),
+        ("diff.minus", quoted(roles.key("diff_delete"))),
+        ("diff.delta", quoted(roles.key("diff_change"))),
+    ];
+
+    // Markup
+    let markup = [
+        ("markup.heading", "{ fg = \"accent\", modifiers = [\"bold\"] }".to_string()),
+        ("markup.list", quoted(roles.key("special"))),
+        ("markup.bold", "{ modifiers = [\"bold\"] }".to_string()),
+        ("markup.italic", "{ modifiers = [\"italic\"] }".to_string()),
+        ("markup.strikethrough", "{ modifiers = [\"crossed_out\"] }".to_string()),
+        ("markup.link.url", format!("{{ fg = \"{}\", modifiers = [\"underlined\"] }}", roles.key("function"))),
+        ("markup.link.text", quoted(roles.key("keyword"))),
+        ("markup.quote", "{ fg = \"fg_dim\", modifiers = [\"italic\"] }".to_string()),
+        ("markup.raw", quoted(roles.key("string"))),
+    ];
+
+    for (group, entries) in [("UI", &ui[..]), ("Syntax", &syntax[..]), ("Diagnostics", &diagnostics[..]), ("Markup", &markup[..])] {
+        output.push_str(&format!("# {}\n", group));
+        for (scope, value) in entries {
+            output.push_str(&format!("\"{}\" = {}\n", scope, value));
+        }
+        output.push('\n');
+    }
+
+    // Palette
+    output.push_str("[palette]\n");
+    for name in ["bg", "fg", "accent", "red", "green", "yellow", "blue", "magenta", "cyan",
+                 "orange", "purple", "pink", "white", "black", "gray"] {
+        if let Some(color) = theme.get_color(name) {
+            output.push_str(&format!("{} = \"{}\"\n", name, color));
+        }
+    }
+    output.push_str(&format!("bg_light = \"{}\"\n", bg_light));
+    output.push_str(&format!("fg_dim = \"{}\"\n", fg_dim));
+
+    Ok(output)
+}
+
+fn quoted(key: &str) -> String {
+    format!("\"{}\"", key)
+}
+
+fn underline(key: &str) -> String {
+    format!("{{ underline = {{ color = \"{}\", style = \"curl\" }} }}", key)
+}
@@ -1,47 +1,154 @@
 use anyhow::Result;
+use crate::config::{parse_hyprland_version, HyprlandConfig};
 use crate::theme::Theme;
-use crate::utils::hex_to_rgb;
+use super::syntax::derived_colors;
+
+/// A line or nested block of hyprland.conf. Keys may repeat (e.g. `animation`).
+pub(crate) enum HyprEntry {
+    Value(&'static str, String),
+    Block(&'static str, Vec<HyprEntry>),
+}
 
 pub fn generate(theme: &Theme) -> Result<String> {
+    generate_for_version(theme, &HyprlandConfig::default().version)
+}
+
+/// Generate hyprland.conf blocks using the config syntax of the given Hyprland release
+pub fn generate_for_version(theme: &Theme, version: &str) -> Result<String> {
     let mut output = String::new();
     
     output.push_str("# Hyprland theme: ");
     output.push_str(&theme.name);
-    output.push_str("\n");
-    output.push_str("# Generated by themectl\n\n");
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str(&format!("# Config syntax: Hyprland {}\n", version));
     
-    // Border width
-    if let Some(border_width) = theme.properties.border_width {
-        output.push_str(&format!("general:border_size = {}\n", border_width));
+    for entry in entries(theme, version)? {
+        output.push('\n');
+        write_entry(&mut output, &entry, 0);
     }
     
-    // Shadow blur
-    if let Some(shadow_blur) = theme.properties.shadow_blur {
-        output.push_str(&format!("decoration:shadow_size = {}\n", shadow_blur));
+    Ok(output)
+}
+
+fn write_entry(output: &mut String, entry: &HyprEntry, depth: usize) {
+    let indent = "    ".repeat(depth);
+    match entry {
+        HyprEntry::Value(key, value) => {
+            output.push_str(&format!("{}{} = {}\n", indent, key, value));
+        }
+        HyprEntry::Block(name, children) => {
+            output.push_str(&format!("{}{} {{\n", indent, name));
+            for child in children {
+                write_entry(output, child, depth + 1);
+            }
+            output.push_str(&format!("{}}}\n", indent));
+        }
     }
+}
+
+/// Hyprland config blocks for a theme, shared by hyprland.conf and the Home Manager module
+pub(crate) fn entries(theme: &Theme, version: &str) -> Result<Vec<HyprEntry>> {
+    use HyprEntry::{Block, Value};
     
-    output.push_str("\n");
+    let (major, minor) = parse_hyprland_version(version).ok_or_else(|| anyhow::anyhow!(
+        "Invalid Hyprland version: '{}'.\n\
+        \n\
+        Expected a release number such as '0.45' or '0.41.2'.\n\
+        \n\
+        To fix: Set a valid version with 'themectl config set-hyprland-version <version>'.",
+        version
+    ))?;
+    // 0.45 replaced drop_shadow/shadow_range/col.shadow with a decoration:shadow block
+    let shadow_block = (major, minor) >= (0, 45);
     
-    // Active border color with alpha
-    if let Some((r, g, b)) = hex_to_rgb(&theme.colors.accent) {
-        output.push_str("col.active_border = rgba(");
-        output.push_str(&format!("{} {} {} 0.8", r, g, b));
-        output.push_str(")\n");
-    }
+    let (bg_light, _) = derived_colors(theme);
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let border_radius = theme.properties.border_radius.unwrap_or(0);
+    let spacing = theme.properties.spacing.unwrap_or(5);
+    let shadow_range = theme.properties.shadow_blur.unwrap_or(4);
     
-    // Inactive border color with alpha
-    if let Some((r, g, b)) = hex_to_rgb(&theme.colors.bg) {
-        output.push_str("col.inactive_border = rgba(");
-        output.push_str(&format!("{} {} {} 0.5", r, g, b));
-        output.push_str(")\n");
-    }
+    let active_border = format!("{} {} 45deg", rgba(&theme.colors.accent, "ee"), rgba(&theme.colors.magenta, "ee"));
+    let inactive_border = rgba(&bg_light, "aa");
+    let shadow_color = rgba(&theme.colors.bg, "4d");
+    
+    let shadow = if shadow_block {
+        vec![Block("shadow", vec![
+            Value("enabled", "true".to_string()),
+            Value("range", shadow_range.to_string()),
+            Value("render_power", "3".to_string()),
+            Value("color", shadow_color),
+        ])]
+    } else {
+        vec![
+            Value("drop_shadow", "true".to_string()),
+            Value("shadow_range", shadow_range.to_string()),
+            Value("shadow_render_power", "3".to_string()),
+            Value("col.shadow", shadow_color),
+        ]
+    };
+    
+    let mut decoration = vec![Value("rounding", border_radius.to_string())];
+    decoration.extend(shadow);
+    decoration.push(Block("blur", vec![
+        Value("enabled", "true".to_string()),
+        Value("size", "6".to_string()),
+        Value("passes", "2".to_string()),
+        Value("new_optimizations", "true".to_string()),
+    ]));
     
-    // Shadow color with alpha
-    if let Some((r, g, b)) = hex_to_rgb(&theme.colors.bg) {
-        output.push_str("col.shadow = rgba(");
-        output.push_str(&format!("{} {} {} 0.3", r, g, b));
-        output.push_str(")\n");
+    let mut entries = vec![
+        Block("general", vec![
+            Value("border_size", border_width.to_string()),
+            Value("gaps_in", spacing.to_string()),
+            Value("gaps_out", (spacing * 2).to_string()),
+            Value("col.active_border", active_border.clone()),
+            Value("col.inactive_border", inactive_border.clone()),
+        ]),
+        Block("decoration", decoration),
+        Block("group", vec![
+            Value("col.border_active", active_border),
+            Value("col.border_inactive", inactive_border),
+            Value("col.border_locked_active", rgba(&theme.colors.yellow, "ee")),
+            Block("groupbar", vec![
+                Value("col.active", rgba(&theme.colors.accent, "ee")),
+                Value("col.inactive", rgba(&bg_light, "aa")),
+                Value("col.locked_active", rgba(&theme.colors.yellow, "ee")),
+                Value("text_color", rgba(&theme.colors.fg, "ff")),
+            ]),
+        ]),
+        Block("misc", vec![
+            Value("background_color", rgba(&theme.colors.bg, "ff")),
+            Value("disable_hyprland_logo", "true".to_string()),
+        ]),
+    ];
+    
+    // Hyprland animation speeds are in units of 100ms
+    if let Some(duration) = theme.properties.animation_duration {
+        let speed = format_speed(duration * 10.0);
+        entries.push(Block("animations", vec![
+            Value("enabled", (duration > 0.0).to_string()),
+            Value("bezier", "themectl, 0.05, 0.9, 0.1, 1.05".to_string()),
+            Value("animation", format!("windows, 1, {}, themectl", speed)),
+            Value("animation", format!("windowsOut, 1, {}, default, popin 80%", speed)),
+            Value("animation", format!("border, 1, {}, default", format_speed(duration * 20.0))),
+            Value("animation", format!("fade, 1, {}, default", speed)),
+            Value("animation", format!("workspaces, 1, {}, default", speed)),
+        ]));
     }
     
-    Ok(output)
+    Ok(entries)
+}
+
+/// Hyprland rgba(RRGGBBAA) color
+fn rgba(hex: &str, alpha: &str) -> String {
+    format!("rgba({}{})", hex.trim_start_matches('#'), alpha)
+}
+
+fn format_speed(speed: f32) -> String {
+    let speed = speed.max(0.1);
+    if speed.fract() == 0.0 {
+        format!("{}", speed as u32)
+    } else {
+        format!("{:.1}", speed)
+    }
 }
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+/// A hyprlock category as (name, [(key, value)]). Categories such as `label`
+/// may appear more than once.
+pub(crate) type HyprlockSection = (&'static str, Vec<(&'static str, String)>);
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Hyprlock theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+
+    for (section, entries) in sections(theme) {
+        output.push_str(&format!("\n{} {{\n", section));
+        for (key, value) in entries {
+            output.push_str(&format!("    {} = {}\n", key, value));
+        }
+        output.push_str("}\n");
+    }
+
+    Ok(output)
+}
+
+/// Hyprlock categories, shared by hyprlock.conf and the Home Manager
+/// `programs.hyprlock.settings` module
+pub(crate) fn sections(theme: &Theme) -> Vec<HyprlockSection> {
+    let (bg_light, fg_dim) = derived_colors(theme);
+    let caps_lock = theme.get_color("orange").unwrap_or(&theme.colors.yellow);
+
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let border_radius = theme.properties.border_radius.unwrap_or(0);
+    let blur_size = theme.properties.shadow_blur.unwrap_or(8);
+
+    vec![
+        ("general", vec![
+            ("hide_cursor", "true".to_string()),
+        ]),
+        ("background", vec![
+            ("monitor", String::new()),
+            ("color", hyprlock_color(&theme.colors.bg)),
+            ("blur_passes", "2".to_string()),
+            ("blur_size", blur_size.to_string()),
+        ]),
+        ("input-field", vec![
+            ("monitor", String::new()),
+            ("size", "300, 50".to_string()),
+            ("outline_thickness", border_width.to_string()),
+            ("rounding", border_radius.to_string()),
+            ("outer_color", hyprlock_color(&theme.colors.accent)),
+            ("inner_color", hyprlock_color(&bg_light)),
+            ("font_color", hyprlock_color(&theme.colors.fg)),
+            ("check_color", hyprlock_color(&theme.colors.yellow)),
+            ("fail_color", hyprlock_color(&theme.colors.red)),
+            ("capslock_color", hyprlock_color(caps_lock)),
+            ("placeholder_text", "<i>Password...</i>".to_string()),
+            ("fade_on_empty", "false".to_string()),
+            ("position", "0, -20".to_string()),
+            ("halign", "center".to_string()),
+            ("valign", "center".to_string()),
+        ]),
+        ("label", vec![
+            ("monitor", String::new()),
+            ("text", "$TIME".to_string()),
+            ("color", hyprlock_color(&theme.colors.fg)),
+            ("font_size", "64".to_string()),
+            ("position", "0, 120".to_string()),
+            ("halign", "center".to_string()),
+            ("valign", "center".to_string()),
+        ]),
+        ("label", vec![
+            ("monitor", String::new()),
+            ("text", "$USER".to_string()),
+            ("color", hyprlock_color(&fg_dim)),
+            ("font_size", "18".to_string()),
+            ("position", "0, 50".to_string()),
+            ("halign", "center".to_string()),
+            ("valign", "center".to_string()),
+        ]),
+    ]
+}
+
+/// Hyprlock colors are written as rgb(RRGGBB)
+fn hyprlock_color(hex: &str) -> String {
+    format!("rgb({})", hex.trim_start_matches('#'))
+}
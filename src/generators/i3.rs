@@ -0,0 +1,20 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::sway::{bar_colors_comment, window_rules};
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# i3 theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Include it from ~/.config/i3/config (i3 4.20 or later):\n");
+    output.push_str("#   include ~/.config/i3/config.d/*\n\n");
+
+    output.push_str(&format!("client.background {}\n", theme.colors.bg));
+    output.push_str(&window_rules(theme));
+
+    output.push_str(&bar_colors_comment(theme, "i3bar"));
+
+    Ok(output)
+}
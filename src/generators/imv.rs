@@ -0,0 +1,39 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::ini::update_section;
+
+/// imv `[options]` colors (RRGGBB without '#'), shared with the Home Manager module
+pub(crate) fn options(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let hex = |color: &str| color.trim_start_matches('#').to_lowercase();
+    vec![
+        ("background", hex(&c.bg)),
+        ("overlay_text_color", hex(&c.fg)),
+        ("overlay_background_color", hex(&c.bg)),
+        ("overlay_background_alpha", "cc".to_string()),
+    ]
+}
+
+/// Generate an imv config with the theme colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# imv configuration: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n\n");
+
+    output.push_str("[options]\n");
+    for (option, value) in options(theme) {
+        output.push_str(&format!("{} = {}\n", option, value));
+    }
+
+    Ok(output)
+}
+
+/// Set the theme colors in an existing imv config, keeping its binds and other options
+pub fn update_config(theme: &Theme, existing: &str) -> Result<String> {
+    if existing.trim().is_empty() {
+        return generate(theme);
+    }
+    Ok(update_section(existing, "options", &options(theme), " = "))
+}
@@ -0,0 +1,62 @@
+/// Set `updates` in one `[section]` of an INI-style config, keeping every other line
+///
+/// Existing keys are replaced in place, missing keys go at the end of the
+/// section, and a missing section is appended to the file. Written keys use
+/// `separator` ("=" or " = ") between key and value.
+pub(crate) fn update_section(existing: &str, section: &str, updates: &[(&str, String)], separator: &str) -> String {
+    let header = format!("[{}]", section);
+    let mut lines: Vec<String> = Vec::new();
+    let mut written = vec![false; updates.len()];
+    let mut in_section = false;
+    let mut found_section = false;
+
+    // Missing keys go after the section's last entry, before its trailing blank lines
+    let flush = |lines: &mut Vec<String>, written: &mut Vec<bool>| {
+        let blanks = lines.iter().rev().take_while(|line| line.trim().is_empty()).count();
+        let trailing = lines.split_off(lines.len() - blanks);
+        for (i, (key, value)) in updates.iter().enumerate() {
+            if !written[i] {
+                lines.push(format!("{}{}{}", key, separator, value));
+                written[i] = true;
+            }
+        }
+        lines.extend(trailing);
+    };
+
+    for line in existing.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            if in_section {
+                flush(&mut lines, &mut written);
+            }
+            in_section = trimmed == header;
+            found_section |= in_section;
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let key = line.split_once('=').map(|(key, _)| key.trim());
+        match updates.iter().position(|(option, _)| in_section && Some(*option) == key) {
+            Some(i) => {
+                lines.push(format!("{}{}{}", updates[i].0, separator, updates[i].1));
+                written[i] = true;
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    if in_section {
+        flush(&mut lines, &mut written);
+    }
+    if !found_section {
+        if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push(header);
+        flush(&mut lines, &mut written);
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    output
+}
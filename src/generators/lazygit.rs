@@ -0,0 +1,50 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::mix_colors;
+use super::syntax::derived_colors;
+
+/// lazygit `gui.theme` keys and their values (a color followed by attributes)
+pub(crate) fn theme_colors(theme: &Theme) -> Vec<(&'static str, Vec<String>)> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let selected_bg = mix_colors(&c.fg, &c.bg, 0.1).unwrap_or_else(|| c.bg.clone());
+    let inactive_bg = mix_colors(&c.fg, &c.bg, 0.05).unwrap_or_else(|| c.bg.clone());
+
+    vec![
+        ("activeBorderColor", vec![c.accent.clone(), "bold".to_string()]),
+        ("inactiveBorderColor", vec![fg_dim.clone()]),
+        ("searchingActiveBorderColor", vec![c.yellow.clone(), "bold".to_string()]),
+        ("optionsTextColor", vec![c.blue.clone()]),
+        ("selectedLineBgColor", vec![selected_bg]),
+        ("inactiveViewSelectedLineBgColor", vec![inactive_bg]),
+        ("cherryPickedCommitFgColor", vec![c.bg.clone()]),
+        ("cherryPickedCommitBgColor", vec![c.cyan.clone()]),
+        ("markedBaseCommitFgColor", vec![c.bg.clone()]),
+        ("markedBaseCommitBgColor", vec![c.yellow.clone()]),
+        ("unstagedChangesColor", vec![c.red.clone()]),
+        ("defaultFgColor", vec![c.fg.clone()]),
+    ]
+}
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# lazygit theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Merge with your config through LG_CONFIG_FILE, e.g.:\n");
+    output.push_str("#   export LG_CONFIG_FILE=\"$HOME/.config/lazygit/config.yml,$HOME/.config/lazygit/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".yml\"\n\n");
+
+    output.push_str("gui:\n");
+    output.push_str("  theme:\n");
+    for (key, values) in theme_colors(theme) {
+        output.push_str(&format!("    {}:\n", key));
+        for value in values {
+            output.push_str(&format!("      - '{}'\n", value));
+        }
+    }
+
+    Ok(output)
+}
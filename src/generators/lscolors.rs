@@ -0,0 +1,175 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::hex_to_rgb;
+use super::syntax::derived_colors;
+
+/// File types: (dircolors keyword, LS_COLORS code)
+const FILE_TYPES: &[(&str, &str)] = &[
+    ("DIR", "di"), ("LINK", "ln"), ("MULTIHARDLINK", "mh"), ("FIFO", "pi"), ("SOCK", "so"),
+    ("DOOR", "do"), ("BLK", "bd"), ("CHR", "cd"), ("ORPHAN", "or"), ("MISSING", "mi"),
+    ("SETUID", "su"), ("SETGID", "sg"), ("CAPABILITY", "ca"), ("STICKY_OTHER_WRITABLE", "tw"),
+    ("OTHER_WRITABLE", "ow"), ("STICKY", "st"), ("EXEC", "ex"),
+];
+
+/// Extension families colored together
+const FAMILIES: &[(&str, &[&str])] = &[
+    ("archive", &["tar", "tgz", "tbz2", "txz", "zip", "7z", "rar", "gz", "bz2", "xz", "zst", "lz4", "lzma", "deb", "rpm", "jar", "apk", "dmg", "iso"]),
+    ("image", &["jpg", "jpeg", "png", "gif", "bmp", "svg", "webp", "tif", "tiff", "ico", "heic", "avif", "xcf", "psd"]),
+    ("audio", &["mp3", "flac", "ogg", "opus", "wav", "m4a", "aac", "mid"]),
+    ("video", &["mp4", "mkv", "webm", "avi", "mov", "flv", "wmv", "m4v"]),
+    ("document", &["pdf", "epub", "djvu", "doc", "docx", "odt", "xls", "xlsx", "ods", "ppt", "pptx", "md", "rst", "tex"]),
+    ("data", &["json", "yaml", "yml", "toml", "ini", "conf", "xml", "csv", "nix", "lock"]),
+    ("compiled", &["rs", "c", "h", "cpp", "hpp", "cc", "go", "zig", "java", "kt", "swift", "hs"]),
+    ("script", &["py", "rb", "pl", "lua", "sh", "bash", "zsh", "fish", "js", "ts", "jsx", "tsx"]),
+    ("web", &["html", "htm", "css", "scss", "sass", "vue", "svelte"]),
+    ("backup", &["bak", "tmp", "swp", "old", "orig", "log"]),
+];
+
+/// A color entry: (dircolors key, LS_COLORS key, SGR sequence)
+pub(crate) type ColorEntry = (String, String, String);
+
+/// File type and extension colors as 24-bit SGR sequences
+pub(crate) fn entries(theme: &Theme) -> Vec<ColorEntry> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let color = |name: &str, fallback: &str| theme.get_color(name).unwrap_or(fallback).to_string();
+
+    let type_sgr = |keyword: &str| match keyword {
+        "DIR" => format!("01;{}", fg(&c.blue)),
+        "LINK" => fg(&c.cyan),
+        "MULTIHARDLINK" => format!("04;{}", fg(&c.fg)),
+        "FIFO" | "DOOR" => fg(&c.yellow),
+        "SOCK" => fg(&c.magenta),
+        "BLK" | "CHR" => format!("01;{}", fg(&c.yellow)),
+        "ORPHAN" | "MISSING" => format!("01;{}", fg(&c.red)),
+        "SETUID" => format!("{};{}", fg(&c.bg), bg(&c.red)),
+        "SETGID" => format!("{};{}", fg(&c.bg), bg(&c.yellow)),
+        "CAPABILITY" => format!("{};{}", fg(&c.bg), bg(&c.magenta)),
+        "STICKY_OTHER_WRITABLE" => format!("{};{}", fg(&c.bg), bg(&c.green)),
+        "OTHER_WRITABLE" => format!("{};{}", fg(&c.blue), bg(&c.green)),
+        "STICKY" => format!("{};{}", fg(&c.bg), bg(&c.blue)),
+        _ => format!("01;{}", fg(&c.green)),
+    };
+    let family_sgr = |family: &str| match family {
+        "archive" => format!("01;{}", fg(&c.red)),
+        "image" => fg(&color("purple", &c.magenta)),
+        "audio" => fg(&c.cyan),
+        "video" => fg(&color("pink", &c.magenta)),
+        "document" => fg(&c.yellow),
+        "data" => fg(&color("orange", &c.yellow)),
+        "compiled" => fg(&c.accent),
+        "script" => fg(&c.green),
+        "web" => fg(&c.blue),
+        _ => fg(&fg_dim),
+    };
+
+    let mut entries: Vec<ColorEntry> = FILE_TYPES.iter()
+        .map(|(keyword, code)| (keyword.to_string(), code.to_string(), type_sgr(keyword)))
+        .collect();
+    for (family, extensions) in FAMILIES {
+        let sgr = family_sgr(family);
+        for ext in *extensions {
+            entries.push((format!(".{}", ext), format!("*.{}", ext), sgr.clone()));
+        }
+    }
+    entries
+}
+
+/// eza-only keys (permissions, sizes, users, dates, git status) for EZA_COLORS
+pub(crate) fn eza_entries(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    vec![
+        ("ur", fg(&c.yellow)), ("uw", fg(&c.red)), ("ux", format!("01;{}", fg(&c.green))), ("ue", fg(&c.green)),
+        ("gr", fg(&c.yellow)), ("gw", fg(&c.red)), ("gx", fg(&c.green)),
+        ("tr", fg(&c.yellow)), ("tw", fg(&c.red)), ("tx", fg(&c.green)),
+        ("sn", fg(&c.green)), ("sb", fg(&fg_dim)),
+        ("uu", fg(&c.yellow)), ("un", fg(&fg_dim)), ("gu", fg(&c.yellow)), ("gn", fg(&fg_dim)),
+        ("da", fg(&c.blue)), ("lp", fg(&c.cyan)), ("hd", format!("04;{}", fg(&c.fg))),
+        ("ga", fg(&c.green)), ("gm", fg(&c.yellow)), ("gd", fg(&c.red)), ("gv", fg(&c.magenta)), ("gt", fg(&c.cyan)),
+    ]
+}
+
+/// LS_COLORS value
+pub(crate) fn ls_colors(theme: &Theme) -> String {
+    entries(theme).iter()
+        .map(|(_, key, sgr)| format!("{}={}", key, sgr))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// EZA_COLORS value (eza reads file colors from LS_COLORS)
+pub(crate) fn eza_colors(theme: &Theme) -> String {
+    eza_entries(theme).iter()
+        .map(|(key, sgr)| format!("{}={}", key, sgr))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Generate a POSIX shell file exporting LS_COLORS and EZA_COLORS (bash/zsh)
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# LS_COLORS/EZA_COLORS: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# bash/zsh: add to ~/.bashrc or ~/.zshrc:\n");
+    output.push_str("#   [ -f ~/.config/lscolors/lscolors.sh ] && . ~/.config/lscolors/lscolors.sh\n");
+    output.push_str("# zsh completion can reuse the colors with:\n");
+    output.push_str("#   zstyle ':completion:*' list-colors \"${(s.:.)LS_COLORS}\"\n\n");
+
+    output.push_str(&format!("export LS_COLORS='{}'\n", ls_colors(theme)));
+    output.push_str(&format!("export EZA_COLORS='{}'\n", eza_colors(theme)));
+
+    Ok(output)
+}
+
+/// Generate a fish file setting LS_COLORS and EZA_COLORS
+pub fn generate_fish(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# LS_COLORS/EZA_COLORS: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# fish: add to ~/.config/fish/config.fish:\n");
+    output.push_str("#   test -f ~/.config/lscolors/lscolors.fish; and source ~/.config/lscolors/lscolors.fish\n\n");
+
+    output.push_str(&format!("set -gx LS_COLORS '{}'\n", ls_colors(theme)));
+    output.push_str(&format!("set -gx EZA_COLORS '{}'\n", eza_colors(theme)));
+
+    Ok(output)
+}
+
+/// Generate a dircolors database
+pub fn generate_dircolors(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# dircolors database: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Load with: eval \"$(dircolors -b ~/.config/lscolors/dircolors)\"\n");
+    output.push_str("# Colors are 24-bit SGR sequences (38;2;R;G;B)\n\n");
+
+    output.push_str("# File types\n");
+    let entries = entries(theme);
+    for (key, _, sgr) in entries.iter().filter(|(key, _, _)| !key.starts_with('.')) {
+        output.push_str(&format!("{} {}\n", key, sgr));
+    }
+
+    output.push_str("\n# Extensions\n");
+    for (key, _, sgr) in entries.iter().filter(|(key, _, _)| key.starts_with('.')) {
+        output.push_str(&format!("{} {}\n", key, sgr));
+    }
+
+    Ok(output)
+}
+
+fn fg(hex: &str) -> String {
+    let (r, g, b) = hex_to_rgb(hex).unwrap_or((255, 255, 255));
+    format!("38;2;{};{};{}", r, g, b)
+}
+
+fn bg(hex: &str) -> String {
+    let (r, g, b) = hex_to_rgb(hex).unwrap_or((0, 0, 0));
+    format!("48;2;{};{};{}", r, g, b)
+}
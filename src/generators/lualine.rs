@@ -0,0 +1,51 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let roles = SyntaxRoles::from_theme(theme);
+    let (bg_light, fg_dim) = derived_colors(theme);
+
+    output.push_str("-- lualine theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n-- Generated by themectl\n");
+    output.push_str("-- Select it in your lualine setup:\n");
+    output.push_str("--   require(\"lualine\").setup({ options = { theme = \"");
+    output.push_str(&theme.name);
+    output.push_str("\" } })\n\n");
+
+    output.push_str("local bg = \"");
+    output.push_str(&theme.colors.bg);
+    output.push_str("\"\nlocal fg = \"");
+    output.push_str(&theme.colors.fg);
+    output.push_str("\"\nlocal bg_light = \"");
+    output.push_str(&bg_light);
+    output.push_str("\"\nlocal fg_dim = \"");
+    output.push_str(&fg_dim);
+    output.push_str("\"\n\nreturn {\n");
+
+    // Mode colors follow the syntax roles so the statusline matches the colorscheme
+    let modes = [
+        ("normal", theme.colors.accent.clone()),
+        ("insert", resolve(theme, roles.key("string"))),
+        ("visual", resolve(theme, roles.key("constant"))),
+        ("replace", resolve(theme, roles.key("error"))),
+        ("command", resolve(theme, roles.key("warning"))),
+    ];
+    for (mode, color) in &modes {
+        output.push_str(&format!("  {} = {{\n", mode));
+        output.push_str(&format!("    a = {{ bg = \"{}\", fg = bg, gui = \"bold\" }},\n", color));
+        output.push_str(&format!("    b = {{ bg = bg_light, fg = \"{}\" }},\n", color));
+        output.push_str("    c = { bg = bg, fg = fg },\n");
+        output.push_str("  },\n");
+    }
+    output.push_str("  inactive = {\n");
+    output.push_str("    a = { bg = bg_light, fg = fg_dim, gui = \"bold\" },\n");
+    output.push_str("    b = { bg = bg_light, fg = fg_dim },\n");
+    output.push_str("    c = { bg = bg, fg = fg_dim },\n");
+    output.push_str("  },\n");
+    output.push_str("}\n");
+
+    Ok(output)
+}
@@ -1,6 +1,37 @@
 use anyhow::Result;
 use crate::theme::Theme;
 
+/// Colors for a notification urgency level, shared by the notification daemon
+/// generators (mako, dunst, swaync) so they look the same for a given theme
+pub(crate) struct UrgencyColors {
+    pub(crate) border: String,
+    pub(crate) background: String,
+    pub(crate) text: String,
+}
+
+/// Urgency colors for "low", "normal" and "critical"
+///
+/// Low uses purple (or cyan), normal the accent, and critical inverts to red.
+pub(crate) fn urgency_colors(theme: &Theme, urgency: &str) -> UrgencyColors {
+    match urgency {
+        "low" => UrgencyColors {
+            border: theme.get_color("purple").unwrap_or(&theme.colors.cyan).to_string(),
+            background: theme.colors.bg.clone(),
+            text: theme.colors.fg.clone(),
+        },
+        "critical" => UrgencyColors {
+            border: theme.colors.red.clone(),
+            background: theme.colors.red.clone(),
+            text: theme.colors.bg.clone(),
+        },
+        _ => UrgencyColors {
+            border: theme.colors.accent.clone(),
+            background: theme.colors.bg.clone(),
+            text: theme.colors.fg.clone(),
+        },
+    }
+}
+
 pub fn generate(theme: &Theme) -> Result<String> {
     let mut output = String::new();
     
@@ -21,20 +52,14 @@ pub fn generate(theme: &Theme) -> Result<String> {
     
     output.push_str("# Urgency levels\n");
     output.push_str("[urgency=low]\n");
-    if let Some(purple) = theme.get_color("purple") {
-        output.push_str("border-color=");
-        output.push_str(purple);
-        output.push_str("\n");
-    } else {
-        output.push_str("border-color=");
-        output.push_str(&theme.colors.cyan);
-        output.push_str("\n");
-    }
+    output.push_str("border-color=");
+    output.push_str(&urgency_colors(theme, "low").border);
+    output.push_str("\n");
     output.push_str("\n");
     
     output.push_str("[urgency=normal]\n");
     output.push_str("border-color=");
-    output.push_str(&theme.colors.accent);
+    output.push_str(&urgency_colors(theme, "normal").border);
     output.push_str("\n\n");
     
     // Warning level (if orange is available)
@@ -48,15 +73,16 @@ pub fn generate(theme: &Theme) -> Result<String> {
         output.push_str("\n\n");
     }
     
+    let critical = urgency_colors(theme, "critical");
     output.push_str("[urgency=critical]\n");
     output.push_str("border-color=");
-    output.push_str(&theme.colors.red);
+    output.push_str(&critical.border);
     output.push_str("\n");
     output.push_str("background-color=");
-    output.push_str(&theme.colors.red);
+    output.push_str(&critical.background);
     output.push_str("\n");
     output.push_str("text-color=");
-    output.push_str(&theme.colors.bg);
+    output.push_str(&critical.text);
     output.push_str("\n");
     
     Ok(output)
@@ -1,61 +1,182 @@
+mod alacritty;
 mod btop;
+mod cava;
+mod dunst;
+mod emacs;
 mod fastfetch;
+mod firefox;
+mod fish;
+mod foot;
+mod fuzzel;
+mod fzf;
+mod ghostty;
 mod git;
 mod gtk;
+mod helix;
 mod hyprland;
+mod hyprlock;
 mod hyprpaper;
+mod i3;
+mod imv;
+mod ini;
 mod kitty;
+mod lazygit;
+mod lscolors;
+mod lualine;
 mod mako;
+mod mpv;
 mod neovim;
 mod nix;
+mod nixos;
+mod palette;
+mod qt;
+mod rofi;
 mod starship;
+mod stylix;
+mod sway;
+mod swaylock;
+mod swaync;
+mod syntax;
+mod tmtheme;
+mod tmux;
+mod tofi;
+mod tty;
+mod vim;
 mod waybar;
+mod wezterm;
 mod wofi;
 mod wlogout;
+mod xresources;
 mod yazi;
+mod zathura;
+mod zellij;
+mod zsh;
 
 use anyhow::Result;
 use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use crate::config::ThemectlConfig;
 use crate::theme::Theme;
+use crate::utils::theme_slug;
 
 pub fn generate(theme: &Theme, format: &str) -> Result<String> {
     match format.to_lowercase().as_str() {
         "nix" => nix::generate(theme),
         "kitty" => kitty::generate(theme),
+        "alacritty" => alacritty::generate(theme),
+        "foot" => foot::generate(theme),
+        "wezterm" => wezterm::generate(theme),
+        "ghostty" => ghostty::generate(theme),
+        "tmux" => tmux::generate(theme),
+        "zellij" => zellij::generate(theme),
         "waybar" => waybar::generate(theme),
         "neovim" => neovim::generate(theme),
+        "lualine" => lualine::generate(theme),
+        "helix" => helix::generate(theme),
         "starship" => starship::generate(theme),
         "mako" => mako::generate(theme),
+        "dunst" => dunst::generate(theme),
+        "swaync" => swaync::generate(theme),
         "hyprland" => hyprland::generate(theme),
+        "sway" => sway::generate(theme),
+        "i3" => i3::generate(theme),
+        "hyprlock" => hyprlock::generate(theme),
+        "swaylock" => swaylock::generate(theme),
         "hyprpaper" => hyprpaper::generate(theme),
         "wofi" => wofi::generate(theme),
+        "rofi" => rofi::generate(theme),
+        "fuzzel" => fuzzel::generate(theme),
+        "tofi" => tofi::generate(theme),
         "wlogout" => wlogout::generate(theme),
         "fastfetch" => fastfetch::generate(theme),
         "yazi" => yazi::generate(theme),
+        "lscolors" => lscolors::generate(theme),
+        "fzf" => fzf::generate(theme),
+        "lazygit" => lazygit::generate(theme),
+        "fish" => fish::generate(theme),
+        "zsh" => zsh::generate(theme),
+        "lscolors-fish" => lscolors::generate_fish(theme),
+        "dircolors" => lscolors::generate_dircolors(theme),
         "gtk" => gtk::generate(theme),
         "gtk-css" => gtk::generate_css(theme),
+        "gtk3-settings" => gtk::generate_gtk3(theme),
+        "qt" => qt::generate(theme),
+        "emacs" => emacs::generate(theme),
+        "vim" => vim::generate(theme),
+        "zathura" => zathura::generate(theme),
+        "tty" => tty::generate(theme),
+        "tty-vtrgb" => tty::generate_vtrgb(theme),
+        "tty-kernel" => tty::generate_kernel_params(theme),
+        "xresources" => xresources::generate(theme),
+        "cava" => cava::generate(theme),
+        "mpv" => mpv::generate(theme),
+        "mpv-uosc" => mpv::generate_uosc(theme),
+        "mpv-modernx" => mpv::generate_modernx(theme),
+        "imv" => imv::generate(theme),
+        "firefox" => firefox::generate(theme),
+        "firefox-content" => firefox::generate_user_content(theme),
+        "qt-kvantum" => qt::generate_kvantum(theme),
         "btop" => btop::generate(theme),
-        "git" => git::generate(theme),
-        "git-nix" => git::generate_nix(theme),
+        "tmtheme" => tmtheme::generate(theme),
+        "delta" => tmtheme::generate_delta(theme),
+        "git" | "git-nix" => generate_with_config(theme, format, &ThemectlConfig::default()),
+        "nixos" => nixos::generate(theme),
+        "stylix" => stylix::generate(theme),
+        "stylix-nix" => stylix::generate_nix(theme),
         _ => anyhow::bail!(
             "Unknown format: '{}'.\n\
             \n\
             Supported formats are:\n\
             - kitty: Terminal emulator configuration\n\
+            - alacritty: Terminal emulator color theme (TOML)\n\
+            - foot: Terminal emulator [colors] section\n\
+            - wezterm: Terminal emulator color scheme (TOML)\n\
+            - ghostty: Terminal emulator theme file\n\
+            - tmux: Terminal multiplexer status and pane styles\n\
+            - zellij: Terminal multiplexer KDL theme\n\
             - waybar: Status bar CSS\n\
             - neovim: Lua color scheme\n\
+            - lualine: Neovim lualine statusline theme\n\
+            - helix: Editor theme with syntax scopes (TOML)\n\
             - starship: Shell prompt configuration\n\
             - mako: Notification daemon colors\n\
+            - dunst: Notification daemon drop-in config\n\
+            - swaync: Notification center CSS\n\
             - hyprland: Window manager colors\n\
+            - sway: Window manager colors (include file)\n\
+            - i3: Window manager colors (include file)\n\
+            - hyprlock: Lock screen configuration\n\
+            - swaylock: Lock screen colors\n\
             - hyprpaper: Wallpaper manager configuration\n\
             - wofi: Application launcher colors\n\
+            - rofi: Application launcher theme (.rasi)\n\
+            - fuzzel: Application launcher [colors] section\n\
+            - tofi: Application launcher theme\n\
             - wlogout: Logout menu colors\n\
             - fastfetch: System info display colors\n\
             - yazi: File manager TOML configuration\n\
-            - gtk: GTK4 theme configuration\n\
+            - lscolors: LS_COLORS/EZA_COLORS for ls and eza\n\
+            - fzf: fzf FZF_DEFAULT_OPTS colors\n\
+            - lazygit: lazygit gui.theme colors\n\
+            - fish: fish_color_* variables\n\
+            - zsh: zsh-syntax-highlighting styles\n\
+            - gtk: GTK3/GTK4 settings and libadwaita colors\n\
+            - qt: qt5ct/qt6ct color scheme\n\
+            - emacs: Emacs deftheme\n\
+            - vim: Vim colorscheme (Vimscript)\n\
+            - zathura: Zathura colors\n\
+            - tty: Linux console palette (setvtrgb script)\n\
+            - xresources: X resources\n\
+            - cava: Cava visualizer colors\n\
+            - mpv: mpv OSD/subtitle colors (uosc, ModernX)\n\
+            - imv: imv image viewer\n\
+            - firefox: Firefox userChrome.css/userContent.css\n\
             - btop: System monitor theme\n\
+            - tmtheme: TextMate theme for bat/delta\n\
             - git: Git color configuration\n\
             - nix: Nix color attribute set\n\
+            - stylix: base16 scheme for Stylix (stylix-nix: Nix snippet)\n\
+            - nixos: NixOS system module (console, GRUB, tuigreet, Plymouth)\n\
             \n\
             You requested: '{}'\n\
             \n\
@@ -65,20 +186,243 @@ pub fn generate(theme: &Theme, format: &str) -> Result<String> {
     }
 }
 
+/// Every format produced by `generate_all`
+const FORMATS: &[&str] = &[
+    "nix", "kitty", "alacritty", "foot", "wezterm", "ghostty", "tmux", "zellij", "waybar", "neovim", "lualine", "helix", "starship",
+    "mako", "dunst", "swaync", "hyprland", "sway", "i3", "hyprlock", "swaylock", "hyprpaper", "wofi", "rofi", "fuzzel", "tofi", "wlogout", "fastfetch", "yazi", "lscolors", "fzf", "lazygit", "fish", "zsh", "gtk", "qt", "emacs", "vim", "zathura", "tty", "xresources", "cava", "mpv", "imv", "firefox", "btop", "tmtheme", "git"
+];
+
+/// Every format produced by `generate_all`
+pub fn formats() -> &'static [&'static str] {
+    FORMATS
+}
+
+/// Apps `apply` can theme: every format except the plain Nix attribute set
+pub fn apps() -> impl Iterator<Item = &'static str> {
+    FORMATS.iter().copied().filter(|format| *format != "nix")
+}
+
+/// File extension for an exported format
+pub fn file_extension(format: &str) -> &'static str {
+    match format {
+        "neovim" | "lualine" => "lua",
+        "nix" | "nixos" => "nix",
+        "stylix" => "yaml",
+        "xresources" => "Xresources",
+        "tty" | "fzf" | "lscolors" => "sh",
+        "vim" => "vim",
+        "emacs" => "el",
+        "firefox" | "swaync" => "css",
+        "zsh" => "zsh",
+        "fish" => "fish",
+        "lazygit" => "yml",
+        "tmtheme" => "tmTheme",
+        "fuzzel" | "foot" => "ini",
+        "rofi" => "rasi",
+        "starship" | "alacritty" | "wezterm" | "helix" | "yazi" => "toml",
+        "zellij" => "kdl",
+        "fastfetch" => "jsonc",
+        "btop" => "theme",
+        _ => "conf",
+    }
+}
+
+/// Standard config path of an app under the config base dir (usually ~/.config)
+///
+/// Firefox profiles and the NixOS module live outside of it and are resolved by
+/// the file manager instead.
+pub fn config_path(app: &str, theme: &Theme, base_dir: &Path) -> Option<PathBuf> {
+    let name = &theme.name;
+    Some(match app {
+        "kitty" => base_dir.join("kitty").join("kitty.conf"),
+        "alacritty" => base_dir.join("alacritty").join("themes").join(format!("{}.toml", name)),
+        "foot" => base_dir.join("foot").join("themes").join(format!("{}.ini", name)),
+        "wezterm" => base_dir.join("wezterm").join("colors").join(format!("{}.toml", name)),
+        "ghostty" => base_dir.join("ghostty").join("themes").join(name),
+        "tmux" => base_dir.join("tmux").join("themes").join(format!("{}.conf", name)),
+        "zellij" => base_dir.join("zellij").join("themes").join(format!("{}.kdl", name)),
+        "waybar" => base_dir.join("waybar").join("style.css"),
+        "neovim" => base_dir.join("nvim").join("colors").join(format!("{}.lua", name)),
+        "lualine" => base_dir.join("nvim").join("lua").join("lualine").join("themes").join(format!("{}.lua", name)),
+        "helix" => base_dir.join("helix").join("themes").join(format!("{}.toml", name)),
+        "starship" => base_dir.join("starship.toml"),
+        "mako" => base_dir.join("mako").join("config"),
+        "dunst" => base_dir.join("dunst").join("dunstrc.d").join("themectl.conf"),
+        "swaync" => base_dir.join("swaync").join("style.css"),
+        "hyprland" => base_dir.join("hypr").join("hyprland.conf"),
+        "sway" => base_dir.join("sway").join("config.d").join("themectl"),
+        "i3" => base_dir.join("i3").join("config.d").join("themectl"),
+        "hyprlock" => base_dir.join("hypr").join("hyprlock.conf"),
+        "swaylock" => base_dir.join("swaylock").join("config"),
+        "hyprpaper" => base_dir.join("hypr").join("hyprpaper.conf"),
+        "wofi" => base_dir.join("wofi").join("style.css"),
+        "rofi" => base_dir.join("rofi").join("themes").join(format!("{}.rasi", name)),
+        "fuzzel" => base_dir.join("fuzzel").join("themes").join(format!("{}.ini", name)),
+        "tofi" => base_dir.join("tofi").join("themes").join(name),
+        "wlogout" => base_dir.join("wlogout").join("style.css"),
+        "fastfetch" => base_dir.join("fastfetch").join("config.jsonc"),
+        "yazi" => base_dir.join("yazi").join("yazi.toml"),
+        "lscolors" => base_dir.join("lscolors").join("lscolors.sh"),
+        "fzf" => base_dir.join("fzf").join("themectl.sh"),
+        "lazygit" => base_dir.join("lazygit").join("themes").join(format!("{}.yml", name)),
+        "fish" => base_dir.join("fish").join("themectl-colors.fish"),
+        "zsh" => base_dir.join("zsh").join("themectl-highlight.zsh"),
+        "gtk" => base_dir.join("gtk-4.0").join("settings.ini"),
+        "qt" => base_dir.join("qt5ct").join("colors").join(format!("{}.conf", name)),
+        "emacs" => base_dir.join("emacs").join("themes").join(format!("{}-theme.el", theme_slug(name))),
+        "vim" => base_dir.join("vim").join("colors").join(format!("{}.vim", theme_slug(name))),
+        "zathura" => base_dir.join("zathura").join("themectl"),
+        "tty" => base_dir.join("tty").join("themectl.sh"),
+        "xresources" => base_dir.join("X11").join("themectl.Xresources"),
+        "cava" => base_dir.join("cava").join("config"),
+        "mpv" => base_dir.join("mpv").join("themectl.conf"),
+        "imv" => base_dir.join("imv").join("config"),
+        "btop" => base_dir.join("btop").join("themes").join(format!("{}.theme", name)),
+        "tmtheme" => base_dir.join("bat").join("themes").join(format!("{}.tmTheme", name)),
+        // Included from ~/.gitconfig with include.path
+        "git" => base_dir.join("git").join("themes").join(format!("{}.conf", name)),
+        _ => return None,
+    })
+}
+
+/// Get the command or action that makes a running application pick up a new theme
+pub fn reload_hint(app: &str) -> Option<&'static str> {
+    match app {
+        "kitty" => Some("kill -SIGUSR1 $(pidof kitty)"),
+        "alacritty" => Some("Alacritty reloads imported themes automatically"),
+        "foot" => Some("New foot windows use the theme (or restart foot --server)"),
+        "wezterm" => Some("WezTerm reloads its config automatically"),
+        "ghostty" => Some("Press ctrl+shift+, in Ghostty to reload its config"),
+        "tmux" => Some("tmux source-file ~/.config/tmux/tmux.conf"),
+        "zellij" => Some("Zellij reloads its config automatically"),
+        "helix" => Some("Run :config-reload in Helix"),
+        "waybar" => Some("pkill -SIGUSR2 waybar"),
+        "mako" => Some("makoctl reload"),
+        "dunst" => Some("dunstctl reload"),
+        "swaync" => Some("swaync-client --reload-css"),
+        "hyprland" => Some("hyprctl reload"),
+        "sway" => Some("swaymsg reload"),
+        "i3" => Some("i3-msg reload"),
+        "tmtheme" => Some("bat cache --build"),
+        "lscolors" => Some(". ~/.config/lscolors/lscolors.sh (fish: source ~/.config/lscolors/lscolors.fish)"),
+        "fzf" => Some(". ~/.config/fzf/themectl.sh"),
+        "lazygit" => Some("Restart lazygit with LG_CONFIG_FILE including the theme file"),
+        "fish" => Some("fish -c 'source ~/.config/fish/themectl-colors.fish'"),
+        "zsh" => Some("source ~/.config/zsh/themectl-highlight.zsh"),
+        "firefox" => Some("Restart Firefox to load userChrome.css"),
+        "zathura" => Some("Run :source in zathura"),
+        "tty" => Some("sh ~/.config/tty/themectl.sh (from a virtual console)"),
+        "xresources" => Some("xrdb -merge ~/.config/X11/themectl.Xresources"),
+        "cava" => Some("pkill -USR2 cava"),
+        "mpv" => Some("Add include=\"~~/themectl.conf\" to mpv.conf; new mpv windows use the theme"),
+        "imv" => Some("Restart imv"),
+        "qt" => Some("Restart Qt applications to pick up the new palette"),
+        "nixos" => Some("sudo nixos-rebuild switch (import /etc/nixos/themectl/system.nix from configuration.nix)"),
+        _ => None,
+    }
+}
+
+/// Apps themed together by a group name in `apply --apps`
+pub fn app_group(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "shell" => Some(&["fzf", "lazygit", "fish", "zsh"]),
+        _ => None,
+    }
+}
+
+/// Enable userChrome.css in a Firefox profile's user.js, keeping other preferences
+pub fn firefox_update_user_js(existing: &str) -> String {
+    firefox::update_user_js(existing)
+}
+
+/// Set theme colors in an mpv script-opts file (uosc, modernx), keeping other options
+pub fn mpv_update_script_opts(theme: &Theme, script: &str, existing: &str) -> Result<String> {
+    mpv::update_script_opts(theme, script, existing)
+}
+
+/// Set the theme colors in an existing cava or imv config, which hold the
+/// user's other settings too
+pub fn update_config_colors(theme: &Theme, app: &str, existing: &str) -> Result<String> {
+    match app {
+        "cava" => cava::update_config(theme, existing),
+        "imv" => imv::update_config(theme, existing),
+        _ => anyhow::bail!("'{}' has no shared config to update", app),
+    }
+}
+
+/// File name `stylix-nix` expects the theme's wallpaper under, next to the snippet
+pub fn stylix_wallpaper_file(theme: &Theme) -> Option<String> {
+    stylix::wallpaper_file(theme)
+}
+
+/// Profile directories listed in Firefox's profiles.ini
+pub fn firefox_profile_dirs(profiles_ini: &str, firefox_dir: &Path) -> Vec<PathBuf> {
+    firefox::profile_dirs(profiles_ini, firefox_dir)
+}
+
+/// Reload hint for an applied theme, for apps whose hint depends on the theme
+pub fn reload_hint_for_theme(theme: &Theme, app: &str) -> Option<String> {
+    match app {
+        "gtk" => Some(format!(
+            "Restart GTK applications (libadwaita follows color-scheme {})",
+            gtk::color_scheme(theme)
+        )),
+        "emacs" => Some(format!("M-x load-theme RET {} RET in Emacs", theme_slug(&theme.name))),
+        "vim" => Some(format!("Run :colorscheme {} in Vim", theme_slug(&theme.name))),
+        _ => reload_hint(app).map(String::from),
+    }
+}
+
+/// The desktop-wide `color-scheme` preference (prefer-dark/prefer-light) for a theme
+pub fn gtk_color_scheme(theme: &Theme) -> &'static str {
+    gtk::color_scheme(theme)
+}
+
+/// Point an existing qt5ct.conf/qt6ct.conf at a generated color scheme
+pub fn qt_select_color_scheme(existing: &str, scheme_path: &str) -> String {
+    qt::set_color_scheme_path(existing, scheme_path)
+}
+
+/// Generate a config, honouring user settings from the themectl config
+/// (e.g. the targeted Hyprland config syntax version)
+pub fn generate_with_config(theme: &Theme, format: &str, config: &ThemectlConfig) -> Result<String> {
+    match format.to_lowercase().as_str() {
+        "hyprland" => hyprland::generate_for_version(theme, &config.hyprland.version),
+        "git" => git::generate_with_truecolor(theme, config.git.use_truecolor()),
+        "git-nix" => git::generate_nix_with_truecolor(theme, config.git.use_truecolor()),
+        _ => generate(theme, format),
+    }
+}
+
 /// Generate a Home Manager module for a specific application
 pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String> {
     nix::generate_home_manager_module(theme, app)
 }
 
+pub fn generate_home_manager_module_with_config(theme: &Theme, app: &str, config: &ThemectlConfig) -> Result<String> {
+    nix::generate_home_manager_module_with_config(theme, app, config)
+}
+
+/// Generate one Home Manager module for every theme and app, switched by `themectl.theme`
+pub fn generate_theme_switching_module(themes: &[Theme], default_theme: Option<&str>, config: &ThemectlConfig) -> Result<String> {
+    let apps: Vec<&str> = apps().collect();
+    nix::generate_theme_switching_module(themes, default_theme, &apps, config)
+}
+
+/// Generate the index of themes the flake exposes as `lib.themes`
+pub fn generate_theme_index(themes: &[Theme]) -> Result<String> {
+    nix::generate_theme_index(themes)
+}
+
 pub fn generate_all(theme: &Theme) -> Result<Vec<(String, String)>> {
-    let formats = vec![
-        "nix", "kitty", "waybar", "neovim", "starship", 
-        "mako", "hyprland", "hyprpaper", "wofi", "wlogout", "fastfetch", "yazi", "gtk", "btop", "git"
-    ];
-    
+    generate_all_with_config(theme, &ThemectlConfig::default())
+}
+
+/// Generate all formats, honouring user settings from the themectl config
+pub fn generate_all_with_config(theme: &Theme, config: &ThemectlConfig) -> Result<Vec<(String, String)>> {
     let mut results = Vec::new();
-    for format in formats {
-        match generate(theme, format) {
+    for format in FORMATS {
+        match generate_with_config(theme, format, config) {
             Ok(content) => results.push((format.to_string(), content)),
             Err(e) => eprintln!("Warning: Failed to generate {}: {}", format, e),
         }
@@ -89,15 +433,15 @@ pub fn generate_all(theme: &Theme) -> Result<Vec<(String, String)>> {
 
 /// Generate all formats in parallel
 pub fn generate_all_parallel(theme: &Theme) -> Vec<(String, Result<String>)> {
-    let formats = vec![
-        "nix", "kitty", "waybar", "neovim", "starship", 
-        "mako", "hyprland", "hyprpaper", "wofi", "wlogout", "fastfetch", "yazi", "gtk", "btop", "git"
-    ];
-    
-    formats
-        .into_par_iter()
+    generate_all_parallel_with_config(theme, &ThemectlConfig::default())
+}
+
+/// Generate all formats in parallel, honouring user settings from the themectl config
+pub fn generate_all_parallel_with_config(theme: &Theme, config: &ThemectlConfig) -> Vec<(String, Result<String>)> {
+    FORMATS
+        .par_iter()
         .map(|format| {
-            let result = generate(theme, format);
+            let result = generate_with_config(theme, format, config);
             (format.to_string(), result)
         })
         .collect()
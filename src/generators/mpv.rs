@@ -0,0 +1,114 @@
+use anyhow::{bail, Result};
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+/// OSD and subtitle options for mpv.conf, shared with the Home Manager module
+pub(crate) fn options(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    vec![
+        ("osd-color", c.fg.clone()),
+        ("osd-border-color", c.bg.clone()),
+        ("sub-color", c.fg.clone()),
+        ("sub-border-color", c.bg.clone()),
+    ]
+}
+
+/// Options for an OSC script's script-opts file ("uosc" or "modernx")
+pub(crate) fn script_options(theme: &Theme, script: &str) -> Result<Vec<(&'static str, String)>> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    match script {
+        "uosc" => {
+            // uosc takes one `color` option of name=RRGGBB pairs
+            let hex = |color: &str| color.trim_start_matches('#').to_lowercase();
+            let colors = [
+                ("foreground", hex(&c.accent)),
+                ("foreground_text", hex(&c.bg)),
+                ("background", hex(&c.bg)),
+                ("background_text", hex(&c.fg)),
+                ("curtain", hex(&c.bg)),
+                ("success", hex(&c.green)),
+                ("error", hex(&c.red)),
+            ];
+            let value = colors.iter().map(|(name, color)| format!("{}={}", name, color)).collect::<Vec<_>>().join(",");
+            Ok(vec![("color", value)])
+        }
+        "modernx" => Ok(vec![
+            ("osc_color", c.bg.clone()),
+            ("seekbarfg_color", c.accent.clone()),
+            ("seekbarbg_color", fg_dim.clone()),
+            ("seekbar_cache_color", fg_dim),
+            ("title_color", c.fg.clone()),
+            ("time_color", c.fg.clone()),
+            ("side_buttons_color", c.fg.clone()),
+            ("middle_buttons_color", c.fg.clone()),
+            ("playpause_color", c.fg.clone()),
+            ("hover_effect_color", c.accent.clone()),
+            ("thumbnail_border_color", c.bg.clone()),
+        ]),
+        _ => bail!("Unknown mpv OSC script: {} (expected uosc or modernx)", script),
+    }
+}
+
+/// Generate an mpv config fragment with OSD and subtitle colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# mpv colors: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Add to ~/.config/mpv/mpv.conf: include=\"~~/themectl.conf\"\n\n");
+
+    for (option, value) in options(theme) {
+        output.push_str(&format!("{}=\"{}\"\n", option, value));
+    }
+
+    Ok(output)
+}
+
+/// Generate `script-opts/uosc.conf` with the theme colors
+pub fn generate_uosc(theme: &Theme) -> Result<String> {
+    update_script_opts(theme, "uosc", "")
+}
+
+/// Generate `script-opts/modernx.conf` with the theme colors
+pub fn generate_modernx(theme: &Theme) -> Result<String> {
+    update_script_opts(theme, "modernx", "")
+}
+
+/// Set the theme colors in a script-opts file, keeping the script's other options
+///
+/// Existing keys are replaced in place and missing keys appended; a header is
+/// only written when the file is new.
+pub fn update_script_opts(theme: &Theme, script: &str, existing: &str) -> Result<String> {
+    let options = script_options(theme, script)?;
+    let mut output = String::new();
+    let mut written = Vec::new();
+
+    if existing.trim().is_empty() {
+        output.push_str(&format!("# {} colors: {}\n", script, theme.name));
+        output.push_str("# Generated by themectl\n");
+    }
+
+    for line in existing.lines() {
+        let key = line.split_once('=').map(|(key, _)| key.trim());
+        match options.iter().find(|(option, _)| Some(*option) == key) {
+            Some((option, value)) => {
+                output.push_str(&format!("{}={}\n", option, value));
+                written.push(*option);
+            }
+            None => {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    }
+
+    for (option, value) in &options {
+        if !written.contains(option) {
+            output.push_str(&format!("{}={}\n", option, value));
+        }
+    }
+
+    Ok(output)
+}
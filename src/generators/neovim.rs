@@ -1,6 +1,108 @@
 use anyhow::Result;
 use crate::theme::Theme;
-use crate::utils::{lighten_color, dim_color};
+use super::palette::ansi_palette;
+use super::syntax::{derived_colors, SyntaxRoles};
+
+/// Diagnostic severities and the syntax role colouring them
+const DIAGNOSTICS: [(&str, &str); 4] = [
+    ("Error", "error"),
+    ("Warn", "warning"),
+    ("Info", "info"),
+    ("Hint", "hint"),
+];
+
+/// Treesitter captures coloured directly from a syntax role
+const TREESITTER_CAPTURES: &[(&str, &str)] = &[
+    ("@string", "string"),
+    ("@string.escape", "special"),
+    ("@string.regexp", "special"),
+    ("@character", "string"),
+    ("@number", "number"),
+    ("@boolean", "boolean"),
+    ("@constant", "constant"),
+    ("@constant.builtin", "builtin"),
+    ("@constant.macro", "preproc"),
+    ("@keyword", "keyword"),
+    ("@keyword.function", "keyword"),
+    ("@keyword.return", "statement"),
+    ("@keyword.conditional", "statement"),
+    ("@keyword.repeat", "statement"),
+    ("@keyword.import", "preproc"),
+    ("@keyword.exception", "error"),
+    ("@function", "function"),
+    ("@function.call", "function"),
+    ("@function.builtin", "builtin"),
+    ("@function.method", "function"),
+    ("@function.macro", "preproc"),
+    ("@constructor", "type"),
+    ("@type", "type"),
+    ("@type.builtin", "type"),
+    ("@type.definition", "type"),
+    ("@attribute", "preproc"),
+    ("@module", "namespace"),
+    ("@label", "special"),
+    ("@variable", "variable"),
+    ("@variable.builtin", "builtin"),
+    ("@variable.parameter", "parameter"),
+    ("@variable.member", "property"),
+    ("@property", "property"),
+    ("@operator", "operator"),
+    ("@punctuation.delimiter", "punctuation"),
+    ("@punctuation.bracket", "punctuation"),
+    ("@punctuation.special", "special"),
+    ("@tag.attribute", "property"),
+    ("@tag.delimiter", "punctuation"),
+    ("@markup.raw", "string"),
+    ("@markup.link", "keyword"),
+    ("@markup.list", "special"),
+    ("@diff.plus", "diff_add"),
+    ("@diff.minus", "diff_delete"),
+    ("@diff.delta", "diff_change"),
+];
+
+/// LSP semantic token types linked to their treesitter equivalents
+const SEMANTIC_TOKENS: &[(&str, &str)] = &[
+    ("class", "@type"),
+    ("enum", "@type"),
+    ("interface", "@type"),
+    ("struct", "@type"),
+    ("type", "@type"),
+    ("typeParameter", "@type.definition"),
+    ("enumMember", "@constant"),
+    ("namespace", "@module"),
+    ("function", "@function"),
+    ("method", "@function.method"),
+    ("macro", "@function.macro"),
+    ("decorator", "@attribute"),
+    ("variable", "@variable"),
+    ("parameter", "@variable.parameter"),
+    ("property", "@property"),
+    ("keyword", "@keyword"),
+    ("comment", "@comment"),
+    ("string", "@string"),
+    ("number", "@number"),
+    ("operator", "@operator"),
+];
+
+/// nvim-cmp completion item kinds and the syntax role colouring them
+const CMP_KINDS: &[(&str, &str)] = &[
+    ("Text", "string"),
+    ("Method", "function"),
+    ("Function", "function"),
+    ("Constructor", "type"),
+    ("Field", "property"),
+    ("Variable", "variable"),
+    ("Class", "type"),
+    ("Interface", "type"),
+    ("Module", "namespace"),
+    ("Property", "property"),
+    ("Keyword", "keyword"),
+    ("Snippet", "special"),
+    ("Constant", "constant"),
+    ("Struct", "type"),
+    ("Enum", "type"),
+    ("EnumMember", "constant"),
+];
 
 pub fn generate(theme: &Theme) -> Result<String> {
     let mut output = String::new();
@@ -70,16 +172,8 @@ pub fn generate(theme: &Theme) -> Result<String> {
     }
     output.push_str("}\n\n");
     
-    let bg_light = if let Some(gray) = theme.get_color("gray") {
-        gray.to_string()
-    } else {
-        lighten_color(&theme.colors.bg, 0.05).unwrap_or_else(|| theme.colors.bg.clone())
-    };
-    let fg_dim = if let Some(gray) = theme.get_color("gray") {
-        gray.to_string()
-    } else {
-        dim_color(&theme.colors.fg, 0.6).unwrap_or_else(|| theme.colors.fg.clone())
-    };
+    let (bg_light, fg_dim) = derived_colors(theme);
+    let roles = SyntaxRoles::from_theme(theme);
     
     output.push_str("local bg_light = \"");
     output.push_str(&bg_light);
@@ -97,9 +191,30 @@ pub fn generate(theme: &Theme) -> Result<String> {
     output.push_str(&theme.name);
     output.push_str("\"\n\n");
     
-    // Normal mode
+    // Terminal colors
+    for (i, color) in ansi_palette(theme).iter().enumerate() {
+        output.push_str(&format!("vim.g.terminal_color_{} = \"{}\"\n", i, color));
+    }
+    output.push('\n');
+    
+    // Editor UI
     output.push_str("vim.api.nvim_set_hl(0, \"Normal\", { bg = colors.bg, fg = colors.fg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"NormalFloat\", { bg = bg_light, fg = colors.fg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"FloatBorder\", { bg = bg_light, fg = colors.accent })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"FloatTitle\", { bg = bg_light, fg = colors.accent, bold = true })\n");
     output.push_str("vim.api.nvim_set_hl(0, \"CursorLine\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CursorColumn\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"ColorColumn\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"Cursor\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"MatchParen\", { bg = bg_light, fg = colors.accent, bold = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"SignColumn\", { bg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WinSeparator\", { fg = fg_dim })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"VertSplit\", { link = \"WinSeparator\" })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"NonText\", { fg = fg_dim })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"Whitespace\", { fg = fg_dim })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"EndOfBuffer\", { fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"Directory\", { fg = colors.blue })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"Title\", { fg = colors.accent, bold = true })\n");
     
     // Visual selection
     output.push_str("vim.api.nvim_set_hl(0, \"Visual\", { bg = colors.accent, fg = colors.bg })\n");
@@ -108,75 +223,153 @@ pub fn generate(theme: &Theme) -> Result<String> {
     output.push_str("vim.api.nvim_set_hl(0, \"LineNr\", { fg = fg_dim })\n");
     output.push_str("vim.api.nvim_set_hl(0, \"CursorLineNr\", { fg = colors.accent })\n");
     
-    // Status line
+    // Status line and tabs
     output.push_str("vim.api.nvim_set_hl(0, \"StatusLine\", { bg = colors.accent, fg = colors.bg })\n");
     output.push_str("vim.api.nvim_set_hl(0, \"StatusLineNC\", { bg = bg_light, fg = colors.fg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TabLine\", { bg = bg_light, fg = fg_dim })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TabLineSel\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TabLineFill\", { bg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WinBar\", { fg = colors.fg, bold = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WinBarNC\", { fg = fg_dim })\n");
+    
+    // Messages
+    output.push_str(&set_fg("ErrorMsg", roles.key("error")));
+    output.push_str(&set_fg("MoreMsg", roles.key("string")));
+    output.push_str(&set_fg("Question", roles.key("function")));
     
     // Comments
-    if let Some(gray) = theme.get_color("gray") {
-        output.push_str(&format!("vim.api.nvim_set_hl(0, \"Comment\", {{ fg = \"{}\" }})\n", gray));
-    } else {
-        output.push_str("vim.api.nvim_set_hl(0, \"Comment\", { fg = fg_dim })\n");
-    }
+    output.push_str(&set_fg("Comment", roles.key("comment")));
     
     // Syntax highlighting
-    output.push_str("vim.api.nvim_set_hl(0, \"String\", { fg = colors.green })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"Keyword\", { fg = colors.blue })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"Function\", { fg = colors.cyan })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"Type\", { fg = colors.yellow })\n");
-    if let Some(_) = theme.get_color("purple") {
-        output.push_str("vim.api.nvim_set_hl(0, \"Constant\", { fg = colors.purple })\n");
-    } else {
-        output.push_str("vim.api.nvim_set_hl(0, \"Constant\", { fg = colors.magenta })\n");
-    }
-    output.push_str("vim.api.nvim_set_hl(0, \"Statement\", { fg = colors.blue })\n");
-    if let Some(_) = theme.get_color("purple") {
-        output.push_str("vim.api.nvim_set_hl(0, \"PreProc\", { fg = colors.purple })\n");
-    } else {
-        output.push_str("vim.api.nvim_set_hl(0, \"PreProc\", { fg = colors.magenta })\n");
-    }
-    if let Some(_) = theme.get_color("pink") {
-        output.push_str("vim.api.nvim_set_hl(0, \"Special\", { fg = colors.pink })\n");
-    } else {
-        output.push_str("vim.api.nvim_set_hl(0, \"Special\", { fg = colors.cyan })\n");
-    }
-    if let Some(_) = theme.get_color("purple") {
-        output.push_str("vim.api.nvim_set_hl(0, \"Number\", { fg = colors.purple })\n");
-    } else {
-        output.push_str("vim.api.nvim_set_hl(0, \"Number\", { fg = colors.magenta })\n");
+    output.push_str(&set_fg("String", roles.key("string")));
+    output.push_str(&set_fg("Keyword", roles.key("keyword")));
+    output.push_str(&set_fg("Function", roles.key("function")));
+    output.push_str(&set_fg("Type", roles.key("type")));
+    output.push_str(&set_fg("Constant", roles.key("constant")));
+    output.push_str(&set_fg("Statement", roles.key("statement")));
+    output.push_str(&set_fg("PreProc", roles.key("preproc")));
+    output.push_str(&set_fg("Special", roles.key("special")));
+    output.push_str(&set_fg("Number", roles.key("number")));
+    output.push_str(&set_fg("Boolean", roles.key("boolean")));
+    output.push_str(&set_fg("Identifier", roles.key("variable")));
+    output.push_str(&set_fg("Operator", roles.key("operator")));
+    output.push_str(&set_fg("Delimiter", roles.key("punctuation")));
+    if let Some(todo) = roles.get("todo") {
+        output.push_str(&set_fg("Todo", todo));
     }
-    output.push_str("vim.api.nvim_set_hl(0, \"Boolean\", { fg = colors.blue })\n");
-    if let Some(_) = theme.get_color("orange") {
-        output.push_str("vim.api.nvim_set_hl(0, \"Todo\", { fg = colors.orange })\n");
-        output.push_str("vim.api.nvim_set_hl(0, \"Tag\", { fg = colors.orange })\n");
+    if let Some(tag) = roles.get("tag") {
+        output.push_str(&set_fg("Tag", tag));
     }
     
     // Errors and warnings
-    output.push_str("vim.api.nvim_set_hl(0, \"Error\", { fg = colors.red })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"WarningMsg\", { fg = colors.yellow })\n");
+    output.push_str(&set_fg("Error", roles.key("error")));
+    output.push_str(&set_fg("WarningMsg", roles.key("warning")));
     
     // Diagnostics
-    output.push_str("vim.api.nvim_set_hl(0, \"DiagnosticError\", { fg = colors.red })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiagnosticWarn\", { fg = colors.yellow })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiagnosticInfo\", { fg = colors.blue })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiagnosticHint\", { fg = colors.cyan })\n");
+    for (severity, role) in DIAGNOSTICS {
+        let color = lua_color(roles.key(role));
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"Diagnostic{}\", {{ fg = {} }})\n", severity, color));
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"DiagnosticVirtualText{}\", {{ bg = bg_light, fg = {} }})\n", severity, color));
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"DiagnosticUnderline{}\", {{ undercurl = true, sp = {} }})\n", severity, color));
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"DiagnosticSign{}\", {{ fg = {} }})\n", severity, color));
+    }
+    
+    // LSP
+    output.push_str("vim.api.nvim_set_hl(0, \"LspReferenceText\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"LspReferenceRead\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"LspReferenceWrite\", { bg = bg_light, underline = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"LspInlayHint\", { fg = fg_dim, italic = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"LspSignatureActiveParameter\", { fg = colors.accent, bold = true })\n");
     
     // Diff
-    output.push_str("vim.api.nvim_set_hl(0, \"DiffAdd\", { fg = colors.green })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiffDelete\", { fg = colors.red })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiffChange\", { fg = colors.yellow })\n");
-    output.push_str("vim.api.nvim_set_hl(0, \"DiffText\", { fg = colors.blue })\n");
+    output.push_str(&set_fg("DiffAdd", roles.key("diff_add")));
+    output.push_str(&set_fg("DiffDelete", roles.key("diff_delete")));
+    output.push_str(&set_fg("DiffChange", roles.key("diff_change")));
+    output.push_str(&set_fg("DiffText", roles.key("diff_text")));
     
     // Search
     output.push_str("vim.api.nvim_set_hl(0, \"Search\", { bg = colors.yellow, fg = colors.bg })\n");
     output.push_str("vim.api.nvim_set_hl(0, \"IncSearch\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CurSearch\", { link = \"IncSearch\" })\n");
     
     // Completion menu
     output.push_str("vim.api.nvim_set_hl(0, \"Pmenu\", { bg = bg_light, fg = colors.fg })\n");
     output.push_str("vim.api.nvim_set_hl(0, \"PmenuSel\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"PmenuSbar\", { bg = bg_light })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"PmenuThumb\", { bg = fg_dim })\n");
     
     // Folds
     output.push_str("vim.api.nvim_set_hl(0, \"Folded\", { fg = fg_dim })\n");
     
+    // Treesitter captures
+    output.push_str("\n-- Treesitter\n");
+    for (capture, role) in TREESITTER_CAPTURES {
+        output.push_str(&set_fg(capture, roles.key(role)));
+    }
+    output.push_str(&format!("vim.api.nvim_set_hl(0, \"@comment\", {{ fg = {}, italic = true }})\n", lua_color(roles.key("comment"))));
+    if let Some(todo) = roles.get("todo") {
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"@comment.todo\", {{ fg = colors.bg, bg = {}, bold = true }})\n", lua_color(todo)));
+    }
+    output.push_str(&set_fg("@tag", roles.get("tag").unwrap_or(roles.key("keyword"))));
+    output.push_str("vim.api.nvim_set_hl(0, \"@markup.heading\", { fg = colors.accent, bold = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"@markup.strong\", { bold = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"@markup.italic\", { italic = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"@markup.strikethrough\", { strikethrough = true })\n");
+    output.push_str(&format!("vim.api.nvim_set_hl(0, \"@markup.link.url\", {{ fg = {}, underline = true }})\n", lua_color(roles.key("function"))));
+    
+    // LSP semantic tokens
+    output.push_str("\n-- LSP semantic tokens\n");
+    for (token, capture) in SEMANTIC_TOKENS {
+        output.push_str(&format!("vim.api.nvim_set_hl(0, \"@lsp.type.{}\", {{ link = \"{}\" }})\n", token, capture));
+    }
+    output.push_str("vim.api.nvim_set_hl(0, \"@lsp.mod.deprecated\", { strikethrough = true })\n");
+    
+    // Plugins
+    output.push_str("\n-- Telescope\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeNormal\", { link = \"NormalFloat\" })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeBorder\", { link = \"FloatBorder\" })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeTitle\", { link = \"FloatTitle\" })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopePromptPrefix\", { fg = colors.accent })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeSelection\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeSelectionCaret\", { bg = colors.accent, fg = colors.bg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"TelescopeMatching\", { fg = colors.accent, bold = true })\n");
+    
+    output.push_str("\n-- Gitsigns\n");
+    output.push_str(&set_fg("GitSignsAdd", roles.key("diff_add")));
+    output.push_str(&set_fg("GitSignsChange", roles.key("diff_change")));
+    output.push_str(&set_fg("GitSignsDelete", roles.key("diff_delete")));
+    output.push_str("vim.api.nvim_set_hl(0, \"GitSignsCurrentLineBlame\", { fg = fg_dim, italic = true })\n");
+    
+    output.push_str("\n-- which-key\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WhichKey\", { fg = colors.accent })\n");
+    output.push_str(&set_fg("WhichKeyGroup", roles.key("keyword")));
+    output.push_str("vim.api.nvim_set_hl(0, \"WhichKeyDesc\", { fg = colors.fg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WhichKeySeparator\", { fg = fg_dim })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"WhichKeyNormal\", { link = \"NormalFloat\" })\n");
+    
+    output.push_str("\n-- nvim-cmp\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CmpItemAbbr\", { fg = colors.fg })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CmpItemAbbrMatch\", { fg = colors.accent, bold = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CmpItemAbbrMatchFuzzy\", { fg = colors.accent })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CmpItemAbbrDeprecated\", { fg = fg_dim, strikethrough = true })\n");
+    output.push_str("vim.api.nvim_set_hl(0, \"CmpItemMenu\", { fg = fg_dim })\n");
+    for (kind, role) in CMP_KINDS {
+        output.push_str(&set_fg(&format!("CmpItemKind{}", kind), roles.key(role)));
+    }
+    
     Ok(output)
 }
+
+/// Lua expression for a palette key: derived colors are locals, the rest live in `colors`.
+/// Hex overrides from the theme's `[syntax]` table are emitted as string literals.
+fn lua_color(key: &str) -> String {
+    match key {
+        "bg_light" | "fg_dim" => key.to_string(),
+        _ if key.starts_with('#') => format!("\"{}\"", key),
+        _ => format!("colors.{}", key),
+    }
+}
+
+fn set_fg(group: &str, key: &str) -> String {
+    format!("vim.api.nvim_set_hl(0, \"{}\", {{ fg = {} }})\n", group, lua_color(key))
+}
@@ -1,6 +1,9 @@
 use anyhow::Result;
+use crate::config::ThemectlConfig;
 use crate::theme::Theme;
+use crate::utils::theme_slug;
 use crate::generators;
+use super::{alacritty, cava, dunst, firefox, fish, fzf, git, gtk, hyprland, hyprlock, imv, lazygit, lscolors, mpv, qt, sway, swaylock, tmtheme, tty, xresources, zathura, zellij, zsh};
 
 pub fn generate(theme: &Theme) -> Result<String> {
     let mut output = String::new();
@@ -80,8 +83,14 @@ pub fn generate(theme: &Theme) -> Result<String> {
     Ok(output)
 }
 
-/// Generate a Home Manager module for a specific application
+/// Generate a Home Manager module for a specific application, with exact git colors
 pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String> {
+    let mut config = ThemectlConfig::default();
+    config.git.colors = "truecolor".to_string();
+    generate_home_manager_module_with_config(theme, app, &config)
+}
+
+pub fn generate_home_manager_module_with_config(theme: &Theme, app: &str, config: &ThemectlConfig) -> Result<String> {
     let mut output = String::new();
     
     // Module header
@@ -98,9 +107,178 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
         output.push_str("\n");
     }
     output.push_str("\n");
+    output.push_str(&module_expression(theme, app, config)?);
+    
+    Ok(output)
+}
+
+/// Generate one Home Manager module holding every theme, selected by `themectl.theme`
+///
+/// Each theme carries its palette and the module of every app in `apps`; only
+/// the apps listed in `themectl.apps` are configured. Without `default_theme`,
+/// `themectl.theme` must be set once any app is enabled. With
+/// `themectl.specialisations` enabled, each theme also gets a Home Manager
+/// specialisation so switching does not need regenerating anything.
+pub fn generate_theme_switching_module(themes: &[Theme], default_theme: Option<&str>, apps: &[&str], config: &ThemectlConfig) -> Result<String> {
+    let mut names: Vec<&str> = themes.iter().map(|theme| theme.name.as_str()).collect();
+    names.sort_unstable();
+    if let Some(pair) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+        anyhow::bail!("More than one theme file is named '{}'; theme names must be unique", pair[0]);
+    }
+    if let Some(default_theme) = default_theme.filter(|name| !names.contains(name)) {
+        anyhow::bail!("Default theme '{}' is not among the exported themes", default_theme);
+    }
+
+    let mut output = String::new();
+    output.push_str("# Home Manager module for all themectl themes\n");
+    output.push_str("# Generated by themectl\n");
+    output.push_str("# Select a theme with `themectl.theme` and the apps to configure with `themectl.apps`.\n");
+    output.push_str("# With `themectl.specialisations = true`, switch without rebuilding:\n");
+    output.push_str("#   $(home-manager generations | head -1 | awk '{print $7}')/specialisation/<theme>/activate\n\n");
+
+    output.push_str("{ config, lib, pkgs, ... }:\n\n");
+    output.push_str("let\n");
+    output.push_str("  cfg = config.themectl;\n");
+    output.push_str("  moduleArgs = { inherit config lib pkgs; };\n\n");
+    output.push_str("  themes = {\n");
+    let mut sorted: Vec<&Theme> = themes.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    for theme in sorted {
+        output.push_str(&format!("    {} = {{\n", nix_key(&theme.name)));
+        output.push_str(&format!("      description = {};\n", nix_string(&theme.description)));
+        output.push_str("      colors = {\n");
+        for (name, color) in palette(theme) {
+            output.push_str(&format!("        {} = {};\n", name, nix_string(color)));
+        }
+        output.push_str("      };\n");
+        output.push_str("      apps = {\n");
+        for app in apps {
+            let expression = module_expression(theme, app, config)?;
+            output.push_str(&format!("        {} = ", nix_key(app)));
+            for (i, line) in expression.trim_end().lines().enumerate() {
+                if i == 1 && line.is_empty() {
+                    continue;
+                }
+                if i > 0 && !line.is_empty() {
+                    output.push_str("          ");
+                }
+                output.push_str(line);
+                output.push('\n');
+            }
+            output.pop();
+            output.push_str(";\n");
+        }
+        output.push_str("      };\n");
+        output.push_str("    };\n");
+    }
+    output.push_str("  };\n");
+    output.push_str("in\n");
+    output.push_str("{\n");
+
+    output.push_str("  options.themectl = {\n");
+    output.push_str("    theme = lib.mkOption {\n");
+    output.push_str("      type = lib.types.enum (builtins.attrNames themes);\n");
+    if let Some(default_theme) = default_theme {
+        output.push_str(&format!("      default = {};\n", nix_string(default_theme)));
+    }
+    output.push_str("      description = \"The themectl theme to apply.\";\n");
+    output.push_str("    };\n");
+    output.push_str("    apps = lib.mkOption {\n");
+    output.push_str("      type = lib.types.listOf (lib.types.enum [");
+    for app in apps {
+        output.push(' ');
+        output.push_str(&nix_string(app));
+    }
+    output.push_str(" ]);\n");
+    output.push_str("      default = [ ];\n");
+    output.push_str("      example = [ \"kitty\" \"waybar\" \"hyprland\" ];\n");
+    output.push_str("      description = \"Applications themectl configures with the selected theme.\";\n");
+    output.push_str("    };\n");
+    output.push_str("    specialisations = lib.mkEnableOption \"a Home Manager specialisation for every theme\";\n");
+    output.push_str("    colors = lib.mkOption {\n");
+    output.push_str("      type = lib.types.attrsOf lib.types.str;\n");
+    output.push_str("      readOnly = true;\n");
+    output.push_str("      default = themes.${cfg.theme}.colors;\n");
+    output.push_str("      description = \"Palette of the selected theme, for use elsewhere in the configuration.\";\n");
+    output.push_str("    };\n");
+    output.push_str("  };\n\n");
+
+    output.push_str("  config = lib.mkMerge ([\n");
+    output.push_str("    (lib.mkIf cfg.specialisations {\n");
+    output.push_str("      specialisation = lib.genAttrs (builtins.attrNames themes)\n");
+    output.push_str("        (name: { configuration.themectl.theme = lib.mkForce name; });\n");
+    output.push_str("    })\n");
+    output.push_str("  ] ++ lib.concatLists (lib.mapAttrsToList (name: theme:\n");
+    output.push_str("    lib.mapAttrsToList (app: module:\n");
+    output.push_str("      lib.mkIf (builtins.elem app cfg.apps && cfg.theme == name) (module moduleArgs)\n");
+    output.push_str("    ) theme.apps\n");
+    output.push_str("  ) themes));\n");
+    output.push_str("}\n");
+
+    Ok(output)
+}
+
+/// Generate the `lib.themes` index: theme metadata with each palette imported
+/// from the `<name>.nix` file the nix generator writes next to it
+pub fn generate_theme_index(themes: &[Theme]) -> Result<String> {
+    let mut sorted: Vec<&Theme> = themes.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0].name == pair[1].name) {
+        anyhow::bail!("More than one theme file is named '{}'; theme names must be unique", pair[0].name);
+    }
+
+    let mut output = String::new();
+    output.push_str("# themectl theme library\n");
+    output.push_str("# Generated by themectl\n");
+    output.push_str("# Regenerate with: themectl export-all nix-lib <dir>\n\n");
+    output.push_str("{\n");
+    for theme in sorted {
+        output.push_str(&format!("  {} = {{\n", nix_key(&theme.name)));
+        output.push_str(&format!("    name = {};\n", nix_string(&theme.name)));
+        output.push_str(&format!("    description = {};\n", nix_string(&theme.description)));
+        if let Some(variant) = &theme.variant {
+            output.push_str(&format!("    variant = {};\n", nix_string(variant)));
+        }
+        output.push_str(&format!("    colors = import {};\n", path_literal(&format!("{}.nix", theme.name))));
+        output.push_str("  };\n");
+    }
+    output.push_str("}\n");
+
+    Ok(output)
+}
+
+/// Nix path literal for an absolute, `~/` or (relative to the generated file) relative path
+///
+/// Paths with characters a literal cannot hold are appended as a string.
+pub(crate) fn path_literal(path: &str) -> String {
+    let (base, rest) = if let Some(rest) = path.strip_prefix("~/") {
+        ("~", format!("/{}", rest))
+    } else if path.starts_with('/') {
+        ("", path.to_string())
+    } else {
+        (".", format!("/{}", path.trim_start_matches("./")))
+    };
+    if rest.chars().all(|c| c.is_ascii_alphanumeric() || "._-+/".contains(c)) && !rest.ends_with('/') {
+        format!("{}{}", base, rest)
+    } else {
+        format!("({}/. + {})", base, nix_string(&rest))
+    }
+}
+
+/// Named colors of a theme, including the optional ones it defines
+fn palette(theme: &Theme) -> Vec<(&'static str, &str)> {
+    ["bg", "fg", "accent", "red", "green", "yellow", "blue", "magenta", "cyan", "orange", "purple", "pink", "white", "black", "gray"]
+        .into_iter()
+        .filter_map(|name| theme.get_color(name).map(|color| (name, color)))
+        .collect()
+}
+
+/// The module function for one app: `{ config, lib, pkgs, ... }: { ... }`
+fn module_expression(theme: &Theme, app: &str, config: &ThemectlConfig) -> Result<String> {
+    let mut output = String::new();
     
     // Generate the actual config content for this app
-    let config_content = generators::generate(theme, app)?;
+    let config_content = generators::generate_with_config(theme, app, config)?;
     
     // Create module based on application type
     match app {
@@ -110,15 +288,130 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("  programs.kitty = {\n");
             output.push_str("    enable = true;\n");
             output.push_str("    settings = {\n");
-            // Parse kitty config and convert to Nix format
-            // For now, use extraConfig to embed the generated config
-            output.push_str("      extraConfig = ''\n");
+            // kitty.conf lines are `key value`
             for line in config_content.lines() {
-                output.push_str("        ");
-                output.push_str(line);
-                output.push_str("\n");
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                if let Some((key, value)) = line.split_once(char::is_whitespace) {
+                    output.push_str(&format!("      {} = {};\n", nix_key(key), nix_value(value.trim())));
+                }
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "alacritty" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.alacritty = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings.colors = {\n");
+            for (section, entries) in alacritty::color_sections(theme)? {
+                output.push_str(&format!("      {} = {{\n", section));
+                for (key, color) in entries {
+                    output.push_str(&format!("        {} = \"{}\";\n", key, color));
+                }
+                output.push_str("      };\n");
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "foot" | "wezterm" | "ghostty" | "helix" | "lualine" | "rofi" | "fuzzel" | "tofi" => {
+            let theme_file = match app {
+                "foot" => format!("foot/themes/{}.ini", theme.name),
+                "wezterm" => format!("wezterm/colors/{}.toml", theme.name),
+                "helix" => format!("helix/themes/{}.toml", theme.name),
+                "lualine" => format!("nvim/lua/lualine/themes/{}.lua", theme.name),
+                "rofi" => format!("rofi/themes/{}.rasi", theme.name),
+                "fuzzel" => format!("fuzzel/themes/{}.ini", theme.name),
+                "tofi" => format!("tofi/themes/{}", theme.name),
+                _ => format!("ghostty/themes/{}", theme.name),
+            };
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str(&format!("  home.file.{}.text = ''\n", nix_string(&format!(".config/{}", theme_file))));
+            push_lines(&mut output, &config_content, "    ");
+            output.push_str("  '';\n");
+            output.push_str("}\n");
+        }
+        "tmux" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.tmux = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    extraConfig = ''\n");
+            push_lines(&mut output, &config_content, "      ");
+            output.push_str("    '';\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "sway" | "i3" => {
+            // Typed options instead of pasting the include file into extraConfig
+            let option = if app == "sway" { "wayland.windowManager.sway" } else { "xsession.windowManager.i3" };
+            let border_width = theme.properties.border_width.unwrap_or(2);
+            let camel = |key: &str| {
+                let mut parts = key.split('_');
+                let first = parts.next().unwrap_or_default().to_string();
+                parts.fold(first, |mut name, part| {
+                    let mut chars = part.chars();
+                    if let Some(c) = chars.next() {
+                        name.push(c.to_ascii_uppercase());
+                        name.push_str(chars.as_str());
+                    }
+                    name
+                })
+            };
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str(&format!("  {} = {{\n", option));
+            output.push_str("    enable = true;\n");
+            output.push_str("    config = {\n");
+            output.push_str("      colors = {\n");
+            for (class, [border, background, text, indicator, child_border]) in sway::client_colors(theme) {
+                output.push_str(&format!(
+                    "        {} = {{ border = \"{}\"; background = \"{}\"; text = \"{}\"; indicator = \"{}\"; childBorder = \"{}\"; }};\n",
+                    camel(class), border, background, text, indicator, child_border
+                ));
+            }
+            if app == "i3" {
+                output.push_str(&format!("        background = \"{}\";\n", theme.colors.bg));
+            }
+            output.push_str("      };\n");
+            output.push_str(&format!("      window = {{ border = {}; titlebar = false; }};\n", border_width));
+            output.push_str(&format!("      floating = {{ border = {}; titlebar = false; }};\n", border_width));
+            output.push_str(&format!("      gaps.inner = {};\n", theme.properties.spacing.unwrap_or(5)));
+            // `bars` is a list, so setting it here would add a bar next to the user's own
+            output.push_str("      # Bar colors: add them to the bar in your own config.bars definition\n");
+            output.push_str("      # colors = {\n");
+            for (key, value) in sway::bar_colors(theme) {
+                match value.split_whitespace().collect::<Vec<_>>()[..] {
+                    [border, background, text] => output.push_str(&format!(
+                        "      #   {} = {{ border = \"{}\"; background = \"{}\"; text = \"{}\"; }};\n",
+                        camel(key), border, background, text
+                    )),
+                    _ => output.push_str(&format!("      #   {} = \"{}\";\n", camel(key), value)),
+                }
+            }
+            output.push_str("      # };\n");
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "zellij" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.zellij = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings = {\n");
+            output.push_str(&format!("      theme = {};\n", nix_string(&theme.name)));
+            output.push_str(&format!("      themes.{} = {{\n", nix_key(&theme.name)));
+            for (key, color) in zellij::theme_colors(theme) {
+                output.push_str(&format!("        {} = \"{}\";\n", key, color));
             }
-            output.push_str("      '';\n");
+            output.push_str("      };\n");
             output.push_str("    };\n");
             output.push_str("  };\n");
             output.push_str("}\n");
@@ -129,11 +422,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("  programs.waybar = {\n");
             output.push_str("    enable = true;\n");
             output.push_str("    style = ''\n");
-            for line in config_content.lines() {
-                output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "      ");
             output.push_str("    '';\n");
             output.push_str("  };\n");
             output.push_str("}\n");
@@ -143,14 +432,10 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("{\n");
             output.push_str("  programs.starship = {\n");
             output.push_str("    enable = true;\n");
-            // Parse TOML and convert to Nix
-            output.push_str("    settings = lib.importTOML (pkgs.writeText \"starship.toml\" ''\n");
-            for line in config_content.lines() {
-                output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
-            output.push_str("    '');\n");
+            let settings: toml::Value = toml::from_str(&config_content)?;
+            output.push_str("    settings = ");
+            push_toml(&mut output, &settings, "    ");
+            output.push_str(";\n");
             output.push_str("  };\n");
             output.push_str("}\n");
         }
@@ -160,11 +445,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("  programs.neovim = {\n");
             output.push_str("    enable = true;\n");
             output.push_str("    extraLuaConfig = ''\n");
-            for line in config_content.lines() {
-                output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "      ");
             output.push_str("    '';\n");
             output.push_str("  };\n");
             output.push_str("}\n");
@@ -174,29 +455,119 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("{\n");
             output.push_str("  services.mako = {\n");
             output.push_str("    enable = true;\n");
-            // Parse config format and convert
-            output.push_str("    extraConfig = ''\n");
+            // Top-level `key=value` lines, then `[criteria]` sections as nested sets
+            output.push_str("    settings = {\n");
+            let mut in_section = false;
             for line in config_content.lines() {
-                output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                if let Some(criteria) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                    if in_section {
+                        output.push_str("      };\n");
+                    }
+                    output.push_str(&format!("      {} = {{\n", nix_string(criteria)));
+                    in_section = true;
+                } else if let Some((key, value)) = line.split_once('=') {
+                    let indent = if in_section { "        " } else { "      " };
+                    output.push_str(&format!("{}{} = {};\n", indent, nix_key(key.trim()), nix_value(value.trim())));
+                }
+            }
+            if in_section {
+                output.push_str("      };\n");
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "dunst" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  services.dunst = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings = {\n");
+            for (section, entries) in dunst::sections(theme) {
+                output.push_str(&format!("      {} = {{\n", section));
+                for (key, value) in entries {
+                    output.push_str(&format!("        {} = {};\n", key, value));
+                }
+                output.push_str("      };\n");
             }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "swaync" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  services.swaync = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    style = ''\n");
+            push_lines(&mut output, &config_content, "      ");
             output.push_str("    '';\n");
             output.push_str("  };\n");
             output.push_str("}\n");
         }
+        "hyprlock" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.hyprlock = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings = {\n");
+            let sections = hyprlock::sections(theme);
+            let mut names: Vec<&str> = sections.iter().map(|(name, _)| *name).collect();
+            names.dedup();
+            for name in names {
+                let blocks: Vec<_> = sections.iter().filter(|(section, _)| *section == name).collect();
+                // `general` is a single category; the others are lists of widgets
+                if name == "general" {
+                    output.push_str(&format!("      {} = {{\n", name));
+                } else {
+                    output.push_str(&format!("      {} = [\n        {{\n", name));
+                }
+                let indent = if name == "general" { "        " } else { "          " };
+                for (i, (_, entries)) in blocks.iter().enumerate() {
+                    if i > 0 {
+                        output.push_str("        }\n        {\n");
+                    }
+                    for (key, value) in entries {
+                        output.push_str(&format!("{}{} = {};\n", indent, key, nix_value(value)));
+                    }
+                }
+                if name == "general" {
+                    output.push_str("      };\n");
+                } else {
+                    output.push_str("        }\n      ];\n");
+                }
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "swaylock" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.swaylock = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings = {\n");
+            for (key, value) in swaylock::settings(theme) {
+                // Colors like 282828 would otherwise look like integers
+                let value = if key.ends_with("color") { format!("\"{}\"", value) } else { nix_value(&value) };
+                output.push_str(&format!("      {} = {};\n", key, value));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
         "hyprland" => {
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
             output.push_str("  wayland.windowManager.hyprland = {\n");
             output.push_str("    enable = true;\n");
-            output.push_str("    extraConfig = ''\n");
-            for line in config_content.lines() {
-                output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
-            output.push_str("    '';\n");
+            output.push_str("    settings = {\n");
+            push_hyprland_entries(&mut output, &hyprland::entries(theme, &config.hyprland.version)?, "      ");
+            output.push_str("    };\n");
             output.push_str("  };\n");
             output.push_str("}\n");
         }
@@ -206,11 +577,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("  home.file.\".config/");
             output.push_str(app);
             output.push_str("/style.css\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
@@ -218,11 +585,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
             output.push_str("  home.file.\".config/fastfetch/config.jsonc\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
@@ -230,11 +593,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
             output.push_str("  home.file.\".config/yazi/yazi.toml\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
@@ -242,52 +601,313 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
             output.push_str("  home.file.\".config/hypr/hyprpaper.conf\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
         "gtk" => {
+            let css = gtk::generate_css(theme)?;
+            let files = [
+                ("gtk-4.0/settings.ini", config_content.clone()),
+                ("gtk-3.0/settings.ini", gtk::generate_gtk3(theme)?),
+                ("gtk-3.0/gtk.css", css.clone()),
+                ("gtk-4.0/gtk.css", css),
+            ];
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
-            output.push_str("  home.file.\".config/gtk-4.0/settings.ini\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
+            for (path, content) in files {
+                output.push_str(&format!("  home.file.{}.text = ''\n", nix_string(&format!(".config/{}", path))));
+                push_lines(&mut output, &content, "    ");
+                output.push_str("  '';\n\n");
             }
+            // libadwaita follows the desktop-wide preference rather than settings.ini
+            output.push_str(&format!(
+                "  dconf.settings.\"org/gnome/desktop/interface\".color-scheme = \"{}\";\n",
+                gtk::color_scheme(theme)
+            ));
+            output.push_str("}\n");
+        }
+        "qt" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  qt = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    platformTheme.name = \"qtct\";\n");
+            output.push_str("  };\n");
+            for tool in ["qt5ct", "qt6ct"] {
+                let scheme = format!("{}/colors/{}.conf", tool, theme.name);
+                output.push_str(&format!("\n  xdg.configFile.{}.text = ''\n", nix_string(&scheme)));
+                push_lines(&mut output, &config_content, "    ");
+                output.push_str("  '';\n");
+                // Interpolated by Nix so the path follows the user's XDG config home,
+                // hence written without push_lines' escaping; only the scheme part is escaped
+                let selection = qt::set_color_scheme_path("", &format!("${{config.xdg.configHome}}/{}", indented_escape(&scheme)));
+                output.push_str(&format!("\n  xdg.configFile.\"{0}/{0}.conf\".text = ''\n", tool));
+                for line in selection.lines() {
+                    output.push_str("    ");
+                    output.push_str(line);
+                    output.push('\n');
+                }
+                output.push_str("  '';\n");
+            }
+            output.push_str("}\n");
+        }
+        "emacs" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str(&format!("  xdg.configFile.{}.text = ''\n", nix_string(&format!("emacs/themes/{}-theme.el", theme_slug(&theme.name)))));
+            push_lines(&mut output, &config_content, "    ");
+            output.push_str("  '';\n\n");
+            output.push_str("  programs.emacs.extraConfig = ''\n");
+            output.push_str("    (add-to-list 'custom-theme-load-path \"${config.xdg.configHome}/emacs/themes/\")\n");
+            push_lines(&mut output, &format!("(load-theme '{} t)", theme_slug(&theme.name)), "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
-        "btop" => {
+        "vim" => {
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
-            output.push_str("  home.file.\".config/btop/themes/");
-            output.push_str(&theme.name);
-            output.push_str(".theme\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
+            output.push_str(&format!("  home.file.{}.text = ''\n", nix_string(&format!(".vim/colors/{}.vim", theme_slug(&theme.name)))));
+            push_lines(&mut output, &config_content, "    ");
+            output.push_str("  '';\n\n");
+            output.push_str("  programs.vim = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str(&format!("    extraConfig = {};\n", nix_string(&format!("colorscheme {}", theme_slug(&theme.name)))));
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "zathura" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.zathura = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    options = {\n");
+            for (option, value) in zathura::options(theme) {
+                output.push_str(&format!("      {} = {};\n", option, nix_value(&value)));
             }
+            output.push_str("      recolor-keephue = true;\n");
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "tty" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("# The console palette is system configuration; on NixOS set:\n");
+            let colors: Vec<String> = tty::console_colors(theme).iter().map(|color| format!("\"{}\"", color)).collect();
+            output.push_str(&format!("#   console.colors = [ {} ];\n", colors.join(" ")));
+            output.push_str(&format!("# or add the kernel parameters: {}\n", tty::kernel_params(theme)));
+            output.push_str("{\n");
+            output.push_str("  xdg.configFile.\"tty/themectl.sh\".text = ''\n");
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
-        "git" => {
+        "xresources" => {
             output.push_str("{ config, lib, pkgs, ... }:\n\n");
             output.push_str("{\n");
-            output.push_str("  programs.git = {\n");
+            output.push_str("  xresources.properties = {\n");
+            for (name, color) in xresources::properties(theme) {
+                output.push_str(&format!("    \"{}\" = \"{}\";\n", name, color));
+            }
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "cava" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.cava = {\n");
             output.push_str("    enable = true;\n");
-            output.push_str("    extraConfig = ''\n");
-            for line in config_content.lines() {
+            output.push_str("    settings.color = {\n");
+            output.push_str(&format!("      background = \"'{}'\";\n", theme.colors.bg));
+            output.push_str(&format!("      foreground = \"'{}'\";\n", theme.colors.accent));
+            let colors = cava::gradient(theme, cava::GRADIENT_COUNT);
+            output.push_str("      gradient = 1;\n");
+            output.push_str(&format!("      gradient_count = {};\n", colors.len()));
+            for (i, color) in colors.iter().enumerate() {
+                output.push_str(&format!("      gradient_color_{} = \"'{}'\";\n", i + 1, color));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "mpv" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.mpv = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    config = {\n");
+            for (option, value) in mpv::options(theme) {
+                output.push_str(&format!("      {} = {};\n", option, nix_value(&value)));
+            }
+            output.push_str("    };\n");
+            output.push_str("    # Only read when the uosc or ModernX script is installed\n");
+            output.push_str("    scriptOpts = {\n");
+            for script in ["uosc", "modernx"] {
+                output.push_str(&format!("      {} = {{\n", script));
+                for (option, value) in mpv::script_options(theme, script)? {
+                    output.push_str(&format!("        {} = {};\n", option, nix_value(&value)));
+                }
+                output.push_str("      };\n");
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "imv" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.imv = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings.options = {\n");
+            for (option, value) in imv::options(theme) {
+                // Always strings: hex values like 282828 would otherwise read as integers
+                output.push_str(&format!("      {} = \"{}\";\n", option, value));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "firefox" => {
+            let user_content = firefox::generate_user_content(theme)?;
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.firefox.enable = true;\n\n");
+            output.push_str("  # Rename \"default\" to match an existing Home Manager profile\n");
+            output.push_str("  programs.firefox.profiles.default = {\n");
+            output.push_str("    settings.\"toolkit.legacyUserProfileCustomizations.stylesheets\" = true;\n");
+            for (key, content) in [("userChrome", config_content.as_str()), ("userContent", user_content.as_str())] {
+                output.push_str(&format!("    {} = ''\n", key));
+                push_lines(&mut output, content, "      ");
+                output.push_str("    '';\n");
+            }
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "btop" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.btop = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str(&format!("    settings.color_theme = {};\n", nix_string(&theme.name)));
+            output.push_str("  };\n\n");
+            output.push_str(&format!("  xdg.configFile.{}.text = ''\n", nix_string(&format!("btop/themes/{}.theme", theme.name))));
+            push_lines(&mut output, &config_content, "    ");
+            output.push_str("  '';\n");
+            output.push_str("}\n");
+        }
+        "lscolors" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.dircolors = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings = {\n");
+            for (key, _, sgr) in lscolors::entries(theme) {
+                let key = if key.starts_with('.') { format!("\"{}\"", key) } else { key };
+                output.push_str(&format!("      {} = \"{}\";\n", key, sgr));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n\n");
+            output.push_str(&format!("  home.sessionVariables.EZA_COLORS = \"{}\";\n", lscolors::eza_colors(theme)));
+            output.push_str("}\n");
+        }
+        "fzf" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.fzf = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    colors = {\n");
+            for (slot, color) in fzf::colors(theme) {
+                output.push_str(&format!("      \"{}\" = \"{}\";\n", slot, color));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "lazygit" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.lazygit = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    settings.gui.theme = {\n");
+            for (key, values) in lazygit::theme_colors(theme) {
+                let values: Vec<String> = values.iter().map(|value| format!("\"{}\"", value)).collect();
+                output.push_str(&format!("      {} = [ {} ];\n", key, values.join(" ")));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "fish" => {
+            // Home Manager sets globals on every start instead of persisting universals
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.fish = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    interactiveShellInit = ''\n");
+            for command in fish::set_commands(theme, "-g") {
                 output.push_str("      ");
-                output.push_str(line);
-                output.push_str("\n");
+                output.push_str(&command);
+                output.push('\n');
+            }
+            output.push_str("    '';\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "zsh" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.zsh = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    syntaxHighlighting = {\n");
+            output.push_str("      enable = true;\n");
+            output.push_str("      styles = {\n");
+            for (key, style) in zsh::styles(theme) {
+                output.push_str(&format!("        \"{}\" = \"{}\";\n", key, style));
             }
+            output.push_str("      };\n");
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "tmtheme" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.bat = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str(&format!("    config.theme = {};\n", nix_string(&theme.name)));
+            output.push_str(&format!(
+                "    themes.{}.src = pkgs.writeText {} ''\n",
+                nix_key(&theme.name),
+                nix_string(&format!("{}.tmTheme", theme.name))
+            ));
+            push_lines(&mut output, &config_content, "      ");
             output.push_str("    '';\n");
+            output.push_str("  };\n\n");
+            output.push_str("  programs.git.delta = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    options = {\n");
+            for (key, value) in tmtheme::delta_options(theme) {
+                output.push_str(&format!("      {} = {};\n", key, nix_value(&value)));
+            }
+            output.push_str("    };\n");
+            output.push_str("  };\n");
+            output.push_str("}\n");
+        }
+        "git" => {
+            output.push_str("{ config, lib, pkgs, ... }:\n\n");
+            output.push_str("{\n");
+            output.push_str("  programs.git = {\n");
+            output.push_str("    enable = true;\n");
+            output.push_str("    extraConfig.color = {\n");
+            output.push_str("      ui = \"auto\";\n");
+            for (section, slots) in git::color_sections(theme, config.git.use_truecolor()) {
+                output.push_str(&format!("      {} = {{\n", nix_key(section)));
+                for (slot, value) in slots {
+                    output.push_str(&format!("        {} = {};\n", nix_key(slot), nix_string(&value)));
+                }
+                output.push_str("      };\n");
+            }
+            output.push_str("    };\n");
             output.push_str("  };\n");
             output.push_str("}\n");
         }
@@ -298,11 +918,7 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
             output.push_str("  home.file.\".config/");
             output.push_str(app);
             output.push_str("/config\".text = ''\n");
-            for line in config_content.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push_str("\n");
-            }
+            push_lines(&mut output, &config_content, "    ");
             output.push_str("  '';\n");
             output.push_str("}\n");
         }
@@ -310,3 +926,112 @@ pub fn generate_home_manager_module(theme: &Theme, app: &str) -> Result<String>
     
     Ok(output)
 }
+
+/// Write `content` into an indented string (`'' ... ''`), one line per line at `indent`
+///
+/// `''` and `${` would end the string or start an antiquotation, so they are escaped.
+fn push_lines(output: &mut String, content: &str, indent: &str) {
+    for line in content.lines() {
+        output.push_str(indent);
+        output.push_str(&indented_escape(line));
+        output.push('\n');
+    }
+}
+
+/// Escape text for an indented string
+fn indented_escape(text: &str) -> String {
+    text.replace("''", "'''").replace("${", "''${")
+}
+
+/// Write a TOML value as a Nix expression; nested lines are indented from `indent`
+fn push_toml(output: &mut String, value: &toml::Value, indent: &str) {
+    match value {
+        toml::Value::String(s) => output.push_str(&nix_string(s)),
+        toml::Value::Integer(i) => output.push_str(&i.to_string()),
+        toml::Value::Float(f) => output.push_str(&format!("{:?}", f)),
+        toml::Value::Boolean(b) => output.push_str(&b.to_string()),
+        toml::Value::Datetime(d) => output.push_str(&nix_string(&d.to_string())),
+        toml::Value::Array(items) => {
+            output.push('[');
+            for item in items {
+                output.push(' ');
+                push_toml(output, item, indent);
+            }
+            output.push_str(" ]");
+        }
+        toml::Value::Table(table) => {
+            let inner = format!("{}  ", indent);
+            output.push_str("{\n");
+            for (key, item) in table {
+                output.push_str(&format!("{}{} = ", inner, nix_key(key)));
+                push_toml(output, item, &inner);
+                output.push_str(";\n");
+            }
+            output.push_str(indent);
+            output.push('}');
+        }
+    }
+}
+
+/// Write Hyprland entries as `settings` attributes
+///
+/// Blocks become nested sets, and keys that repeat (`animation`, `bezier`) are
+/// collected into a list in their original order.
+fn push_hyprland_entries(output: &mut String, entries: &[hyprland::HyprEntry], indent: &str) {
+    use hyprland::HyprEntry::{Block, Value};
+
+    let mut written: Vec<&str> = Vec::new();
+    for entry in entries {
+        match entry {
+            Value(key, _) => {
+                if written.contains(key) {
+                    continue;
+                }
+                written.push(key);
+                let values: Vec<String> = entries.iter()
+                    .filter_map(|e| match e {
+                        Value(k, v) if k == key => Some(nix_value(v)),
+                        _ => None,
+                    })
+                    .collect();
+                if values.len() == 1 {
+                    output.push_str(&format!("{}{} = {};\n", indent, nix_key(key), values[0]));
+                } else {
+                    output.push_str(&format!("{}{} = [ {} ];\n", indent, nix_key(key), values.join(" ")));
+                }
+            }
+            Block(name, children) => {
+                output.push_str(&format!("{}{} = {{\n", indent, nix_key(name)));
+                push_hyprland_entries(output, children, &format!("{}  ", indent));
+                output.push_str(&format!("{}}};\n", indent));
+            }
+        }
+    }
+}
+
+/// Attribute name, quoted unless it is a plain identifier
+fn nix_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let plain = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '\'');
+    if plain {
+        key.to_string()
+    } else {
+        nix_string(key)
+    }
+}
+
+/// Double-quoted Nix string
+fn nix_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace("${", "\\${"))
+}
+
+/// Nix literal for a config value: integers and booleans stay bare, everything
+/// else becomes an escaped string
+fn nix_value(value: &str) -> String {
+    if value == "true" || value == "false" || value.parse::<i64>().is_ok() {
+        value.to_string()
+    } else {
+        nix_string(value)
+    }
+}
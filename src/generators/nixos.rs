@@ -0,0 +1,216 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{color_distance, hex_to_rgb};
+use super::syntax::derived_colors;
+use super::tty::console_colors;
+
+/// ANSI color names tuigreet (ratatui) accepts for palette slots 1-6
+const ANSI_NAMES: [&str; 6] = ["red", "green", "yellow", "blue", "magenta", "cyan"];
+
+/// Name of the ANSI color closest to `color`
+///
+/// tuigreet runs on the virtual console, which only has the 16 palette colors
+/// that `console.colors` sets, so named colors render as the theme's own.
+fn ansi_name(theme: &Theme, color: &str) -> &'static str {
+    let c = &theme.colors;
+    let slots = [&c.red, &c.green, &c.yellow, &c.blue, &c.magenta, &c.cyan];
+    slots.iter()
+        .zip(ANSI_NAMES)
+        .min_by(|(a, _), (b, _)| {
+            let a = color_distance(a, color).unwrap_or(f64::MAX);
+            let b = color_distance(b, color).unwrap_or(f64::MAX);
+            a.total_cmp(&b)
+        })
+        .map(|(_, name)| name)
+        .unwrap_or("blue")
+}
+
+/// tuigreet `--theme` specification
+pub(crate) fn tuigreet_theme(theme: &Theme) -> String {
+    let accent = ansi_name(theme, &theme.colors.accent);
+    [
+        ("border", accent),
+        ("title", accent),
+        ("text", "gray"),
+        ("greet", "gray"),
+        ("prompt", ansi_name(theme, &theme.colors.green)),
+        ("input", "gray"),
+        ("time", accent),
+        ("action", ansi_name(theme, &theme.colors.blue)),
+        ("button", accent),
+        ("container", "black"),
+    ]
+    .iter()
+    .map(|(element, color)| format!("{}={}", element, color))
+    .collect::<Vec<_>>()
+    .join(";")
+}
+
+/// GRUB `theme.txt` with the menu drawn in the theme colors
+pub(crate) fn grub_theme(theme: &Theme) -> String {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let mut output = String::new();
+
+    output.push_str("title-text: \"\"\n");
+    output.push_str(&format!("desktop-color: \"{}\"\n", c.bg));
+    output.push_str("terminal-box: \"\"\n\n");
+    output.push_str("+ boot_menu {\n");
+    output.push_str("  left = 15%\n");
+    output.push_str("  top = 25%\n");
+    output.push_str("  width = 70%\n");
+    output.push_str("  height = 50%\n");
+    output.push_str("  item_height = 28\n");
+    output.push_str("  item_spacing = 4\n");
+    output.push_str(&format!("  item_color = \"{}\"\n", c.fg));
+    output.push_str(&format!("  selected_item_color = \"{}\"\n", c.accent));
+    output.push_str("}\n\n");
+    output.push_str("+ label {\n");
+    output.push_str("  left = 15%\n");
+    output.push_str("  top = 80%\n");
+    output.push_str("  width = 70%\n");
+    output.push_str("  align = \"center\"\n");
+    output.push_str("  id = \"__timeout__\"\n");
+    output.push_str("  text = \"Booting in %d seconds\"\n");
+    output.push_str(&format!("  color = \"{}\"\n", fg_dim));
+    output.push_str("}\n");
+
+    output
+}
+
+/// Plymouth script channels ("r, g, b" from 0 to 1) for a hex color
+fn plymouth_rgb(color: &str) -> String {
+    let (r, g, b) = hex_to_rgb(color).unwrap_or((0, 0, 0));
+    format!("{:.3}, {:.3}, {:.3}", r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+}
+
+/// Plymouth script drawing the theme background and the boot prompts
+///
+/// A script theme has to draw the LUKS password and other questions itself,
+/// otherwise they are invisible.
+pub(crate) fn plymouth_script(theme: &Theme) -> String {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let bg = plymouth_rgb(&c.bg);
+    let fg = plymouth_rgb(&c.fg);
+    let accent = plymouth_rgb(&c.accent);
+    let dim = plymouth_rgb(&fg_dim);
+    let mut output = String::new();
+
+    output.push_str(&format!("Window.SetBackgroundTopColor({});\n", bg));
+    output.push_str(&format!("Window.SetBackgroundBottomColor({});\n\n", bg));
+
+    output.push_str("fun centered_text(text, r, g, b, y) {\n");
+    output.push_str("  sprite = Sprite(Image.Text(text, r, g, b));\n");
+    output.push_str("  sprite.SetPosition(Window.GetWidth() / 2 - sprite.GetImage().GetWidth() / 2, y, 10);\n");
+    output.push_str("  return sprite;\n");
+    output.push_str("}\n\n");
+
+    output.push_str("fun show_prompt(prompt, entry) {\n");
+    output.push_str(&format!("  global.prompt_sprite = centered_text(prompt, {}, Window.GetHeight() / 2 - 30);\n", fg));
+    output.push_str(&format!("  global.entry_sprite = centered_text(entry, {}, Window.GetHeight() / 2 + 10);\n", accent));
+    output.push_str("}\n\n");
+
+    output.push_str("fun display_password(prompt, bullets) {\n");
+    output.push_str("  entry = \"\";\n");
+    output.push_str("  for (i = 0; i < bullets; i++)\n");
+    output.push_str("    entry += \"*\";\n");
+    output.push_str("  show_prompt(prompt, entry);\n");
+    output.push_str("}\n\n");
+
+    output.push_str("fun display_question(prompt, entry) {\n");
+    output.push_str("  show_prompt(prompt, entry);\n");
+    output.push_str("}\n\n");
+
+    output.push_str("fun display_normal() {\n");
+    output.push_str("  global.prompt_sprite = NULL;\n");
+    output.push_str("  global.entry_sprite = NULL;\n");
+    output.push_str("}\n\n");
+
+    output.push_str("fun display_message(text) {\n");
+    output.push_str(&format!("  global.message_sprite = centered_text(text, {}, Window.GetHeight() - 60);\n", dim));
+    output.push_str("}\n\n");
+
+    output.push_str("Plymouth.SetDisplayPasswordFunction(display_password);\n");
+    output.push_str("Plymouth.SetDisplayQuestionFunction(display_question);\n");
+    output.push_str("Plymouth.SetDisplayNormalFunction(display_normal);\n");
+    output.push_str("Plymouth.SetMessageFunction(display_message);\n");
+
+    output
+}
+
+/// Generate a NixOS module theming the boot-to-desktop chain: GRUB, Plymouth,
+/// the virtual console and the tuigreet greeter
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# NixOS system theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Import from configuration.nix: imports = [ ./themectl/system.nix ];\n");
+    output.push_str("# Plymouth and greetd are only themed here; enable them yourself with\n");
+    output.push_str("# boot.plymouth.enable and services.greetd.enable.\n\n");
+
+    output.push_str("{ config, lib, pkgs, ... }:\n\n");
+    output.push_str("let\n");
+    output.push_str("  grubTheme = pkgs.writeTextDir \"theme.txt\" ''\n");
+    for line in grub_theme(theme).lines() {
+        if !line.is_empty() {
+            output.push_str("    ");
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+    output.push_str("  '';\n\n");
+
+    output.push_str("  plymouthTheme = pkgs.runCommand \"plymouth-theme-themectl\" { } ''\n");
+    output.push_str("    dir=$out/share/plymouth/themes/themectl\n");
+    output.push_str("    mkdir -p $dir\n");
+    output.push_str("    cat > $dir/themectl.plymouth <<EOF\n");
+    output.push_str("    [Plymouth Theme]\n");
+    output.push_str("    Name=themectl\n");
+    output.push_str("    Description=Boot splash in the themectl theme colors\n");
+    output.push_str("    ModuleName=script\n\n");
+    output.push_str("    [script]\n");
+    output.push_str("    ImageDir=$dir\n");
+    output.push_str("    ScriptFile=$dir/themectl.script\n");
+    output.push_str("    EOF\n");
+    output.push_str("    cat > $dir/themectl.script <<'EOF'\n");
+    for line in plymouth_script(theme).lines() {
+        if !line.is_empty() {
+            output.push_str("    ");
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+    output.push_str("    EOF\n");
+    output.push_str("  '';\n");
+    output.push_str("in\n");
+    output.push_str("{\n");
+
+    output.push_str("  console.colors = [\n");
+    for color in console_colors(theme) {
+        output.push_str(&format!("    \"{}\"\n", color));
+    }
+    output.push_str("  ];\n\n");
+
+    output.push_str("  boot.loader.grub = {\n");
+    output.push_str(&format!("    backgroundColor = \"{}\";\n", theme.colors.bg));
+    output.push_str("    theme = grubTheme;\n");
+    output.push_str("  };\n\n");
+
+    output.push_str("  boot.plymouth = {\n");
+    output.push_str("    theme = \"themectl\";\n");
+    output.push_str("    themePackages = [ plymouthTheme ];\n");
+    output.push_str("  };\n\n");
+
+    output.push_str("  # To keep your own greeter command, add the same --theme argument to it\n");
+    output.push_str("  services.greetd.settings.default_session.command = lib.mkDefault\n");
+    output.push_str(&format!(
+        "    \"${{pkgs.tuigreet}}/bin/tuigreet --time --remember --theme '{}'\";\n",
+        tuigreet_theme(theme)
+    ));
+    output.push_str("}\n");
+
+    Ok(output)
+}
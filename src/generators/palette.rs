@@ -0,0 +1,40 @@
+use crate::theme::Theme;
+use crate::utils::lighten_color;
+
+/// Derive the 16-color ANSI palette (color0-color15) shared by the terminal generators
+///
+/// color0-7 are the normal colors and color8-15 their bright counterparts.
+/// `black`, `gray` and `white` are used when the theme defines them; otherwise
+/// the bright colors are lightened versions of the normal ones.
+pub(crate) fn ansi_palette(theme: &Theme) -> [String; 16] {
+    let brighten = |color: &str, factor: f32| {
+        lighten_color(color, factor).unwrap_or_else(|| color.to_string())
+    };
+
+    let black = theme.get_color("black").unwrap_or(&theme.colors.bg).to_string();
+    let bright_black = theme.get_color("gray")
+        .map(|gray| gray.to_string())
+        .unwrap_or_else(|| brighten(&theme.colors.bg, 0.1));
+    let bright_white = theme.get_color("white")
+        .map(|white| white.to_string())
+        .unwrap_or_else(|| brighten(&theme.colors.fg, 0.2));
+
+    [
+        black,
+        theme.colors.red.clone(),
+        theme.colors.green.clone(),
+        theme.colors.yellow.clone(),
+        theme.colors.blue.clone(),
+        theme.colors.magenta.clone(),
+        theme.colors.cyan.clone(),
+        theme.colors.fg.clone(),
+        bright_black,
+        brighten(&theme.colors.red, 0.2),
+        brighten(&theme.colors.green, 0.2),
+        brighten(&theme.colors.yellow, 0.2),
+        brighten(&theme.colors.blue, 0.2),
+        brighten(&theme.colors.magenta, 0.2),
+        brighten(&theme.colors.cyan, 0.2),
+        bright_white,
+    ]
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{darken_color, lighten_color};
+use super::ini::update_section;
+use super::syntax::derived_colors;
+
+/// QPalette color roles in the order qt5ct/qt6ct expect them
+const ROLES: [&str; 21] = [
+    "WindowText", "Button", "Light", "Midlight", "Dark", "Mid", "Text", "BrightText",
+    "ButtonText", "Base", "Window", "Shadow", "Highlight", "HighlightedText", "Link",
+    "LinkVisited", "AlternateBase", "NoRole", "ToolTipBase", "ToolTipText", "PlaceholderText",
+];
+
+/// Generate a qt5ct/qt6ct color scheme
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let active = palette(theme);
+
+    output.push_str("# Qt color scheme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Used by qt5ct/qt6ct via color_scheme_path in qt5ct.conf/qt6ct.conf\n\n");
+
+    output.push_str("[ColorScheme]\n");
+    output.push_str(&format!("active_colors={}\n", join_colors(&active)));
+    output.push_str(&format!("disabled_colors={}\n", join_colors(&disabled_palette(theme, &active))));
+    output.push_str(&format!("inactive_colors={}\n", join_colors(&active)));
+
+    Ok(output)
+}
+
+/// Generate a Kvantum theme that only overrides colors (Kvantum falls back to
+/// its default SVG when a theme directory has no SVG of its own)
+pub fn generate_kvantum(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let colors = palette(theme);
+    let role = |name: &str| colors[ROLES.iter().position(|role| *role == name).unwrap_or(0)].clone();
+    let (_, fg_dim) = derived_colors(theme);
+
+    output.push_str("# Kvantum color override: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Select it with: kvantummanager --set ");
+    output.push_str(&theme.name);
+    output.push_str("\n\n");
+
+    output.push_str("[%General]\n");
+    output.push_str("author=themectl\n");
+    output.push_str(&format!("comment={}\n", theme.name));
+    output.push_str(&format!("composite=true\nspread_menuitems=true\nmenu_shadow_depth={}\n\n",
+        theme.properties.shadow_blur.unwrap_or(4)));
+
+    output.push_str("[GeneralColors]\n");
+    let entries = [
+        ("window.color", role("Window")),
+        ("base.color", role("Base")),
+        ("alt.base.color", role("AlternateBase")),
+        ("button.color", role("Button")),
+        ("light.color", role("Light")),
+        ("mid.light.color", role("Midlight")),
+        ("dark.color", role("Dark")),
+        ("mid.color", role("Mid")),
+        ("highlight.color", role("Highlight")),
+        ("inactive.highlight.color", role("Highlight")),
+        ("text.color", role("Text")),
+        ("window.text.color", role("WindowText")),
+        ("button.text.color", role("ButtonText")),
+        ("disabled.text.color", fg_dim.clone()),
+        ("tooltip.text.color", role("ToolTipText")),
+        ("highlight.text.color", role("HighlightedText")),
+        ("link.color", role("Link")),
+        ("link.visited.color", role("LinkVisited")),
+        ("progress.indicator.text.color", role("HighlightedText")),
+    ];
+    for (key, color) in entries {
+        output.push_str(&format!("{}={}\n", key, color));
+    }
+
+    Ok(output)
+}
+
+/// Point qt5ct.conf/qt6ct.conf at a color scheme, keeping all other settings
+///
+/// Sets `color_scheme_path` and `custom_palette=true` in `[Appearance]`,
+/// adding the section if it is missing.
+pub fn set_color_scheme_path(existing: &str, scheme_path: &str) -> String {
+    let updates = [("color_scheme_path", scheme_path.to_string()), ("custom_palette", "true".to_string())];
+    update_section(existing, "Appearance", &updates, "=")
+}
+
+/// Active palette as 21 #RRGGBB colors in QPalette role order
+fn palette(theme: &Theme) -> Vec<String> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let lighten = |color: &str, factor: f32| lighten_color(color, factor).unwrap_or_else(|| color.to_string());
+    let darken = |color: &str, factor: f32| darken_color(color, factor).unwrap_or_else(|| color.to_string());
+
+    let button = lighten(&c.bg, 0.08);
+    let bright_text = theme.get_color("white").map(String::from).unwrap_or_else(|| lighten(&c.fg, 0.2));
+
+    ROLES.iter().map(|role| match *role {
+        "WindowText" | "Text" | "ButtonText" | "ToolTipText" => c.fg.clone(),
+        "Button" | "AlternateBase" | "ToolTipBase" => button.clone(),
+        "Light" => lighten(&c.bg, 0.2),
+        "Midlight" => lighten(&c.bg, 0.12),
+        "Dark" => darken(&c.bg, 0.3),
+        "Mid" => darken(&c.bg, 0.15),
+        "BrightText" => bright_text.clone(),
+        "Shadow" => darken(&c.bg, 0.6),
+        "Highlight" => c.accent.clone(),
+        "HighlightedText" => c.bg.clone(),
+        "Link" => c.blue.clone(),
+        "LinkVisited" => c.magenta.clone(),
+        "PlaceholderText" => fg_dim.clone(),
+        _ => c.bg.clone(),
+    }).collect()
+}
+
+/// Disabled palette: text roles are dimmed and the selection loses its accent
+fn disabled_palette(theme: &Theme, active: &[String]) -> Vec<String> {
+    let (_, fg_dim) = derived_colors(theme);
+    ROLES.iter().zip(active).map(|(role, color)| match *role {
+        "WindowText" | "Text" | "ButtonText" | "BrightText" | "HighlightedText" => fg_dim.clone(),
+        "Highlight" => active[1].clone(),
+        _ => color.clone(),
+    }).collect()
+}
+
+/// qt5ct/qt6ct colors are #AARRGGBB
+fn join_colors(colors: &[String]) -> String {
+    colors.iter()
+        .map(|color| format!("#ff{}", color.trim_start_matches('#').to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
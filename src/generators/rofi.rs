@@ -0,0 +1,98 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let (bg_light, fg_dim) = derived_colors(theme);
+
+    let border_radius = theme.properties.border_radius.unwrap_or(0);
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let spacing = theme.properties.spacing.unwrap_or(8);
+
+    output.push_str("/* Rofi theme: ");
+    output.push_str(&theme.name);
+    output.push_str(" */\n/* Generated by themectl */\n");
+    output.push_str("/* Use it from ~/.config/rofi/config.rasi:\n");
+    output.push_str(" *   @theme \"~/.config/rofi/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".rasi\"\n */\n\n");
+
+    output.push_str("* {\n");
+    output.push_str(&format!("    bg: {};\n", theme.colors.bg));
+    output.push_str(&format!("    bg-alt: {};\n", bg_light));
+    output.push_str(&format!("    fg: {};\n", theme.colors.fg));
+    output.push_str(&format!("    fg-dim: {};\n", fg_dim));
+    output.push_str(&format!("    accent: {};\n", theme.colors.accent));
+    output.push_str(&format!("    urgent: {};\n", theme.colors.red));
+    output.push_str(&format!("    active: {};\n", theme.colors.green));
+    output.push_str("    background-color: transparent;\n");
+    output.push_str("    text-color: @fg;\n");
+    output.push_str("}\n\n");
+
+    output.push_str("window {\n");
+    output.push_str("    background-color: @bg;\n");
+    output.push_str(&format!("    border: {}px;\n", border_width));
+    output.push_str("    border-color: @accent;\n");
+    output.push_str(&format!("    border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+
+    output.push_str("mainbox {\n");
+    output.push_str(&format!("    spacing: {}px;\n", spacing));
+    output.push_str(&format!("    padding: {}px;\n", spacing));
+    output.push_str("}\n\n");
+
+    output.push_str("inputbar {\n");
+    output.push_str("    background-color: @bg-alt;\n");
+    output.push_str(&format!("    padding: {}px;\n", spacing));
+    output.push_str(&format!("    spacing: {}px;\n", spacing));
+    output.push_str(&format!("    border-radius: {}px;\n", border_radius));
+    output.push_str("    children: [ prompt, entry ];\n");
+    output.push_str("}\n\n");
+
+    output.push_str("prompt {\n    text-color: @accent;\n}\n\n");
+    output.push_str("entry {\n    placeholder-color: @fg-dim;\n}\n\n");
+
+    output.push_str("listview {\n");
+    output.push_str(&format!("    spacing: {}px;\n", spacing / 2));
+    output.push_str("    scrollbar: false;\n");
+    output.push_str("}\n\n");
+
+    output.push_str("element {\n");
+    output.push_str(&format!("    padding: {}px;\n", spacing / 2));
+    output.push_str(&format!("    spacing: {}px;\n", spacing));
+    output.push_str(&format!("    border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+
+    // element <state>.<kind>: normal/alternate/selected x normal/urgent/active
+    let states = [
+        ("normal.normal", "@bg", "@fg"),
+        ("normal.urgent", "@bg", "@urgent"),
+        ("normal.active", "@bg", "@active"),
+        ("alternate.normal", "@bg", "@fg"),
+        ("alternate.urgent", "@bg", "@urgent"),
+        ("alternate.active", "@bg", "@active"),
+        ("selected.normal", "@accent", "@bg"),
+        ("selected.urgent", "@urgent", "@bg"),
+        ("selected.active", "@active", "@bg"),
+    ];
+    for (state, background, text) in states {
+        output.push_str(&format!(
+            "element {} {{\n    background-color: {};\n    text-color: {};\n}}\n\n",
+            state, background, text
+        ));
+    }
+
+    output.push_str("element-icon, element-text {\n");
+    output.push_str("    background-color: inherit;\n");
+    output.push_str("    text-color: inherit;\n");
+    output.push_str("}\n\n");
+
+    output.push_str("message {\n");
+    output.push_str("    background-color: @bg-alt;\n");
+    output.push_str(&format!("    padding: {}px;\n", spacing));
+    output.push_str(&format!("    border-radius: {}px;\n", border_radius));
+    output.push_str("}\n");
+
+    Ok(output)
+}
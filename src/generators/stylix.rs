@@ -0,0 +1,123 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{darken_color, is_light_color, lighten_color, mix_colors};
+use super::nix::path_literal;
+
+/// Stylix polarity: the theme's variant when it names one, else from the background
+pub(crate) fn polarity(theme: &Theme) -> &'static str {
+    match theme.variant.as_deref() {
+        Some(variant) if variant.contains("light") => "light",
+        Some(variant) if variant.contains("dark") => "dark",
+        _ if is_light_color(&theme.colors.bg) => "light",
+        _ => "dark",
+    }
+}
+
+/// The base16 palette (base00-base0F) as RRGGBB without '#'
+///
+/// base00-base07 ramp from the background to the strongest foreground, so they
+/// read correctly for light themes too; base08-base0F are the accent hues.
+pub(crate) fn base16_palette(theme: &Theme) -> [String; 16] {
+    let c = &theme.colors;
+    let shade = |weight: f32| mix_colors(&c.fg, &c.bg, weight).unwrap_or_else(|| c.fg.clone());
+    let light = polarity(theme) == "light";
+
+    let base07 = if light {
+        theme.get_color("black").map(str::to_string)
+            .unwrap_or_else(|| darken_color(&c.fg, 0.2).unwrap_or_else(|| c.fg.clone()))
+    } else {
+        theme.get_color("white").map(str::to_string)
+            .unwrap_or_else(|| lighten_color(&c.fg, 0.2).unwrap_or_else(|| c.fg.clone()))
+    };
+    let base06 = mix_colors(&base07, &c.fg, 0.5).unwrap_or_else(|| c.fg.clone());
+    let orange = theme.get_color("orange").map(str::to_string)
+        .unwrap_or_else(|| mix_colors(&c.red, &c.yellow, 0.5).unwrap_or_else(|| c.red.clone()));
+    let brown = theme.get_color("pink").map(str::to_string)
+        .unwrap_or_else(|| darken_color(&c.red, 0.3).unwrap_or_else(|| c.red.clone()));
+
+    [
+        c.bg.clone(),
+        shade(0.08),
+        shade(0.18),
+        theme.get_color("gray").map(str::to_string).unwrap_or_else(|| shade(0.45)),
+        shade(0.75),
+        c.fg.clone(),
+        base06,
+        base07,
+        c.red.clone(),
+        orange,
+        c.yellow.clone(),
+        c.green.clone(),
+        c.cyan.clone(),
+        c.blue.clone(),
+        c.magenta.clone(),
+        brown,
+    ]
+    .map(|color| color.trim_start_matches('#').to_lowercase())
+}
+
+/// Generate a base16 scheme in the YAML format Stylix and base16.nix read
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# base16 scheme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str(&format!("scheme: \"{}\"\n", yaml_escape(&theme.name)));
+    output.push_str("author: \"themectl\"\n");
+    for (i, color) in base16_palette(theme).iter().enumerate() {
+        output.push_str(&format!("base0{:X}: \"{}\"\n", i, color));
+    }
+
+    Ok(output)
+}
+
+/// File name the wallpaper has next to the Stylix snippet
+///
+/// `~/` paths give none: they aren't resolvable in pure flake evaluation.
+pub(crate) fn wallpaper_file(theme: &Theme) -> Option<String> {
+    let wallpaper = theme.wallpaper.as_deref()?;
+    if wallpaper.starts_with("~/") {
+        return None;
+    }
+    Path::new(wallpaper).file_name().map(|name| name.to_string_lossy().into_owned())
+}
+
+/// Generate a NixOS or Home Manager snippet pointing Stylix at the scheme
+///
+/// The scheme is expected next to the snippet as `<theme>.yaml`, and the
+/// wallpaper under its own file name (the export copies it there).
+pub fn generate_nix(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Stylix configuration: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Import from a NixOS or Home Manager configuration that uses Stylix;\n");
+    output.push_str(&format!("# {}.yaml must be next to this file.\n\n", theme.name));
+
+    output.push_str("{ config, lib, pkgs, ... }:\n\n");
+    output.push_str("{\n");
+    output.push_str("  stylix = {\n");
+    output.push_str("    enable = true;\n");
+    output.push_str(&format!("    base16Scheme = {};\n", path_literal(&format!("{}.yaml", theme.name))));
+    output.push_str(&format!("    polarity = \"{}\";\n", polarity(theme)));
+    match (&theme.wallpaper, wallpaper_file(theme)) {
+        (_, Some(file)) => output.push_str(&format!("    image = {};\n", path_literal(&file))),
+        (Some(wallpaper), None) => output.push_str(&format!(
+            "    # wallpaper {} skipped: ~/ paths don't work in pure flake evaluation\n",
+            wallpaper
+        )),
+        (None, None) => {}
+    }
+    output.push_str("  };\n");
+    output.push_str("}\n");
+
+    Ok(output)
+}
+
+/// Escape a string for a double-quoted YAML scalar
+fn yaml_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+/// `client.<class>` colors as (class, [border, background, text, indicator, child_border])
+pub(crate) type ClientColors = (&'static str, [String; 5]);
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Sway theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Include it from ~/.config/sway/config:\n");
+    output.push_str("#   include ~/.config/sway/config.d/*\n\n");
+
+    output.push_str(&window_rules(theme));
+
+    output.push_str(&bar_colors_comment(theme, "swaybar"));
+
+    Ok(output)
+}
+
+/// Bar colors as a commented block to copy into the user's own `bar {}`
+///
+/// A live `bar {}` (or `bar <id>` for an id that doesn't exist yet) would start
+/// a bar the user may not have, e.g. next to a waybar started with `exec`.
+pub(crate) fn bar_colors_comment(theme: &Theme, bar: &str) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# {} colors: copy this block into the bar {{}} of your config
+", bar));
+    output.push_str("# bar {\n");
+    output.push_str("#     colors {\n");
+    for (key, value) in bar_colors(theme) {
+        output.push_str(&format!("#         {:<18} {}\n", key, value));
+    }
+    output.push_str("#     }\n");
+    output.push_str("# }\n");
+    output
+}
+
+/// Client colors, borders and gaps, shared by the sway and i3 generators
+pub(crate) fn window_rules(theme: &Theme) -> String {
+    let mut output = String::new();
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let spacing = theme.properties.spacing.unwrap_or(5);
+
+    output.push_str("# Window colors: border background text indicator child_border\n");
+    for (class, colors) in client_colors(theme) {
+        output.push_str(&format!("client.{:<16} {}\n", class, colors.join(" ")));
+    }
+    output.push('\n');
+
+    output.push_str(&format!("default_border pixel {}\n", border_width));
+    output.push_str(&format!("default_floating_border pixel {}\n", border_width));
+    output.push_str(&format!("gaps inner {}\n\n", spacing));
+
+    output
+}
+
+pub(crate) fn client_colors(theme: &Theme) -> Vec<ClientColors> {
+    let (bg_light, fg_dim) = derived_colors(theme);
+    let c = &theme.colors;
+
+    vec![
+        ("focused", [c.accent.clone(), c.accent.clone(), c.bg.clone(), c.magenta.clone(), c.accent.clone()]),
+        ("focused_inactive", [bg_light.clone(), bg_light.clone(), c.fg.clone(), bg_light.clone(), bg_light]),
+        ("unfocused", [c.bg.clone(), c.bg.clone(), fg_dim.clone(), c.bg.clone(), c.bg.clone()]),
+        ("urgent", [c.red.clone(), c.red.clone(), c.bg.clone(), c.red.clone(), c.red.clone()]),
+        ("placeholder", [c.bg.clone(), c.bg.clone(), fg_dim, c.bg.clone(), c.bg.clone()]),
+    ]
+}
+
+/// swaybar/i3bar `colors {}` entries; workspace values are "border background text"
+pub(crate) fn bar_colors(theme: &Theme) -> Vec<(&'static str, String)> {
+    let (bg_light, fg_dim) = derived_colors(theme);
+    let c = &theme.colors;
+
+    vec![
+        ("background", c.bg.clone()),
+        ("statusline", c.fg.clone()),
+        ("separator", fg_dim.clone()),
+        ("focused_workspace", format!("{} {} {}", c.accent, c.accent, c.bg)),
+        ("active_workspace", format!("{} {} {}", bg_light, bg_light, c.fg)),
+        ("inactive_workspace", format!("{} {} {}", c.bg, c.bg, fg_dim)),
+        ("urgent_workspace", format!("{} {} {}", c.red, c.red, c.bg)),
+        ("binding_mode", format!("{} {} {}", c.yellow, c.yellow, c.bg)),
+    ]
+}
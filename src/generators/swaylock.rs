@@ -0,0 +1,60 @@
+use anyhow::Result;
+use crate::theme::Theme;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Swaylock theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n\n");
+
+    for (key, value) in settings(theme) {
+        output.push_str(&format!("{}={}\n", key, value));
+    }
+
+    Ok(output)
+}
+
+/// Swaylock options, shared by the config file and the Home Manager
+/// `programs.swaylock.settings` module
+///
+/// Each indicator state (normal, clear, ver, wrong, caps-lock) gets its own
+/// ring, inside, line and text colors.
+pub(crate) fn settings(theme: &Theme) -> Vec<(String, String)> {
+    let bg = swaylock_color(&theme.colors.bg);
+    let caps_lock = theme.get_color("orange").unwrap_or(&theme.colors.yellow);
+
+    let mut settings = vec![
+        ("color".to_string(), bg.clone()),
+        ("indicator-thickness".to_string(), (theme.properties.border_width.unwrap_or(2) * 5).to_string()),
+        ("key-hl-color".to_string(), swaylock_color(&theme.colors.green)),
+        ("bs-hl-color".to_string(), swaylock_color(&theme.colors.red)),
+        ("caps-lock-key-hl-color".to_string(), swaylock_color(&theme.colors.green)),
+        ("caps-lock-bs-hl-color".to_string(), swaylock_color(&theme.colors.red)),
+        ("separator-color".to_string(), "00000000".to_string()),
+        ("layout-bg-color".to_string(), bg.clone()),
+        ("layout-border-color".to_string(), swaylock_color(&theme.colors.accent)),
+        ("layout-text-color".to_string(), swaylock_color(&theme.colors.fg)),
+    ];
+
+    let states = [
+        ("", theme.colors.accent.as_str(), theme.colors.fg.as_str()),
+        ("-clear", theme.colors.yellow.as_str(), theme.colors.yellow.as_str()),
+        ("-ver", theme.colors.blue.as_str(), theme.colors.blue.as_str()),
+        ("-wrong", theme.colors.red.as_str(), theme.colors.red.as_str()),
+        ("-caps-lock", caps_lock, caps_lock),
+    ];
+    for (state, ring, text) in states {
+        settings.push((format!("ring{}-color", state), swaylock_color(ring)));
+        settings.push((format!("inside{}-color", state), bg.clone()));
+        settings.push((format!("line{}-color", state), bg.clone()));
+        settings.push((format!("text{}-color", state), swaylock_color(text)));
+    }
+
+    settings
+}
+
+/// Swaylock colors are RRGGBB without a leading '#'
+fn swaylock_color(hex: &str) -> String {
+    hex.trim_start_matches('#').to_string()
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::mako::urgency_colors;
+use super::syntax::derived_colors;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let (bg_light, fg_dim) = derived_colors(theme);
+
+    let border_radius = theme.properties.border_radius.unwrap_or(0);
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let spacing = theme.properties.spacing.unwrap_or(8);
+
+    output.push_str("/* SwayNotificationCenter theme: ");
+    output.push_str(&theme.name);
+    output.push_str(" */\n/* Generated by themectl */\n\n");
+
+    output.push_str(&format!("@define-color bg {};\n", theme.colors.bg));
+    output.push_str(&format!("@define-color bg-alt {};\n", bg_light));
+    output.push_str(&format!("@define-color fg {};\n", theme.colors.fg));
+    output.push_str(&format!("@define-color fg-dim {};\n", fg_dim));
+    output.push_str(&format!("@define-color accent {};\n", theme.colors.accent));
+    for urgency in ["low", "normal", "critical"] {
+        let colors = urgency_colors(theme, urgency);
+        output.push_str(&format!("@define-color {}-border {};\n", urgency, colors.border));
+        output.push_str(&format!("@define-color {}-bg {};\n", urgency, colors.background));
+        output.push_str(&format!("@define-color {}-fg {};\n", urgency, colors.text));
+    }
+    output.push('\n');
+
+    // Notification cards
+    output.push_str("* {\n  font-size: inherit;\n}\n\n");
+    output.push_str(".notification-row {\n  outline: none;\n}\n\n");
+    output.push_str(".notification {\n");
+    output.push_str("  background: @bg;\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  border: {}px solid @normal-border;\n", border_width));
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str(&format!("  margin: {}px;\n", spacing / 2));
+    output.push_str("  box-shadow: none;\n");
+    output.push_str("}\n\n");
+
+    for urgency in ["low", "normal", "critical"] {
+        output.push_str(&format!(".notification.{} {{\n", urgency));
+        output.push_str(&format!("  background: @{}-bg;\n", urgency));
+        output.push_str(&format!("  color: @{}-fg;\n", urgency));
+        output.push_str(&format!("  border-color: @{}-border;\n", urgency));
+        output.push_str("}\n\n");
+    }
+    output.push_str(".notification.critical .summary,\n");
+    output.push_str(".notification.critical .body,\n");
+    output.push_str(".notification.critical .time {\n");
+    output.push_str("  color: @critical-fg;\n");
+    output.push_str("}\n\n");
+
+    output.push_str(".notification-content {\n");
+    output.push_str(&format!("  padding: {}px;\n", spacing));
+    output.push_str("}\n\n");
+    output.push_str(".notification-default-action,\n.notification-action {\n");
+    output.push_str("  background: transparent;\n");
+    output.push_str("  color: inherit;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".notification-default-action:hover,\n.notification-action:hover {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str("}\n\n");
+    output.push_str(".summary {\n  color: @fg;\n  font-weight: bold;\n}\n\n");
+    output.push_str(".body {\n  color: @fg;\n}\n\n");
+    output.push_str(".time {\n  color: @fg-dim;\n}\n\n");
+    output.push_str(".close-button {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".close-button:hover {\n  background: @critical-border;\n  color: @bg;\n}\n\n");
+
+    // Control center
+    output.push_str(".control-center {\n");
+    output.push_str("  background: @bg;\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  border: {}px solid @accent;\n", border_width));
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".control-center-list {\n  background: transparent;\n}\n\n");
+    output.push_str(".floating-notifications {\n  background: transparent;\n}\n\n");
+    output.push_str(".blank-window {\n  background: transparent;\n}\n\n");
+
+    // Widgets
+    output.push_str(".widget-title {\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  margin: {}px;\n", spacing));
+    output.push_str("  font-weight: bold;\n");
+    output.push_str("}\n\n");
+    output.push_str(".widget-title > button {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".widget-title > button:hover {\n  background: @accent;\n  color: @bg;\n}\n\n");
+    output.push_str(".widget-dnd {\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  margin: {}px;\n", spacing));
+    output.push_str("}\n\n");
+    output.push_str(".widget-dnd > switch {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".widget-dnd > switch:checked {\n  background: @accent;\n}\n\n");
+    output.push_str(".widget-dnd > switch slider {\n  background: @fg;\n}\n\n");
+    output.push_str(".widget-label > label {\n  color: @fg-dim;\n}\n\n");
+    output.push_str(".widget-mpris-player {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str(&format!("  padding: {}px;\n", spacing));
+    output.push_str("}\n\n");
+    output.push_str(".widget-volume,\n.widget-backlight {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str(&format!("  padding: {}px;\n", spacing));
+    output.push_str("}\n\n");
+    output.push_str(".widget-buttons-grid {\n");
+    output.push_str("  background: @bg-alt;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".widget-buttons-grid > flowbox > flowboxchild > button {\n");
+    output.push_str("  background: @bg;\n");
+    output.push_str("  color: @fg;\n");
+    output.push_str(&format!("  border-radius: {}px;\n", border_radius));
+    output.push_str("}\n\n");
+    output.push_str(".widget-buttons-grid > flowbox > flowboxchild > button:hover {\n");
+    output.push_str("  background: @accent;\n");
+    output.push_str("  color: @bg;\n");
+    output.push_str("}\n");
+
+    Ok(output)
+}
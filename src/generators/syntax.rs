@@ -0,0 +1,105 @@
+use crate::theme::{Theme, SYNTAX_ROLES};
+use crate::utils::{dim_color, lighten_color};
+
+/// Editor highlight roles mapped to palette keys, shared by the editor generators
+/// so that every editor highlights code the same way for a given theme.
+///
+/// Values are palette keys (`"green"`, `"fg_dim"`, ...) rather than hex colors so
+/// generators can reference their own palette table (`colors.green` in Lua,
+/// `"green"` in a Helix `[palette]`). Overrides from the theme's `[syntax]` table
+/// may also be `#RRGGBB` colors; use [`resolve`] to get a hex color either way.
+pub(crate) struct SyntaxRoles {
+    roles: Vec<(&'static str, String)>,
+}
+
+impl SyntaxRoles {
+    pub(crate) fn from_theme(theme: &Theme) -> Self {
+        let has = |name: &str| theme.get_color(name).is_some();
+        let purple_or_magenta = if has("purple") { "purple" } else { "magenta" };
+
+        let mut roles: Vec<(&'static str, String)> = vec![
+            ("comment", if has("gray") { "gray" } else { "fg_dim" }.to_string()),
+            ("string", "green".to_string()),
+            ("keyword", "blue".to_string()),
+            ("function", "cyan".to_string()),
+            ("type", "yellow".to_string()),
+            ("constant", purple_or_magenta.to_string()),
+            ("statement", "blue".to_string()),
+            ("preproc", purple_or_magenta.to_string()),
+            ("special", if has("pink") { "pink" } else { "cyan" }.to_string()),
+            ("number", purple_or_magenta.to_string()),
+            ("boolean", "blue".to_string()),
+            ("variable", "fg".to_string()),
+            ("parameter", "fg".to_string()),
+            ("property", "blue".to_string()),
+            ("operator", "fg".to_string()),
+            ("punctuation", "fg_dim".to_string()),
+            ("namespace", "yellow".to_string()),
+            ("builtin", if has("pink") { "pink" } else { "cyan" }.to_string()),
+            ("error", "red".to_string()),
+            ("warning", "yellow".to_string()),
+            ("info", "blue".to_string()),
+            ("hint", "cyan".to_string()),
+            ("diff_add", "green".to_string()),
+            ("diff_delete", "red".to_string()),
+            ("diff_change", "yellow".to_string()),
+            ("diff_text", "blue".to_string()),
+        ];
+        if has("orange") {
+            roles.push(("todo", "orange".to_string()));
+            roles.push(("tag", "orange".to_string()));
+        }
+
+        for (role, value) in &theme.syntax {
+            if let Some(entry) = roles.iter_mut().find(|(name, _)| name == role) {
+                entry.1 = value.clone();
+            } else if let Some(name) = SYNTAX_ROLES.iter().find(|name| *name == role) {
+                roles.push((name, value.clone()));
+            }
+        }
+
+        Self { roles }
+    }
+
+    /// Palette key for a role, if the theme defines it
+    pub(crate) fn get(&self, role: &str) -> Option<&str> {
+        self.roles.iter()
+            .find(|(name, _)| *name == role)
+            .map(|(_, key)| key.as_str())
+    }
+
+    /// Palette key for a role that is always defined
+    pub(crate) fn key(&self, role: &str) -> &str {
+        self.get(role).unwrap_or("fg")
+    }
+}
+
+/// Hex color for a role value (a palette key, a derived key or `#RRGGBB`)
+pub(crate) fn resolve(theme: &Theme, value: &str) -> String {
+    if value.starts_with('#') {
+        return value.to_string();
+    }
+    let (bg_light, fg_dim) = derived_colors(theme);
+    match value {
+        "bg_light" => bg_light,
+        "fg_dim" => fg_dim,
+        _ => theme.get_color(value).unwrap_or(&theme.colors.fg).to_string(),
+    }
+}
+
+/// Derived editor colors: (bg_light, fg_dim)
+///
+/// `gray` is preferred for both when the theme defines it.
+pub(crate) fn derived_colors(theme: &Theme) -> (String, String) {
+    let bg_light = if let Some(gray) = theme.get_color("gray") {
+        gray.to_string()
+    } else {
+        lighten_color(&theme.colors.bg, 0.05).unwrap_or_else(|| theme.colors.bg.clone())
+    };
+    let fg_dim = if let Some(gray) = theme.get_color("gray") {
+        gray.to_string()
+    } else {
+        dim_color(&theme.colors.fg, 0.6).unwrap_or_else(|| theme.colors.fg.clone())
+    };
+    (bg_light, fg_dim)
+}
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::mix_colors;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+/// TextMate scope rules: (name, scopes, syntax role, font style)
+const SCOPE_RULES: &[(&str, &str, &str, &str)] = &[
+    ("Comment", "comment, punctuation.definition.comment", "comment", "italic"),
+    ("String", "string, punctuation.definition.string", "string", ""),
+    ("Escape", "constant.character.escape, string.regexp", "special", ""),
+    ("Number", "constant.numeric", "number", ""),
+    ("Boolean", "constant.language.boolean", "boolean", ""),
+    ("Constant", "constant.language, constant.character, constant.other, support.constant", "constant", ""),
+    ("Keyword", "keyword, storage.type, storage.modifier", "keyword", ""),
+    ("Operator", "keyword.operator", "operator", ""),
+    ("Preprocessor", "meta.preprocessor, keyword.control.import, keyword.other.import", "preproc", ""),
+    ("Function", "entity.name.function, support.function, meta.function-call", "function", ""),
+    ("Builtin", "support.function.builtin, variable.language", "builtin", ""),
+    ("Type", "entity.name.type, entity.name.class, support.type, support.class", "type", ""),
+    ("Namespace", "entity.name.namespace, entity.name.module", "namespace", ""),
+    ("Variable", "variable", "variable", ""),
+    ("Parameter", "variable.parameter", "parameter", ""),
+    ("Property", "variable.other.member, variable.other.property, meta.object-literal.key, support.type.property-name, entity.other.attribute-name", "property", ""),
+    ("Tag", "entity.name.tag", "tag", ""),
+    ("Punctuation", "punctuation", "punctuation", ""),
+    ("Invalid", "invalid", "error", ""),
+    ("Diff inserted", "markup.inserted, meta.diff.header.to-file", "diff_add", ""),
+    ("Diff deleted", "markup.deleted, meta.diff.header.from-file", "diff_delete", ""),
+    ("Diff changed", "markup.changed", "diff_change", ""),
+    ("Diff range", "meta.diff.range, meta.diff.header", "diff_text", ""),
+    ("Heading", "markup.heading", "keyword", "bold"),
+    ("Bold", "markup.bold", "type", "bold"),
+    ("Italic", "markup.italic", "namespace", "italic"),
+    ("Link", "markup.underline.link", "info", "underline"),
+];
+
+/// Generate a TextMate .tmTheme for bat, delta and other syntect users
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let c = &theme.colors;
+    let roles = SyntaxRoles::from_theme(theme);
+    let (_, fg_dim) = derived_colors(theme);
+    let mix = |top: &str, weight: f32| mix_colors(top, &c.bg, weight).unwrap_or_else(|| c.bg.clone());
+
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n");
+    output.push_str(&format!("<!-- TextMate theme: {} -->\n", xml_escape(&theme.name)));
+    output.push_str("<!-- Generated by themectl -->\n");
+    output.push_str("<!-- Copy to ~/.config/bat/themes/ and run: bat cache --build -->\n");
+    output.push_str("<plist version=\"1.0\">\n");
+    output.push_str("<dict>\n");
+    output.push_str("  <key>name</key>\n");
+    output.push_str(&format!("  <string>{}</string>\n", xml_escape(&theme.name)));
+    output.push_str("  <key>settings</key>\n");
+    output.push_str("  <array>\n");
+
+    // Global editor colors
+    let globals = [
+        ("background", c.bg.clone()),
+        ("foreground", c.fg.clone()),
+        ("caret", c.accent.clone()),
+        ("selection", mix(&c.accent, 0.3)),
+        ("lineHighlight", mix(&c.fg, 0.08)),
+        ("gutter", c.bg.clone()),
+        ("gutterForeground", fg_dim.clone()),
+        ("invisibles", fg_dim),
+        ("findHighlight", mix(&c.yellow, 0.4)),
+    ];
+    output.push_str("    <dict>\n");
+    output.push_str("      <key>settings</key>\n");
+    output.push_str("      <dict>\n");
+    for (key, color) in globals {
+        output.push_str(&format!("        <key>{}</key>\n", key));
+        output.push_str(&format!("        <string>{}</string>\n", color));
+    }
+    output.push_str("      </dict>\n");
+    output.push_str("    </dict>\n");
+
+    for (name, scope, role, font_style) in SCOPE_RULES {
+        let color = resolve(theme, roles.get(role).unwrap_or_else(|| roles.key("keyword")));
+        output.push_str("    <dict>\n");
+        output.push_str(&format!("      <key>name</key>\n      <string>{}</string>\n", name));
+        output.push_str(&format!("      <key>scope</key>\n      <string>{}</string>\n", scope));
+        output.push_str("      <key>settings</key>\n");
+        output.push_str("      <dict>\n");
+        output.push_str(&format!("        <key>foreground</key>\n        <string>{}</string>\n", color));
+        if !font_style.is_empty() {
+            output.push_str(&format!("        <key>fontStyle</key>\n        <string>{}</string>\n", font_style));
+        }
+        output.push_str("      </dict>\n");
+        output.push_str("    </dict>\n");
+    }
+
+    output.push_str("  </array>\n");
+    output.push_str("</dict>\n");
+    output.push_str("</plist>\n");
+
+    Ok(output)
+}
+
+/// Generate a git config `[delta]` section using the tmTheme and real hex colors
+pub fn generate_delta(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Delta configuration: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Requires the tmTheme in ~/.config/bat/themes/ (run: bat cache --build)\n");
+    output.push_str("# Include with: git config --global --add include.path ~/.config/git/themes/");
+    output.push_str(&theme.name);
+    output.push_str("-delta.conf\n\n");
+
+    output.push_str("[delta]\n");
+    for (key, value) in delta_options(theme) {
+        // Quote colors so git does not read '#' as the start of a comment
+        let value: Vec<String> = value.split(' ')
+            .map(|word| if word.starts_with('#') { format!("\"{}\"", word) } else { word.to_string() })
+            .collect();
+        output.push_str(&format!("    {} = {}\n", key, value.join(" ")));
+    }
+
+    Ok(output)
+}
+
+/// Delta options as delta style strings, shared with the Home Manager module
+pub(crate) fn delta_options(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let mix = |top: &str, weight: f32| mix_colors(top, &c.bg, weight).unwrap_or_else(|| c.bg.clone());
+
+    vec![
+        ("syntax-theme", theme.name.clone()),
+        ("plus-style", format!("syntax {}", mix(&c.green, 0.2))),
+        ("plus-emph-style", format!("syntax {}", mix(&c.green, 0.4))),
+        ("minus-style", format!("syntax {}", mix(&c.red, 0.2))),
+        ("minus-emph-style", format!("syntax {}", mix(&c.red, 0.4))),
+        ("line-numbers-plus-style", c.green.clone()),
+        ("line-numbers-minus-style", c.red.clone()),
+        ("line-numbers-zero-style", fg_dim),
+        ("file-style", format!("{} bold", c.accent)),
+        ("file-decoration-style", format!("{} ul", c.accent)),
+        ("hunk-header-style", "file line-number syntax".to_string()),
+        ("hunk-header-decoration-style", format!("{} box", c.blue)),
+        ("commit-style", format!("{} bold", c.yellow)),
+    ]
+}
+
+fn xml_escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::lighten_color;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    
+    let bg = &theme.colors.bg;
+    let fg = &theme.colors.fg;
+    let accent = &theme.colors.accent;
+    let bg_light = lighten_color(bg, 0.1).unwrap_or_else(|| bg.clone());
+    let muted = theme.get_color("gray").map(|c| c.to_string()).unwrap_or_else(|| bg_light.clone());
+    let current_match = theme.get_color("orange").unwrap_or(accent);
+    
+    output.push_str("# Tmux theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Source it from ~/.config/tmux/tmux.conf:\n");
+    output.push_str("#   source-file ~/.config/tmux/themes/");
+    output.push_str(&theme.name);
+    output.push_str(".conf\n\n");
+    
+    // Status bar
+    output.push_str("# Status bar\n");
+    output.push_str(&format!("set -g status-style \"bg={},fg={}\"\n", bg, fg));
+    output.push_str(&format!("set -g status-left-style \"bg={},fg={},bold\"\n", accent, bg));
+    output.push_str(&format!("set -g status-right-style \"bg={},fg={}\"\n\n", bg_light, fg));
+    
+    // Window status
+    output.push_str("# Window status\n");
+    output.push_str(&format!("set -g window-status-style \"bg={},fg={}\"\n", bg, muted));
+    output.push_str(&format!("set -g window-status-current-style \"bg={},fg={},bold\"\n", accent, bg));
+    output.push_str(&format!("set -g window-status-activity-style \"bg={},fg={}\"\n", bg, theme.colors.yellow));
+    output.push_str(&format!("set -g window-status-bell-style \"bg={},fg={},bold\"\n\n", bg, theme.colors.red));
+    
+    // Pane borders
+    output.push_str("# Pane borders\n");
+    output.push_str(&format!("set -g pane-border-style \"fg={}\"\n", muted));
+    output.push_str(&format!("set -g pane-active-border-style \"fg={}\"\n", accent));
+    output.push_str(&format!("set -g display-panes-colour \"{}\"\n", muted));
+    output.push_str(&format!("set -g display-panes-active-colour \"{}\"\n\n", accent));
+    
+    // Messages and command prompt
+    output.push_str("# Messages\n");
+    output.push_str(&format!("set -g message-style \"bg={},fg={}\"\n", bg_light, fg));
+    output.push_str(&format!("set -g message-command-style \"bg={},fg={}\"\n\n", bg_light, accent));
+    
+    // Copy mode
+    output.push_str("# Copy mode\n");
+    output.push_str(&format!("set -g mode-style \"bg={},fg={}\"\n", accent, bg));
+    output.push_str(&format!("set -g copy-mode-match-style \"bg={},fg={}\"\n", theme.colors.yellow, bg));
+    output.push_str(&format!("set -g copy-mode-current-match-style \"bg={},fg={}\"\n\n", current_match, bg));
+    
+    // Clock
+    output.push_str("# Clock\n");
+    output.push_str(&format!("set -g clock-mode-colour \"{}\"\n", accent));
+    
+    Ok(output)
+}
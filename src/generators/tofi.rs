@@ -0,0 +1,45 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::derived_colors;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let (_, fg_dim) = derived_colors(theme);
+
+    let border_radius = theme.properties.border_radius.unwrap_or(0);
+    let border_width = theme.properties.border_width.unwrap_or(2);
+    let spacing = theme.properties.spacing.unwrap_or(8);
+
+    output.push_str("# Tofi theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Include it from ~/.config/tofi/config:\n");
+    output.push_str("#   include = themes/");
+    output.push_str(&theme.name);
+    output.push_str("\n\n");
+
+    output.push_str(&format!("background-color = {}\n", theme.colors.bg));
+    output.push_str(&format!("text-color = {}\n", theme.colors.fg));
+    output.push_str(&format!("prompt-color = {}\n", theme.colors.accent));
+    output.push_str(&format!("placeholder-color = {}\n", fg_dim));
+    output.push_str(&format!("input-color = {}\n", theme.colors.fg));
+    output.push_str(&format!("default-result-color = {}\n", theme.colors.fg));
+    output.push_str(&format!("selection-color = {}\n", theme.colors.bg));
+    output.push_str(&format!("selection-background = {}\n", theme.colors.accent));
+    output.push_str(&format!("selection-match-color = {}\n", theme.colors.bg));
+    output.push_str(&format!("border-color = {}\n", theme.colors.accent));
+    output.push_str(&format!("outline-color = {}\n", theme.colors.bg));
+    output.push('\n');
+    output.push_str(&format!("border-width = {}\n", border_width));
+    output.push_str("outline-width = 0\n");
+    output.push_str(&format!("corner-radius = {}\n", border_radius));
+    output.push_str(&format!("selection-background-corner-radius = {}\n", border_radius));
+    output.push_str(&format!("selection-background-padding = {}\n", spacing / 2));
+    output.push_str(&format!("result-spacing = {}\n", spacing));
+    output.push_str(&format!("padding-top = {}\n", spacing * 2));
+    output.push_str(&format!("padding-bottom = {}\n", spacing * 2));
+    output.push_str(&format!("padding-left = {}\n", spacing * 2));
+    output.push_str(&format!("padding-right = {}\n", spacing * 2));
+
+    Ok(output)
+}
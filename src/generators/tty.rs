@@ -0,0 +1,75 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::hex_to_rgb;
+use super::palette::ansi_palette;
+
+/// The 16-color palette as (red, green, blue) channel lists
+fn channels(theme: &Theme) -> [Vec<u8>; 3] {
+    let mut channels = [Vec::new(), Vec::new(), Vec::new()];
+    for color in ansi_palette(theme) {
+        let (r, g, b) = hex_to_rgb(&color).unwrap_or((0, 0, 0));
+        channels[0].push(r);
+        channels[1].push(g);
+        channels[2].push(b);
+    }
+    channels
+}
+
+/// Palette as `RRGGBB` strings, the form NixOS `console.colors` expects
+pub(crate) fn console_colors(theme: &Theme) -> Vec<String> {
+    ansi_palette(theme).iter()
+        .map(|color| color.trim_start_matches('#').to_lowercase())
+        .collect()
+}
+
+/// Kernel command line parameters setting the console palette at boot
+pub(crate) fn kernel_params(theme: &Theme) -> String {
+    let [red, green, blue] = channels(theme);
+    let join = |values: &[u8]| values.iter().map(|v| format!("0x{:02x}", v)).collect::<Vec<_>>().join(",");
+    format!("vt.default_red={} vt.default_grn={} vt.default_blu={}", join(&red), join(&green), join(&blue))
+}
+
+/// Generate a setvtrgb palette file: one line each of red, green and blue values
+///
+/// setvtrgb does not accept comments, so this file has no header.
+pub fn generate_vtrgb(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    for channel in channels(theme) {
+        let values: Vec<String> = channel.iter().map(|v| v.to_string()).collect();
+        output.push_str(&values.join(","));
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Generate the kernel command line parameters as a single line
+pub fn generate_kernel_params(theme: &Theme) -> Result<String> {
+    Ok(format!("{}\n", kernel_params(theme)))
+}
+
+/// Generate a script applying the palette to the Linux virtual console
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("#!/bin/sh\n");
+    output.push_str("# Linux console palette: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Run from a virtual console: sh ~/.config/tty/themectl.sh\n");
+    output.push_str("# setvtrgb changes every console (as root); otherwise only the current one is recolored.\n");
+    output.push_str("# To apply the palette at boot, add to the kernel command line:\n");
+    output.push_str(&format!("#   {}\n\n", kernel_params(theme)));
+
+    output.push_str("if [ \"$(id -u)\" -eq 0 ] && command -v setvtrgb >/dev/null 2>&1; then\n");
+    output.push_str("    setvtrgb - <<'EOF'\n");
+    output.push_str(&generate_vtrgb(theme)?);
+    output.push_str("EOF\n");
+    output.push_str("else\n");
+    for (i, color) in ansi_palette(theme).iter().enumerate() {
+        output.push_str(&format!("    printf '\\033]P{:X}{}'\n", i, color.trim_start_matches('#').to_lowercase()));
+    }
+    output.push_str("    clear\n");
+    output.push_str("fi\n");
+
+    Ok(output)
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{hex_to_rgb, is_light_color, mix_colors, theme_slug};
+use super::palette::ansi_palette;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+/// Vim syntax groups colored directly from a syntax role
+const SYNTAX_GROUPS: &[(&str, &str)] = &[
+    ("Comment", "comment"),
+    ("String", "string"),
+    ("Character", "string"),
+    ("Number", "number"),
+    ("Float", "number"),
+    ("Boolean", "boolean"),
+    ("Constant", "constant"),
+    ("Identifier", "variable"),
+    ("Function", "function"),
+    ("Statement", "statement"),
+    ("Conditional", "statement"),
+    ("Repeat", "statement"),
+    ("Label", "special"),
+    ("Operator", "operator"),
+    ("Keyword", "keyword"),
+    ("Exception", "error"),
+    ("PreProc", "preproc"),
+    ("Include", "preproc"),
+    ("Define", "preproc"),
+    ("Macro", "preproc"),
+    ("Type", "type"),
+    ("StorageClass", "keyword"),
+    ("Structure", "type"),
+    ("Typedef", "type"),
+    ("Special", "special"),
+    ("SpecialChar", "special"),
+    ("Delimiter", "punctuation"),
+    ("Error", "error"),
+    ("ErrorMsg", "error"),
+    ("WarningMsg", "warning"),
+    ("MoreMsg", "string"),
+    ("Question", "function"),
+    ("diffAdded", "diff_add"),
+    ("diffRemoved", "diff_delete"),
+    ("diffChanged", "diff_change"),
+];
+
+/// Generate a Vimscript colorscheme for plain Vim with gui and cterm colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let c = &theme.colors;
+    let roles = SyntaxRoles::from_theme(theme);
+    let (_, fg_dim) = derived_colors(theme);
+    // Panel background; bg_light would match fg_dim text for themes that define gray
+    let surface = mix_colors(&c.fg, &c.bg, 0.1).unwrap_or_else(|| c.bg.clone());
+    let role = |name: &str| resolve(theme, roles.key(name));
+    let diff_bg = |name: &str| mix_colors(&role(name), &c.bg, 0.25).unwrap_or_else(|| c.bg.clone());
+
+    output.push_str("\" Vim colorscheme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n\" Generated by themectl\n");
+    output.push_str("\" Copy to ~/.vim/colors/ (Vim 9.1+ also reads ~/.config/vim/colors/), then run:\n");
+    output.push_str(&format!("\"   :colorscheme {}\n\n", theme_slug(&theme.name)));
+
+    let background = if is_light_color(&c.bg) { "light" } else { "dark" };
+    output.push_str(&format!("set background={}\n", background));
+    output.push_str("hi clear\n");
+    output.push_str("if exists(\"syntax_on\")\n");
+    output.push_str("  syntax reset\n");
+    output.push_str("endif\n");
+    // :colorscheme looks up colors/<name>.vim, so the name must be a file name without spaces
+    output.push_str(&format!("let g:colors_name = \"{}\"\n\n", theme_slug(&theme.name)));
+
+    // Terminal colors for :terminal
+    let ansi: Vec<String> = ansi_palette(theme).iter().map(|color| format!("\"{}\"", color)).collect();
+    output.push_str(&format!("let g:terminal_ansi_colors = [{}]\n\n", ansi.join(", ")));
+
+    // Editor UI
+    output.push_str("\" Editor\n");
+    let ui: Vec<(&str, Option<&String>, Option<&String>, &str)> = vec![
+        ("Normal", Some(&c.fg), Some(&c.bg), ""),
+        ("Cursor", Some(&c.bg), Some(&c.accent), ""),
+        ("CursorLine", None, Some(&surface), ""),
+        ("CursorColumn", None, Some(&surface), ""),
+        ("ColorColumn", None, Some(&surface), ""),
+        ("CursorLineNr", Some(&c.accent), None, "bold"),
+        ("LineNr", Some(&fg_dim), None, ""),
+        ("SignColumn", None, Some(&c.bg), ""),
+        ("FoldColumn", Some(&fg_dim), Some(&c.bg), ""),
+        ("Folded", Some(&fg_dim), Some(&surface), ""),
+        ("VertSplit", Some(&fg_dim), Some(&c.bg), ""),
+        ("NonText", Some(&fg_dim), None, ""),
+        ("SpecialKey", Some(&fg_dim), None, ""),
+        ("EndOfBuffer", Some(&c.bg), None, ""),
+        ("Directory", Some(&c.blue), None, ""),
+        ("Title", Some(&c.accent), None, "bold"),
+        ("Visual", Some(&c.bg), Some(&c.accent), ""),
+        ("MatchParen", Some(&c.accent), Some(&surface), "bold"),
+        ("Search", Some(&c.bg), Some(&c.yellow), ""),
+        ("IncSearch", Some(&c.bg), Some(&c.accent), ""),
+        ("StatusLine", Some(&c.bg), Some(&c.accent), ""),
+        ("StatusLineNC", Some(&c.fg), Some(&surface), ""),
+        ("StatusLineTerm", Some(&c.bg), Some(&c.accent), ""),
+        ("StatusLineTermNC", Some(&c.fg), Some(&surface), ""),
+        ("TabLine", Some(&fg_dim), Some(&surface), ""),
+        ("TabLineSel", Some(&c.bg), Some(&c.accent), ""),
+        ("TabLineFill", None, Some(&c.bg), ""),
+        ("WildMenu", Some(&c.bg), Some(&c.accent), ""),
+        ("Pmenu", Some(&c.fg), Some(&surface), ""),
+        ("PmenuSel", Some(&c.bg), Some(&c.accent), ""),
+        ("PmenuSbar", None, Some(&surface), ""),
+        ("PmenuThumb", None, Some(&fg_dim), ""),
+        ("SpellBad", Some(&c.red), None, "undercurl"),
+        ("SpellCap", Some(&c.yellow), None, "undercurl"),
+        ("Underlined", Some(&c.blue), None, "underline"),
+    ];
+    for (group, fg, bg, attr) in ui {
+        output.push_str(&highlight(group, fg.map(String::as_str), bg.map(String::as_str), attr));
+    }
+
+    // Syntax
+    output.push_str("\n\" Syntax\n");
+    for (group, name) in SYNTAX_GROUPS {
+        output.push_str(&highlight(group, Some(&role(name)), None, ""));
+    }
+    if let Some(todo) = roles.get("todo") {
+        output.push_str(&highlight("Todo", Some(&c.bg), Some(&resolve(theme, todo)), "bold"));
+    } else {
+        output.push_str(&highlight("Todo", Some(&c.bg), Some(&c.yellow), "bold"));
+    }
+    if let Some(tag) = roles.get("tag") {
+        output.push_str(&highlight("Tag", Some(&resolve(theme, tag)), None, ""));
+    }
+
+    // Diff mode
+    output.push_str("\n\" Diff\n");
+    output.push_str(&highlight("DiffAdd", None, Some(&diff_bg("diff_add")), ""));
+    output.push_str(&highlight("DiffDelete", Some(&role("diff_delete")), Some(&diff_bg("diff_delete")), ""));
+    output.push_str(&highlight("DiffChange", None, Some(&diff_bg("diff_change")), ""));
+    output.push_str(&highlight("DiffText", None, Some(&diff_bg("diff_text")), "bold"));
+
+    Ok(output)
+}
+
+/// A `hi` command with gui colors and the nearest xterm-256 cterm colors
+fn highlight(group: &str, fg: Option<&str>, bg: Option<&str>, attr: &str) -> String {
+    let mut line = format!("hi {}", group);
+    if let Some(fg) = fg {
+        line.push_str(&format!(" guifg={} ctermfg={}", fg, cterm_color(fg)));
+    }
+    if let Some(bg) = bg {
+        line.push_str(&format!(" guibg={} ctermbg={}", bg, cterm_color(bg)));
+    }
+    let attr = if attr.is_empty() { "NONE" } else { attr };
+    // Terminals without undercurl fall back to underline
+    let cterm_attr = if attr == "undercurl" { "underline" } else { attr };
+    line.push_str(&format!(" gui={} cterm={}\n", attr, cterm_attr));
+    line
+}
+
+/// Nearest xterm-256 color index (6x6x6 cube or grayscale ramp)
+fn cterm_color(hex: &str) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let (r, g, b) = hex_to_rgb(hex).unwrap_or((0, 0, 0));
+    let distance = |(r2, g2, b2): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, r2) + d(g, g2) + d(b, b2)
+    };
+    let nearest_level = |value: u8| {
+        (0..6).min_by_key(|&i| (LEVELS[i] as i32 - value as i32).abs()).unwrap_or(0)
+    };
+
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = ((average as i32 - 3) / 10).clamp(0, 23) as u8;
+    let gray_value = 8 + 10 * gray_step;
+    let gray_index = 232 + gray_step as usize;
+
+    if distance((gray_value, gray_value, gray_value)) < distance(cube) {
+        gray_index as u8
+    } else {
+        cube_index as u8
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::lighten_color;
+use super::palette::ansi_palette;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    let palette = ansi_palette(theme);
+    let bg_light = lighten_color(&theme.colors.bg, 0.1).unwrap_or_else(|| theme.colors.bg.clone());
+    
+    output.push_str("# WezTerm color scheme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Select it in ~/.config/wezterm/wezterm.lua:\n");
+    output.push_str("#   config.color_scheme = \"");
+    output.push_str(&theme.name);
+    output.push_str("\"\n\n");
+    
+    output.push_str("[colors]\n");
+    output.push_str(&format!("foreground = \"{}\"\n", theme.colors.fg));
+    output.push_str(&format!("background = \"{}\"\n", theme.colors.bg));
+    output.push_str(&format!("cursor_bg = \"{}\"\n", theme.colors.accent));
+    output.push_str(&format!("cursor_fg = \"{}\"\n", theme.colors.bg));
+    output.push_str(&format!("cursor_border = \"{}\"\n", theme.colors.accent));
+    output.push_str(&format!("selection_bg = \"{}\"\n", theme.colors.accent));
+    output.push_str(&format!("selection_fg = \"{}\"\n", theme.colors.bg));
+    output.push_str(&format!("scrollbar_thumb = \"{}\"\n", bg_light));
+    output.push_str(&format!("split = \"{}\"\n", bg_light));
+    output.push_str(&format!("ansi = {}\n", toml_array(&palette[..8])));
+    output.push_str(&format!("brights = {}\n\n", toml_array(&palette[8..])));
+    
+    output.push_str("[colors.tab_bar]\n");
+    output.push_str(&format!("background = \"{}\"\n\n", theme.colors.bg));
+    
+    output.push_str("[colors.tab_bar.active_tab]\n");
+    output.push_str(&format!("bg_color = \"{}\"\n", theme.colors.accent));
+    output.push_str(&format!("fg_color = \"{}\"\n\n", theme.colors.bg));
+    
+    output.push_str("[colors.tab_bar.inactive_tab]\n");
+    output.push_str(&format!("bg_color = \"{}\"\n", theme.colors.bg));
+    output.push_str(&format!("fg_color = \"{}\"\n\n", theme.colors.fg));
+    
+    output.push_str("[metadata]\n");
+    output.push_str(&format!("name = \"{}\"\n", theme.name));
+    output.push_str("author = \"themectl\"\n");
+    
+    Ok(output)
+}
+
+fn toml_array(colors: &[String]) -> String {
+    let quoted: Vec<String> = colors.iter().map(|c| format!("\"{}\"", c)).collect();
+    format!("[{}]", quoted.join(", "))
+}
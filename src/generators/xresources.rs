@@ -0,0 +1,34 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::palette::ansi_palette;
+
+/// Resource names and colors, shared with the Home Manager module
+pub(crate) fn properties(theme: &Theme) -> Vec<(String, String)> {
+    let c = &theme.colors;
+    let mut properties = vec![
+        ("*.foreground".to_string(), c.fg.clone()),
+        ("*.background".to_string(), c.bg.clone()),
+        ("*.cursorColor".to_string(), c.accent.clone()),
+    ];
+    for (i, color) in ansi_palette(theme).into_iter().enumerate() {
+        properties.push((format!("*.color{}", i), color));
+    }
+    properties
+}
+
+/// Generate X resources for xterm, urxvt and other X11 applications
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("! Xresources: ");
+    output.push_str(&theme.name);
+    output.push_str("\n! Generated by themectl\n");
+    output.push_str("! Load with: xrdb -merge ~/.config/X11/themectl.Xresources\n");
+    output.push_str("! or add to ~/.Xresources: #include \".config/X11/themectl.Xresources\"\n\n");
+
+    for (name, color) in properties(theme) {
+        output.push_str(&format!("{}: {}\n", name, color));
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use crate::utils::{hex_to_rgb, mix_colors};
+use super::syntax::derived_colors;
+
+/// Zathura color options, shared with the Home Manager module
+pub(crate) fn options(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let (_, fg_dim) = derived_colors(theme);
+    let surface = mix_colors(&c.fg, &c.bg, 0.1).unwrap_or_else(|| c.bg.clone());
+    let rgba = |hex: &str, alpha: f32| {
+        let (r, g, b) = hex_to_rgb(hex).unwrap_or((0, 0, 0));
+        format!("rgba({},{},{},{})", r, g, b, alpha)
+    };
+
+    vec![
+        ("default-bg", c.bg.clone()),
+        ("default-fg", c.fg.clone()),
+        ("statusbar-bg", surface.clone()),
+        ("statusbar-fg", c.fg.clone()),
+        ("inputbar-bg", c.bg.clone()),
+        ("inputbar-fg", c.fg.clone()),
+        ("notification-bg", c.bg.clone()),
+        ("notification-fg", c.fg.clone()),
+        ("notification-error-bg", c.red.clone()),
+        ("notification-error-fg", c.bg.clone()),
+        ("notification-warning-bg", c.yellow.clone()),
+        ("notification-warning-fg", c.bg.clone()),
+        ("highlight-color", rgba(&c.yellow, 0.5)),
+        ("highlight-fg", c.bg.clone()),
+        ("highlight-active-color", rgba(&c.accent, 0.5)),
+        ("completion-bg", surface.clone()),
+        ("completion-fg", c.fg.clone()),
+        ("completion-group-bg", c.bg.clone()),
+        ("completion-group-fg", fg_dim),
+        ("completion-highlight-bg", c.accent.clone()),
+        ("completion-highlight-fg", c.bg.clone()),
+        ("index-bg", c.bg.clone()),
+        ("index-fg", c.fg.clone()),
+        ("index-active-bg", c.accent.clone()),
+        ("index-active-fg", c.bg.clone()),
+        ("render-loading-bg", c.bg.clone()),
+        ("render-loading-fg", c.fg.clone()),
+        ("recolor-lightcolor", c.bg.clone()),
+        ("recolor-darkcolor", c.fg.clone()),
+    ]
+}
+
+/// Generate a zathurarc fragment with the theme colors
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# Zathura colors: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Add to ~/.config/zathura/zathurarc: include themectl\n");
+    output.push_str("# Documents are drawn with the theme colors when recoloring is on (Ctrl+R)\n\n");
+
+    for (option, value) in options(theme) {
+        output.push_str(&format!("set {} \"{}\"\n", option, value));
+    }
+    output.push_str("set recolor-keephue \"true\"\n");
+
+    Ok(output)
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::palette::ansi_palette;
+
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+    
+    output.push_str("// Zellij theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n// Generated by themectl\n");
+    output.push_str("// Select it in ~/.config/zellij/config.kdl:\n");
+    output.push_str("//   theme \"");
+    output.push_str(&theme.name);
+    output.push_str("\"\n\n");
+    
+    output.push_str("themes {\n");
+    output.push_str(&format!("    \"{}\" {{\n", theme.name));
+    for (key, color) in theme_colors(theme) {
+        output.push_str(&format!("        {} \"{}\"\n", key, color));
+    }
+    output.push_str("    }\n");
+    output.push_str("}\n");
+    
+    Ok(output)
+}
+
+/// Zellij theme color slots, shared by the KDL output and the Home Manager
+/// `programs.zellij.settings` module
+pub(crate) fn theme_colors(theme: &Theme) -> Vec<(&'static str, String)> {
+    let palette = ansi_palette(theme);
+    let orange = theme.get_color("orange").unwrap_or(&theme.colors.accent);
+    
+    vec![
+        ("fg", theme.colors.fg.clone()),
+        ("bg", theme.colors.bg.clone()),
+        ("black", palette[0].clone()),
+        ("red", palette[1].clone()),
+        ("green", palette[2].clone()),
+        ("yellow", palette[3].clone()),
+        ("blue", palette[4].clone()),
+        ("magenta", palette[5].clone()),
+        ("cyan", palette[6].clone()),
+        ("white", palette[7].clone()),
+        ("orange", orange.to_string()),
+    ]
+}
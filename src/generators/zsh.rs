@@ -0,0 +1,68 @@
+use anyhow::Result;
+use crate::theme::Theme;
+use super::syntax::{derived_colors, resolve, SyntaxRoles};
+
+/// ZSH_HIGHLIGHT_STYLES keys and their styles
+pub(crate) fn styles(theme: &Theme) -> Vec<(&'static str, String)> {
+    let c = &theme.colors;
+    let roles = SyntaxRoles::from_theme(theme);
+    let (_, fg_dim) = derived_colors(theme);
+    let role = |name: &str| format!("fg={}", resolve(theme, roles.key(name)));
+
+    vec![
+        ("default", format!("fg={}", c.fg)),
+        ("unknown-token", format!("fg={},bold", c.red)),
+        ("reserved-word", role("keyword")),
+        ("alias", role("function")),
+        ("suffix-alias", role("function")),
+        ("global-alias", role("function")),
+        ("builtin", role("builtin")),
+        ("function", role("function")),
+        ("command", role("function")),
+        ("precommand", format!("{},underline", role("function"))),
+        ("hashed-command", role("function")),
+        ("commandseparator", role("operator")),
+        ("autodirectory", format!("fg={},underline", c.blue)),
+        ("path", "underline".to_string()),
+        ("path_pathseparator", format!("fg={},underline", fg_dim)),
+        ("path_prefix", "underline".to_string()),
+        ("globbing", role("special")),
+        ("history-expansion", role("special")),
+        ("command-substitution-delimiter", role("punctuation")),
+        ("process-substitution-delimiter", role("punctuation")),
+        ("back-quoted-argument-delimiter", role("punctuation")),
+        ("single-hyphen-option", role("property")),
+        ("double-hyphen-option", role("property")),
+        ("single-quoted-argument", role("string")),
+        ("double-quoted-argument", role("string")),
+        ("dollar-quoted-argument", role("string")),
+        ("dollar-double-quoted-argument", role("variable")),
+        ("back-double-quoted-argument", role("special")),
+        ("back-dollar-quoted-argument", role("special")),
+        ("assign", role("variable")),
+        ("redirection", role("operator")),
+        ("comment", role("comment")),
+        ("named-fd", role("number")),
+        ("numeric-fd", role("number")),
+        ("arg0", role("function")),
+    ]
+}
+
+/// Generate ZSH_HIGHLIGHT_STYLES for zsh-syntax-highlighting
+pub fn generate(theme: &Theme) -> Result<String> {
+    let mut output = String::new();
+
+    output.push_str("# zsh-syntax-highlighting theme: ");
+    output.push_str(&theme.name);
+    output.push_str("\n# Generated by themectl\n");
+    output.push_str("# Source after loading zsh-syntax-highlighting in ~/.zshrc:\n");
+    output.push_str("#   source ~/.config/zsh/themectl-highlight.zsh\n");
+    output.push_str("# Hex colors need a truecolor terminal (zsh 5.7+)\n\n");
+
+    output.push_str("typeset -gA ZSH_HIGHLIGHT_STYLES\n");
+    for (key, style) in styles(theme) {
+        output.push_str(&format!("ZSH_HIGHLIGHT_STYLES[{}]='{}'\n", key, style));
+    }
+
+    Ok(output)
+}
@@ -3,7 +3,7 @@ use std::fs;
 use std::path::Path;
 
 use crate::cache;
-use crate::theme::Theme;
+use crate::theme::{Theme, SYNTAX_ROLES};
 use crate::utils::{validate_hex_color, check_contrast, ContrastLevel, find_similar_colors, calculate_contrast_ratio};
 
 pub fn parse_theme_file<P: AsRef<Path>>(path: P) -> Result<Theme> {
@@ -114,6 +114,10 @@ pub fn validate_theme(theme: &Theme) -> Result<()> {
         validate_color(gray, "gray")?;
     }
     
+    for (role, value) in &theme.syntax {
+        validate_syntax_role(theme, role, value)?;
+    }
+    
     Ok(())
 }
 
@@ -181,6 +185,35 @@ fn validate_color(color: &str, name: &str) -> Result<()> {
     Ok(())
 }
 
+fn validate_syntax_role(theme: &Theme, role: &str, value: &str) -> Result<()> {
+    if !SYNTAX_ROLES.contains(&role) {
+        anyhow::bail!(
+            "Unknown syntax role '{}' in [syntax] table.\n\
+            \n\
+            Valid roles: {}\n\
+            \n\
+            To fix: Rename or remove the '{}' entry.",
+            role, SYNTAX_ROLES.join(", "), role
+        );
+    }
+    if value.starts_with('#') {
+        return validate_color(value, &format!("syntax.{}", role));
+    }
+    if !matches!(value, "bg_light" | "fg_dim") && theme.get_color(value).is_none() {
+        anyhow::bail!(
+            "Syntax role '{}' refers to '{}', which is not a color in this theme.\n\
+            \n\
+            Syntax roles take either a palette key (bg, fg, accent, red, green, yellow, blue, \
+            magenta, cyan, orange, purple, pink, white, black, gray, bg_light, fg_dim) \
+            or a hex color (#RRGGBB). Optional colors must be defined in [colors] to be used.\n\
+            \n\
+            To fix: Define '{}' in the [colors] table or use a different color for '{}'.",
+            role, value, value, role
+        );
+    }
+    Ok(())
+}
+
 pub fn find_theme_files<P: AsRef<Path>>(dir: P) -> Result<Vec<std::path::PathBuf>> {
     let mut themes = Vec::new();
     
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Theme {
@@ -10,8 +11,23 @@ pub struct Theme {
     pub colors: ColorPalette,
     #[serde(default)]
     pub properties: ThemeProperties,
+    /// Editor syntax role overrides: role name -> palette key or #RRGGBB
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub syntax: BTreeMap<String, String>,
+    /// Wallpaper image for targets that set one (Stylix)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallpaper: Option<String>,
 }
 
+/// Syntax roles that can be overridden in a theme's `[syntax]` table
+pub const SYNTAX_ROLES: &[&str] = &[
+    "comment", "string", "keyword", "function", "type", "constant", "statement",
+    "preproc", "special", "number", "boolean", "variable", "parameter", "property",
+    "operator", "punctuation", "namespace", "builtin", "todo", "tag",
+    "error", "warning", "info", "hint",
+    "diff_add", "diff_delete", "diff_change", "diff_text",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorPalette {
     pub bg: String,
@@ -42,6 +42,80 @@ pub fn dim_color(hex: &str, factor: f32) -> Option<String> {
     Some(rgb_to_hex(r, g, b))
 }
 
+/// Blend `top` over `base`; a weight of 0.0 gives `base`, 1.0 gives `top`
+pub fn mix_colors(top: &str, base: &str, weight: f32) -> Option<String> {
+    let (r1, g1, b1) = hex_to_rgb(top)?;
+    let (r2, g2, b2) = hex_to_rgb(base)?;
+    let weight = weight.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 * weight + b as f32 * (1.0 - weight)).round() as u8;
+    Some(rgb_to_hex(mix(r1, r2), mix(g1, g2), mix(b1, b2)))
+}
+
+/// Convert a hex color to OKLab (L, a, b)
+pub fn hex_to_oklab(hex: &str) -> Option<(f64, f64, f64)> {
+    let (r, g, b) = hex_to_rgb(hex)?;
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    Some((
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ))
+}
+
+/// Convert an OKLab color to hex, clamping out-of-gamut channels
+pub fn oklab_to_hex(l: f64, a: f64, b: f64) -> String {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    let r = 4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_;
+    let g = -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_;
+    let b = -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_;
+
+    let encode = |c: f64| {
+        let c = c.clamp(0.0, 1.0);
+        let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+        (c * 255.0).round() as u8
+    };
+    rgb_to_hex(encode(r), encode(g), encode(b))
+}
+
+/// `count` evenly spaced colors along the path through `stops`, interpolated in OKLab
+///
+/// The first and last colors are the first and last stops.
+pub fn oklab_gradient(stops: &[&str], count: usize) -> Option<Vec<String>> {
+    let points = stops.iter().map(|stop| hex_to_oklab(stop)).collect::<Option<Vec<_>>>()?;
+    if points.is_empty() || count == 0 {
+        return Some(Vec::new());
+    }
+    if points.len() == 1 || count == 1 {
+        return Some(vec![oklab_to_hex(points[0].0, points[0].1, points[0].2); count]);
+    }
+
+    let segments = (points.len() - 1) as f64;
+    let colors = (0..count).map(|i| {
+        let position = i as f64 / (count - 1) as f64 * segments;
+        let index = (position.floor() as usize).min(points.len() - 2);
+        let t = position - index as f64;
+        let (from, to) = (points[index], points[index + 1]);
+        oklab_to_hex(
+            from.0 + (to.0 - from.0) * t,
+            from.1 + (to.1 - from.1) * t,
+            from.2 + (to.2 - from.2) * t,
+        )
+    }).collect();
+    Some(colors)
+}
+
 pub fn validate_hex_color(color: &str) -> bool {
     let hex_regex = Regex::new(r"^#?[0-9A-Fa-f]{6}$").unwrap();
     hex_regex.is_match(color)
@@ -55,6 +129,21 @@ pub fn normalize_hex(hex: &str) -> String {
     }
 }
 
+/// Lowercase slug of a theme name, usable as a Lisp symbol, a Vim colorscheme
+/// name and a file name ("Tokyo Night" becomes "tokyo-night")
+pub fn theme_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() { "themectl".to_string() } else { slug.to_string() }
+}
+
 /// Contrast level requirements (WCAG)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ContrastLevel {
@@ -278,6 +367,14 @@ pub fn generate_variant(theme: &Theme, variant: &str) -> anyhow::Result<Theme> {
             gray: theme.colors.gray.as_ref().map(|c| adjust_color(c)),
         },
         properties: theme.properties.clone(),
+        // Hex overrides follow the palette; palette keys resolve against the new colors
+        syntax: theme.syntax.iter()
+            .map(|(role, value)| {
+                let value = if value.starts_with('#') { adjust_color(value) } else { value.clone() };
+                (role.clone(), value)
+            })
+            .collect(),
+        wallpaper: theme.wallpaper.clone(),
     };
     
     Ok(new_theme)
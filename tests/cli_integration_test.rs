@@ -4,6 +4,8 @@ use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
 use themectl::cli::{Cli, Commands, VariantCommands, ConfigCommands, BackupCommands};
+use themectl::config::ThemectlConfig;
+use themectl::file_manager::FileManager;
 use common::*;
 
 fn create_test_theme_file(temp_dir: &TempDir, name: &str) -> PathBuf {
@@ -145,6 +147,86 @@ fn test_cli_export_theme_to_file() {
     assert!(output_path.exists());
 }
 
+#[test]
+fn test_cli_export_nix_module() {
+    let temp_dir = create_temp_themes_dir();
+    let themes_dir = get_themes_path(&temp_dir);
+    create_test_theme_file(&temp_dir, "export-theme");
+    
+    let output_dir = temp_dir.path().join("themectl");
+    
+    let cli = Cli {
+        command: Commands::Export {
+            theme: "export-theme".to_string(),
+            format: "nix-module".to_string(),
+            output: Some(output_dir.clone()),
+            all: false,
+        },
+        themes_dir: Some(themes_dir),
+        dry_run: false,
+    };
+    
+    let result = cli.execute();
+    assert!(result.is_ok());
+    let module = fs::read_to_string(output_dir.join("default.nix")).unwrap();
+    assert!(module.contains("default = \"test-theme\";"));
+}
+
+#[test]
+fn test_cli_export_stylix() {
+    let temp_dir = create_temp_themes_dir();
+    let themes_dir = get_themes_path(&temp_dir);
+    create_test_theme_file(&temp_dir, "export-theme");
+    
+    let output_dir = temp_dir.path().join("stylix");
+    
+    let cli = Cli {
+        command: Commands::Export {
+            theme: "export-theme".to_string(),
+            format: "stylix".to_string(),
+            output: Some(output_dir.clone()),
+            all: false,
+        },
+        themes_dir: Some(themes_dir),
+        dry_run: false,
+    };
+    
+    let result = cli.execute();
+    assert!(result.is_ok());
+    assert!(fs::read_to_string(output_dir.join("test-theme.yaml")).unwrap().contains("base00: \"282828\""));
+    assert!(fs::read_to_string(output_dir.join("test-theme.nix")).unwrap().contains("base16Scheme = ./test-theme.yaml;"));
+}
+
+#[test]
+fn test_cli_export_stylix_copies_wallpaper() {
+    let temp_dir = create_temp_themes_dir();
+    let themes_dir = get_themes_path(&temp_dir);
+    let theme_path = create_test_theme_file(&temp_dir, "wallpaper-theme");
+    let content = fs::read_to_string(&theme_path).unwrap()
+        .replace("[colors]", "wallpaper = \"walls/forest.png\"\n\n[colors]");
+    fs::write(&theme_path, content).unwrap();
+    fs::create_dir_all(themes_dir.join("walls")).unwrap();
+    fs::write(themes_dir.join("walls").join("forest.png"), b"png").unwrap();
+    
+    let output_dir = temp_dir.path().join("stylix");
+    
+    let cli = Cli {
+        command: Commands::Export {
+            theme: "wallpaper-theme".to_string(),
+            format: "stylix".to_string(),
+            output: Some(output_dir.clone()),
+            all: false,
+        },
+        themes_dir: Some(themes_dir),
+        dry_run: false,
+    };
+    
+    assert!(cli.execute().is_ok());
+    // Resolved against the theme file and copied next to the snippet
+    assert_eq!(fs::read(output_dir.join("forest.png")).unwrap(), b"png");
+    assert!(fs::read_to_string(output_dir.join("test-theme.nix")).unwrap().contains("image = ./forest.png;"));
+}
+
 #[test]
 fn test_cli_export_all_formats() {
     let temp_dir = create_temp_themes_dir();
@@ -405,6 +487,59 @@ fn test_cli_export_all_themes() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_cli_export_all_nix_lib() {
+    let temp_dir = create_temp_themes_dir();
+    let themes_dir = get_themes_path(&temp_dir);
+    create_test_theme_file(&temp_dir, "theme1");
+    
+    let output_dir = temp_dir.path().join("lib");
+    
+    let cli = Cli {
+        command: Commands::ExportAll {
+            format: "nix-lib".to_string(),
+            output_dir: output_dir.clone(),
+        },
+        themes_dir: Some(themes_dir),
+        dry_run: false,
+    };
+    
+    let result = cli.execute();
+    assert!(result.is_ok());
+    let index = fs::read_to_string(output_dir.join("default.nix")).unwrap();
+    assert!(index.contains("colors = import ./test-theme.nix;"));
+    assert!(output_dir.join("test-theme.nix").exists());
+    assert!(output_dir.join("home-manager.nix").exists());
+}
+
+#[test]
+fn test_flake_nix_lib_matches_bundled_themes() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_dir = temp_dir.path().join("lib");
+    
+    let cli = Cli {
+        command: Commands::ExportAll {
+            format: "nix-lib".to_string(),
+            output_dir: output_dir.clone(),
+        },
+        themes_dir: Some(PathBuf::from("themes")),
+        dry_run: false,
+    };
+    cli.execute().unwrap();
+    
+    for entry in fs::read_dir(&output_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let committed = PathBuf::from("nix/lib").join(path.file_name().unwrap());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            fs::read_to_string(&committed).unwrap_or_default(),
+            "{:?} is out of date; regenerate it as described under Development in README.md:\n\
+            cargo run -- export-all nix-lib nix/lib --themes-dir themes",
+            committed
+        );
+    }
+}
+
 #[test]
 fn test_cli_variant_create() {
     let temp_dir = create_temp_themes_dir();
@@ -596,6 +731,65 @@ fn test_cli_apply_theme_dry_run() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_apply_nixos_dry_run_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().to_path_buf();
+    let config = ThemectlConfig { nixos_mode: true, ..ThemectlConfig::default() };
+    
+    let manager = FileManager::with_config(Some(&config_dir), true, Some(config));
+    manager.apply_theme_filtered(&create_test_theme(), &["nixos"]).unwrap();
+    
+    // The module is only printed for review
+    assert_eq!(fs::read_dir(&config_dir).unwrap().count(), 0);
+}
+
+#[test]
+fn test_apply_nixos_goes_under_config_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().to_path_buf();
+    let config = ThemectlConfig { nixos_mode: true, ..ThemectlConfig::default() };
+    
+    let manager = FileManager::with_config(Some(&config_dir), false, Some(config));
+    manager.apply_theme_filtered(&create_test_theme(), &["nixos"]).unwrap();
+    
+    let module = fs::read_to_string(config_dir.join("nixos").join("themectl").join("system.nix")).unwrap();
+    assert!(module.contains("console.colors = ["));
+    assert!(module.contains("services.greetd.settings.default_session.command"));
+}
+
+#[test]
+fn test_apply_tmtheme_writes_delta_under_config_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().to_path_buf();
+    let config = ThemectlConfig { deployment_method: "standard".to_string(), ..ThemectlConfig::default() };
+    let theme = create_test_theme();
+    
+    let manager = FileManager::with_config(Some(&config_dir), false, Some(config));
+    manager.apply_theme_filtered(&theme, &["tmtheme"]).unwrap();
+    
+    let delta = config_dir.join("git").join("themes").join(format!("{}-delta.conf", theme.name));
+    assert!(fs::read_to_string(delta).unwrap().contains("[delta]"));
+}
+
+#[test]
+fn test_apply_firefox_uses_profiles_under_config_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_dir = temp_dir.path().to_path_buf();
+    let firefox_dir = config_dir.join(".mozilla").join("firefox");
+    fs::create_dir_all(firefox_dir.join("abc.default")).unwrap();
+    fs::write(firefox_dir.join("profiles.ini"), "[Profile0]\nName=default\nIsRelative=1\nPath=abc.default\n").unwrap();
+    let config = ThemectlConfig { deployment_method: "standard".to_string(), ..ThemectlConfig::default() };
+    
+    let manager = FileManager::with_config(Some(&config_dir), false, Some(config));
+    manager.apply_theme_filtered(&create_test_theme(), &["firefox"]).unwrap();
+    
+    let profile = firefox_dir.join("abc.default");
+    assert!(profile.join("chrome").join("userChrome.css").exists());
+    assert!(profile.join("chrome").join("userContent.css").exists());
+    assert!(fs::read_to_string(profile.join("user.js")).unwrap().contains("toolkit.legacyUserProfileCustomizations.stylesheets"));
+}
+
 #[test]
 fn test_cli_apply_theme_with_apps() {
     let temp_dir = create_temp_themes_dir();
@@ -27,6 +27,8 @@ pub fn create_test_theme() -> Theme {
             gray: None,
         },
         properties: ThemeProperties::default(),
+        syntax: Default::default(),
+        wallpaper: None,
     }
 }
 
@@ -61,6 +63,8 @@ pub fn create_full_test_theme() -> Theme {
             animation_duration: Some(0.2),
             spacing: Some(4),
         },
+        syntax: Default::default(),
+        wallpaper: None,
     }
 }
 
@@ -116,6 +120,8 @@ pub fn create_low_contrast_theme() -> Theme {
             gray: None,
         },
         properties: ThemeProperties::default(),
+        syntax: Default::default(),
+        wallpaper: None,
     }
 }
 
@@ -144,6 +150,8 @@ pub fn create_similar_colors_theme() -> Theme {
             gray: None,
         },
         properties: ThemeProperties::default(),
+        syntax: Default::default(),
+        wallpaper: None,
     }
 }
 
@@ -172,5 +180,7 @@ pub fn create_aaa_contrast_theme() -> Theme {
             gray: None,
         },
         properties: ThemeProperties::default(),
+        syntax: Default::default(),
+        wallpaper: None,
     }
 }
@@ -0,0 +1,78 @@
+use themectl::config::{parse_hyprland_version, GitConfig, ThemectlConfig};
+
+#[test]
+fn test_parse_hyprland_version() {
+    assert_eq!(parse_hyprland_version("0.45"), Some((0, 45)));
+    assert_eq!(parse_hyprland_version("0.41.2"), Some((0, 41)));
+    assert_eq!(parse_hyprland_version("v0.46.0"), Some((0, 46)));
+    assert_eq!(parse_hyprland_version("0"), None);
+    assert_eq!(parse_hyprland_version("latest"), None);
+    assert_eq!(parse_hyprland_version("0.45.1.2"), None);
+}
+
+#[test]
+fn test_set_hyprland_version() {
+    let mut config = ThemectlConfig::default();
+    assert_eq!(config.hyprland.version, "0.45");
+    
+    assert!(config.set_hyprland_version("0.40").is_ok());
+    assert_eq!(config.hyprland.version, "0.40");
+    
+    assert!(config.set_hyprland_version("newest").is_err());
+    assert_eq!(config.hyprland.version, "0.40");
+}
+
+#[test]
+fn test_hyprland_config_defaults_when_missing() {
+    let config: ThemectlConfig = toml::from_str("deployment_method = \"standard\"").unwrap();
+    assert_eq!(config.hyprland.version, "0.45");
+    
+    let config: ThemectlConfig = toml::from_str("[hyprland]\nversion = \"0.42\"").unwrap();
+    assert_eq!(config.hyprland.version, "0.42");
+}
+
+#[test]
+fn test_set_git_colors() {
+    let mut config = ThemectlConfig::default();
+    assert_eq!(config.git.colors, "auto");
+    
+    assert!(config.set_git_colors("truecolor").is_ok());
+    assert!(config.git.use_truecolor());
+    assert!(config.set_git_colors("16").is_ok());
+    assert!(!config.git.use_truecolor());
+    
+    assert!(config.set_git_colors("256").is_err());
+    assert_eq!(config.git.colors, "16");
+}
+
+#[test]
+fn test_git_colors_resolve_auto() {
+    let mut config = ThemectlConfig::default();
+    config.git.resolve_auto();
+    assert!(config.git.colors == "truecolor" || config.git.colors == "16");
+    
+    config.git.colors = "16".to_string();
+    config.git.resolve_auto();
+    assert_eq!(config.git.colors, "16");
+}
+
+#[test]
+fn test_git_config_defaults_when_missing() {
+    let config: ThemectlConfig = toml::from_str("deployment_method = \"standard\"").unwrap();
+    assert_eq!(config.git.colors, GitConfig::default().colors);
+    
+    let config: ThemectlConfig = toml::from_str("[git]\ncolors = \"truecolor\"").unwrap();
+    assert!(config.git.use_truecolor());
+}
+
+#[test]
+fn test_set_firefox_profile() {
+    let mut config = ThemectlConfig::default();
+    assert!(config.firefox.profile.is_none());
+    
+    config.set_firefox_profile(std::path::PathBuf::from("/home/user/.mozilla/firefox/abc.default"));
+    assert_eq!(config.firefox.profile.as_deref(), Some(std::path::Path::new("/home/user/.mozilla/firefox/abc.default")));
+    
+    let config: ThemectlConfig = toml::from_str("[firefox]\nprofile = \"/tmp/profile\"").unwrap();
+    assert_eq!(config.firefox.profile, Some(std::path::PathBuf::from("/tmp/profile")));
+}
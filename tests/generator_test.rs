@@ -40,6 +40,359 @@ fn test_generate_kitty_with_optional_colors() {
     }
 }
 
+#[test]
+fn test_generate_alacritty() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "alacritty");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("[colors.primary]"));
+    assert!(output.contains("[colors.normal]"));
+    assert!(output.contains("[colors.bright]"));
+    assert!(output.contains("[colors.cursor]"));
+    assert!(output.contains("[colors.selection]"));
+    assert!(output.contains("[colors.search.matches]"));
+    assert!(output.contains("[colors.hints.start]"));
+    assert!(output.contains(&format!("background = \"{}\"", theme.colors.bg)));
+    assert!(output.contains(&format!("red = \"{}\"", theme.colors.red)));
+    
+    // Output must be valid TOML
+    assert!(output.parse::<toml::Table>().is_ok());
+}
+
+#[test]
+fn test_generate_alacritty_home_manager_module() {
+    let theme = create_test_theme();
+    let result = generators::generate_home_manager_module(&theme, "alacritty");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output.contains("programs.alacritty"));
+    assert!(output.contains("settings.colors"));
+    assert!(output.contains(&format!("background = \"{}\";", theme.colors.bg)));
+}
+
+#[test]
+fn test_home_manager_modules_use_typed_settings() {
+    let theme = create_test_theme();
+
+    let kitty = generators::generate_home_manager_module(&theme, "kitty").unwrap();
+    assert!(kitty.contains(&format!("      background = \"{}\";", theme.colors.bg)));
+    assert!(kitty.contains("color15 = \""));
+    assert!(!kitty.contains("extraConfig"));
+
+    let starship = generators::generate_home_manager_module(&theme, "starship").unwrap();
+    assert!(starship.contains("palette = {"));
+    assert!(!starship.contains("importTOML"));
+
+    let mako = generators::generate_home_manager_module(&theme, "mako").unwrap();
+    assert!(mako.contains("services.mako"));
+    assert!(mako.contains(&format!("      background-color = \"{}\";", theme.colors.bg)));
+    assert!(mako.contains("\"urgency=critical\" = {"));
+
+    let btop = generators::generate_home_manager_module(&theme, "btop").unwrap();
+    assert!(btop.contains(&format!("settings.color_theme = \"{}\";", theme.name)));
+    assert!(btop.contains(&format!("xdg.configFile.\"btop/themes/{}.theme\".text", theme.name)));
+
+    let git = generators::generate_home_manager_module(&theme, "git").unwrap();
+    assert!(git.contains("extraConfig.color = {"));
+    assert!(git.contains("      diff = {"));
+    assert!(git.contains("ui = \"auto\";"));
+
+    let hyprland = generators::generate_home_manager_module(&theme, "hyprland").unwrap();
+    assert!(hyprland.contains("    settings = {"));
+    assert!(hyprland.contains("\"col.active_border\" = \""));
+    assert!(hyprland.contains("general = {"));
+    assert!(!hyprland.contains("extraConfig"));
+}
+
+#[test]
+fn test_home_manager_module_escapes_nix_strings() {
+    let mut theme = create_full_test_theme();
+    theme.name = "it''s-\"${x}\"".to_string();
+
+    let btop = generators::generate_home_manager_module(&theme, "btop").unwrap();
+    assert!(btop.contains("color_theme = \"it''s-\\\"\\${x}\\\"\";"));
+
+    for (app, _) in generators::generate_all(&theme).unwrap() {
+        if app == "nix" {
+            continue;
+        }
+        let module = generators::generate_home_manager_module(&theme, &app).unwrap();
+        let errors = rnix::parse(&module).errors();
+        assert!(errors.is_empty(), "{} module does not parse: {:?}\n{}", app, errors, module);
+        // Every ${x} from the name must be escaped, in "…" (\${) and ''…'' (''${) strings
+        for line in module.lines().filter(|line| !line.trim_start().starts_with('#')) {
+            for (i, _) in line.match_indices("${x}") {
+                let before = &line[..i];
+                assert!(
+                    before.ends_with('\\') || before.ends_with("''"),
+                    "{} module interpolates the theme name: {}", app, line
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_home_manager_modules_parse_as_nix() {
+    let theme = create_full_test_theme();
+    for (app, _) in generators::generate_all(&theme).unwrap() {
+        if app == "nix" {
+            continue;
+        }
+        let module = generators::generate_home_manager_module(&theme, &app).unwrap();
+        let errors = rnix::parse(&module).errors();
+        assert!(errors.is_empty(), "{} module does not parse: {:?}\n{}", app, errors, module);
+    }
+}
+
+#[test]
+fn test_generate_theme_switching_module() {
+    let themes = vec![create_test_theme(), create_full_test_theme()];
+    let module = generators::generate_theme_switching_module(&themes, Some("test-theme"), &themectl::config::ThemectlConfig::default()).unwrap();
+
+    assert!(module.contains("options.themectl = {"));
+    assert!(module.contains("type = lib.types.enum (builtins.attrNames themes);"));
+    assert!(module.contains("default = \"test-theme\";"));
+    assert!(module.contains("    test-theme = {"));
+    assert!(module.contains("    full-test-theme = {"));
+    assert!(module.contains(&format!("        bg = \"{}\";", themes[0].colors.bg)));
+    assert!(module.contains("        kitty = { config, lib, pkgs, ... }:"));
+    assert!(module.contains("\"kitty\""));
+    assert!(!module.contains("\"nix\""));
+    assert!(module.contains("specialisation = lib.genAttrs"));
+    assert!(module.contains("configuration.themectl.theme = lib.mkForce name;"));
+
+    let errors = rnix::parse(&module).errors();
+    assert!(errors.is_empty(), "module does not parse: {:?}", errors);
+}
+
+#[test]
+fn test_generate_theme_switching_module_rejects_unknown_default() {
+    let themes = vec![create_test_theme()];
+    let result = generators::generate_theme_switching_module(&themes, Some("missing"), &themectl::config::ThemectlConfig::default());
+    assert!(result.is_err());
+
+    let duplicates = vec![create_test_theme(), create_test_theme()];
+    let result = generators::generate_theme_switching_module(&duplicates, Some("test-theme"), &themectl::config::ThemectlConfig::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_generate_theme_index() {
+    let mut dark = create_test_theme();
+    dark.variant = Some("dark".to_string());
+    let themes = vec![create_full_test_theme(), dark];
+    let index = generators::generate_theme_index(&themes).unwrap();
+
+    assert!(index.contains("  test-theme = {"));
+    assert!(index.contains("    name = \"test-theme\";"));
+    assert!(index.contains("    variant = \"dark\";"));
+    assert!(index.contains("    colors = import ./full-test-theme.nix;"));
+    assert!(index.find("full-test-theme = {").unwrap() < index.find("  test-theme = {").unwrap());
+    assert!(rnix::parse(&index).errors().is_empty());
+
+    let mut spaced = create_test_theme();
+    spaced.name = "my theme".to_string();
+    let index = generators::generate_theme_index(&[spaced]).unwrap();
+    assert!(index.contains("colors = import (./. + \"/my theme.nix\");"));
+}
+
+#[test]
+fn test_generate_stylix() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "stylix").unwrap();
+
+    assert!(output.contains("scheme: \"test-theme\"\n"));
+    assert!(output.contains("author: \"themectl\"\n"));
+    assert!(output.contains("base00: \"282828\"\n"));
+    assert!(output.contains("base05: \"ebdbb2\"\n"));
+    assert!(output.contains("base08: \"cc241d\"\n"));
+    assert!(output.contains("base0A: \"d79921\"\n"));
+    assert!(output.contains("base0D: \"458588\"\n"));
+    assert!(output.contains("base0F: \""));
+    let colors: Vec<&str> = output.lines().filter(|line| line.starts_with("base0")).collect();
+    assert_eq!(colors.len(), 16);
+    assert!(colors.iter().all(|line| !line.contains('#')));
+}
+
+#[test]
+fn test_generate_stylix_nix() {
+    let mut theme = create_test_theme();
+    let output = generators::generate(&theme, "stylix-nix").unwrap();
+    assert!(output.contains("base16Scheme = ./test-theme.yaml;"));
+    assert!(output.contains("polarity = \"dark\";"));
+    assert!(!output.contains("image ="));
+    assert!(rnix::parse(&output).errors().is_empty());
+
+    theme.variant = Some("light".to_string());
+    theme.wallpaper = Some("walls/forest.png".to_string());
+    let output = generators::generate(&theme, "stylix-nix").unwrap();
+    assert!(output.contains("polarity = \"light\";"));
+    assert!(output.contains("image = ./forest.png;"));
+
+    // The image is referenced next to the snippet, where the export copies it
+    theme.wallpaper = Some("/srv/walls/my forest.png".to_string());
+    let output = generators::generate(&theme, "stylix-nix").unwrap();
+    assert!(output.contains("image = (./. + \"/my forest.png\");"));
+    assert!(rnix::parse(&output).errors().is_empty());
+
+    // Home-relative paths are impure in flakes
+    theme.wallpaper = Some("~/Pictures/forest.png".to_string());
+    let output = generators::generate(&theme, "stylix-nix").unwrap();
+    assert!(!output.contains("image ="));
+    assert!(output.contains("# wallpaper ~/Pictures/forest.png skipped"));
+    assert!(rnix::parse(&output).errors().is_empty());
+}
+
+#[test]
+fn test_generate_nixos() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "nixos").unwrap();
+
+    assert!(output_contains_name(&output, &theme.name));
+    // console.colors takes RRGGBB without '#'
+    assert!(output.contains("console.colors = [\n    \"282828\"\n    \"cc241d\"\n"));
+    let console = output.split("console.colors = [").nth(1).unwrap().split("];").next().unwrap();
+    assert_eq!(console.lines().filter(|line| line.trim().starts_with('"')).count(), 16);
+    assert!(!console.contains('#'));
+
+    assert!(output.contains("backgroundColor = \"#282828\";"));
+    assert!(output.contains("desktop-color: \"#282828\""));
+    assert!(output.contains("selected_item_color = \"#fe8019\""));
+    assert!(output.contains("--theme 'border="));
+    assert!(output.contains("container=black"));
+    assert!(output.contains("theme = \"themectl\";"));
+    assert!(output.contains("Window.SetBackgroundTopColor(0.157, 0.157, 0.157);"));
+    // Without these callbacks a script theme hides the LUKS password prompt
+    for callback in ["SetDisplayPasswordFunction", "SetDisplayQuestionFunction", "SetDisplayNormalFunction", "SetMessageFunction"] {
+        assert!(output.contains(&format!("Plymouth.{}(", callback)), "Missing Plymouth.{}", callback);
+    }
+    assert!(rnix::parse(&output).errors().is_empty());
+}
+
+#[test]
+fn test_generate_foot() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "foot");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("[colors]"));
+    // Foot uses bare RRGGBB values
+    assert!(output.contains(&format!("background={}", theme.colors.bg.trim_start_matches('#'))));
+    assert!(output.contains(&format!("regular1={}", theme.colors.red.trim_start_matches('#'))));
+    assert!(output.contains("bright7="));
+}
+
+#[test]
+fn test_generate_wezterm() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "wezterm");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    
+    let parsed: toml::Table = output.parse().expect("WezTerm scheme should be valid TOML");
+    let colors = parsed["colors"].as_table().unwrap();
+    assert_eq!(colors["background"].as_str(), Some(theme.colors.bg.as_str()));
+    assert_eq!(colors["ansi"].as_array().unwrap().len(), 8);
+    assert_eq!(colors["brights"].as_array().unwrap().len(), 8);
+}
+
+#[test]
+fn test_generate_ghostty() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "ghostty");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains(&format!("palette = 1={}", theme.colors.red)));
+    assert!(output.contains("palette = 15="));
+    assert!(output.contains(&format!("background = {}", theme.colors.bg)));
+    assert!(output.contains("cursor-color = "));
+}
+
+#[test]
+fn test_terminal_generators_share_palette() {
+    let theme = create_full_test_theme();
+    let kitty = generators::generate(&theme, "kitty").unwrap();
+    let ghostty = generators::generate(&theme, "ghostty").unwrap();
+    let foot = generators::generate(&theme, "foot").unwrap();
+    
+    // color8 is the theme's gray in every terminal
+    let gray = theme.colors.gray.as_ref().unwrap();
+    assert!(kitty.contains(&format!("color8 {}", gray)));
+    assert!(ghostty.contains(&format!("palette = 8={}", gray)));
+    assert!(foot.contains(&format!("bright0={}", gray.trim_start_matches('#'))));
+    
+    // color1 is red, not a shifted palette entry
+    assert!(kitty.contains(&format!("color1 {}", theme.colors.red)));
+}
+
+#[test]
+fn test_generate_tmux() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "tmux");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains(&format!("set -g status-style \"bg={},fg={}\"", theme.colors.bg, theme.colors.fg)));
+    assert!(output.contains("set -g window-status-current-style"));
+    assert!(output.contains(&format!("set -g pane-active-border-style \"fg={}\"", theme.colors.accent)));
+    assert!(output.contains("set -g message-style"));
+    assert!(output.contains("set -g mode-style"));
+    assert!(output.contains("set -g copy-mode-match-style"));
+}
+
+#[test]
+fn test_generate_zellij() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "zellij");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output.contains("themes {"));
+    assert!(output.contains(&format!("\"{}\" {{", theme.name)));
+    assert!(output.contains(&format!("bg \"{}\"", theme.colors.bg)));
+    assert!(output.contains(&format!("red \"{}\"", theme.colors.red)));
+    // No orange in the theme, so it falls back to the accent
+    assert!(output.contains(&format!("orange \"{}\"", theme.colors.accent)));
+}
+
+#[test]
+fn test_generate_multiplexer_home_manager_modules() {
+    let theme = create_test_theme();
+    
+    let tmux = generators::generate_home_manager_module(&theme, "tmux").unwrap();
+    assert!(tmux.contains("programs.tmux"));
+    assert!(tmux.contains("extraConfig = ''"));
+    assert!(tmux.contains("set -g status-style"));
+    
+    let zellij = generators::generate_home_manager_module(&theme, "zellij").unwrap();
+    assert!(zellij.contains("programs.zellij"));
+    assert!(zellij.contains(&format!("theme = \"{}\";", theme.name)));
+    assert!(zellij.contains(&format!("bg = \"{}\";", theme.colors.bg)));
+}
+
+#[test]
+fn test_reload_hints() {
+    assert!(generators::reload_hint("kitty").is_some());
+    assert!(generators::reload_hint("foot").is_some());
+    assert!(generators::reload_hint("wezterm").is_some());
+    assert!(generators::reload_hint("ghostty").is_some());
+    assert!(generators::reload_hint("tmux").unwrap().contains("tmux source-file"));
+    assert_eq!(generators::reload_hint("dunst"), Some("dunstctl reload"));
+    assert!(generators::reload_hint("nix").is_none());
+}
+
 #[test]
 fn test_generate_waybar() {
     let theme = create_test_theme();
@@ -81,6 +434,90 @@ fn test_generate_neovim_with_optional_colors() {
     }
 }
 
+#[test]
+fn test_generate_neovim_modern_groups() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "neovim").unwrap();
+    
+    for group in ["\"@keyword\"", "\"@function.call\"", "\"@variable.parameter\"",
+                  "\"DiagnosticVirtualTextError\"", "\"DiagnosticUnderlineWarn\"", "\"LspReferenceText\"",
+                  "\"@lsp.type.function\"", "\"TelescopeSelection\"", "\"GitSignsAdd\"",
+                  "\"WhichKeyGroup\"", "\"CmpItemAbbrMatch\"", "\"CmpItemKindFunction\""] {
+        assert!(output.contains(group), "Missing highlight group {}", group);
+    }
+    
+    // Terminal colors follow the shared ANSI palette
+    assert!(output.contains(&format!("vim.g.terminal_color_1 = \"{}\"", theme.colors.red)));
+    assert!(output.contains(&format!("vim.g.terminal_color_8 = \"{}\"", theme.colors.gray.as_ref().unwrap())));
+    assert!(output.contains("vim.g.terminal_color_15 = "));
+}
+
+#[test]
+fn test_neovim_syntax_overrides() {
+    let mut theme = create_full_test_theme();
+    theme.syntax.insert("keyword".to_string(), "red".to_string());
+    theme.syntax.insert("comment".to_string(), "#7c6f64".to_string());
+    
+    let neovim = generators::generate(&theme, "neovim").unwrap();
+    assert!(neovim.contains("\"Keyword\", { fg = colors.red }"));
+    assert!(neovim.contains("\"@keyword\", { fg = colors.red }"));
+    assert!(neovim.contains("\"Comment\", { fg = \"#7c6f64\" }"));
+    
+    // Overrides apply to every editor using the syntax mapping
+    let helix: toml::Table = generators::generate(&theme, "helix").unwrap().parse().unwrap();
+    assert_eq!(helix["keyword"].as_str(), Some("red"));
+}
+
+#[test]
+fn test_generate_lualine() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "lualine").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("return {"));
+    for mode in ["normal", "insert", "visual", "replace", "command", "inactive"] {
+        assert!(output.contains(&format!("  {} = {{", mode)), "Missing lualine mode {}", mode);
+    }
+    // Insert mode uses the string color, like the colorscheme
+    assert!(output.contains(&format!("a = {{ bg = \"{}\"", theme.colors.green)));
+}
+
+#[test]
+fn test_generate_helix() {
+    let theme = create_test_theme();
+    let result = generators::generate(&theme, "helix");
+    
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    assert!(output_contains_name(&output, &theme.name));
+    
+    let parsed: toml::Table = output.parse().expect("Helix theme should be valid TOML");
+    let palette = parsed["palette"].as_table().unwrap();
+    assert_eq!(palette["bg"].as_str(), Some(theme.colors.bg.as_str()));
+    assert!(palette.contains_key("fg_dim"));
+    for scope in ["ui.background", "ui.statusline", "ui.selection", "ui.cursor",
+                  "diagnostic.error", "markup.heading", "keyword", "function", "string", "type", "comment"] {
+        assert!(parsed.contains_key(scope), "Missing scope {}", scope);
+    }
+}
+
+#[test]
+fn test_helix_and_neovim_share_syntax_colors() {
+    let theme = create_full_test_theme();
+    let helix: toml::Table = generators::generate(&theme, "helix").unwrap().parse().unwrap();
+    let neovim = generators::generate(&theme, "neovim").unwrap();
+    
+    for (scope, group) in [("keyword", "Keyword"), ("function", "Function"), ("string", "String"), ("type", "Type")] {
+        let key = helix[scope].as_str().unwrap();
+        assert!(
+            neovim.contains(&format!("\"{}\", {{ fg = colors.{} }}", group, key)),
+            "{} and {} should use the same color",
+            scope,
+            group
+        );
+    }
+}
+
 #[test]
 fn test_generate_starship() {
     let theme = create_test_theme();
@@ -121,6 +558,121 @@ fn test_generate_mako() {
     assert!(output_contains_color(&output, &theme.colors.fg));
 }
 
+#[test]
+fn test_generate_dunst() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "dunst").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    for section in ["[global]", "[urgency_low]", "[urgency_normal]", "[urgency_critical]"] {
+        assert!(output.contains(section), "Missing dunst section {}", section);
+    }
+    assert!(output.contains(&format!("frame_color = \"{}\"", theme.colors.accent)));
+    assert!(output.contains("corner_radius = 8"));
+}
+
+#[test]
+fn test_generate_swaync() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "swaync").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    for selector in [".notification {", ".notification.critical {", ".control-center {",
+                     ".widget-title {", ".widget-dnd > switch:checked {", ".widget-buttons-grid {"] {
+        assert!(output.contains(selector), "Missing swaync selector {}", selector);
+    }
+    assert_eq!(output.matches('{').count(), output.matches('}').count());
+}
+
+#[test]
+fn test_notification_daemons_share_urgency_colors() {
+    let theme = create_full_test_theme();
+    let purple = theme.colors.purple.clone().unwrap();
+    let mako = generators::generate(&theme, "mako").unwrap();
+    let dunst = generators::generate(&theme, "dunst").unwrap();
+    let swaync = generators::generate(&theme, "swaync").unwrap();
+    
+    // Low urgency uses purple, critical inverts to red
+    assert!(mako.contains(&format!("[urgency=low]\nborder-color={}", purple)));
+    assert!(dunst.contains(&format!("[urgency_low]\n    background = \"{}\"\n    foreground = \"{}\"\n    frame_color = \"{}\"",
+                                    theme.colors.bg, theme.colors.fg, purple)));
+    assert!(swaync.contains(&format!("@define-color low-border {};", purple)));
+    
+    assert!(mako.contains(&format!("[urgency=critical]\nborder-color={}\nbackground-color={}\ntext-color={}",
+                                   theme.colors.red, theme.colors.red, theme.colors.bg)));
+    assert!(dunst.contains(&format!("[urgency_critical]\n    background = \"{}\"\n    foreground = \"{}\"",
+                                    theme.colors.red, theme.colors.bg)));
+    assert!(swaync.contains(&format!("@define-color critical-bg {};", theme.colors.red)));
+    assert!(swaync.contains(&format!("@define-color critical-fg {};", theme.colors.bg)));
+}
+
+#[test]
+fn test_generate_notification_home_manager_modules() {
+    let theme = create_test_theme();
+    
+    let dunst = generators::generate_home_manager_module(&theme, "dunst").unwrap();
+    assert!(dunst.contains("services.dunst = {"));
+    assert!(dunst.contains("urgency_critical = {"));
+    assert!(dunst.contains(&format!("frame_color = \"{}\";", theme.colors.accent)));
+    
+    let swaync = generators::generate_home_manager_module(&theme, "swaync").unwrap();
+    assert!(swaync.contains("services.swaync = {"));
+    assert!(swaync.contains("style = ''"));
+}
+
+#[test]
+fn test_generate_hyprlock() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "hyprlock").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    for section in ["background {", "input-field {", "label {"] {
+        assert!(output.contains(section), "Missing hyprlock section {}", section);
+    }
+    let accent = theme.colors.accent.trim_start_matches('#');
+    let red = theme.colors.red.trim_start_matches('#');
+    assert!(output.contains(&format!("outer_color = rgb({})", accent)));
+    assert!(output.contains(&format!("fail_color = rgb({})", red)));
+    assert!(output.contains("outline_thickness = 2"));
+    assert!(output.contains("rounding = 8"));
+    assert_eq!(output.matches('{').count(), output.matches('}').count());
+}
+
+#[test]
+fn test_generate_swaylock() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "swaylock").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    for state in ["", "-clear", "-ver", "-wrong", "-caps-lock"] {
+        for part in ["ring", "inside", "line", "text"] {
+            let key = format!("{}{}-color=", part, state);
+            assert!(output.contains(&key), "Missing swaylock option {}", key);
+        }
+    }
+    assert!(output.contains(&format!("ring-wrong-color={}", theme.colors.red.trim_start_matches('#'))));
+    // Colors are written without '#'
+    assert!(!output.lines().filter(|line| !line.starts_with('#')).any(|line| line.contains('#')));
+}
+
+#[test]
+fn test_generate_lock_screen_home_manager_modules() {
+    let theme = create_test_theme();
+    
+    let hyprlock = generators::generate_home_manager_module(&theme, "hyprlock").unwrap();
+    assert!(hyprlock.contains("programs.hyprlock = {"));
+    assert!(hyprlock.contains("settings = {"));
+    assert!(hyprlock.contains("input-field = ["));
+    assert!(hyprlock.contains("outline_thickness = 2;"));
+    assert_eq!(hyprlock.matches('{').count(), hyprlock.matches('}').count());
+    assert_eq!(hyprlock.matches('[').count(), hyprlock.matches(']').count());
+    
+    let swaylock = generators::generate_home_manager_module(&theme, "swaylock").unwrap();
+    assert!(swaylock.contains("programs.swaylock = {"));
+    assert!(swaylock.contains(&format!("ring-color = \"{}\";", theme.colors.accent.trim_start_matches('#'))));
+    assert!(swaylock.contains("indicator-thickness = 10;"));
+}
+
 #[test]
 fn test_generate_hyprland() {
     let theme = create_test_theme();
@@ -135,6 +687,127 @@ fn test_generate_hyprland() {
     assert!(output.contains("col.inactive_border") || output.contains("rgba"));
 }
 
+#[test]
+fn test_generate_hyprland_blocks() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "hyprland").unwrap();
+    
+    for block in ["general {", "decoration {", "shadow {", "blur {", "group {", "groupbar {", "misc {", "animations {"] {
+        assert!(output.contains(block), "Missing hyprland block {}", block);
+    }
+    assert_eq!(output.matches('{').count(), output.matches('}').count());
+    
+    // Gradient border from accent to magenta
+    assert!(output.contains("col.active_border = rgba(fe8019ee) rgba(b16286ee) 45deg"));
+    assert!(output.contains("rounding = 8"));
+    assert!(output.contains("range = 10"));
+    // 0.2s animation duration is a speed of 2 (100ms units)
+    assert!(output.contains("animation = windows, 1, 2, themectl"));
+    // Deprecated names are not used with the current syntax
+    assert!(!output.contains("col.shadow"));
+    assert!(!output.contains("drop_shadow"));
+}
+
+#[test]
+fn test_generate_hyprland_legacy_syntax() {
+    let theme = create_full_test_theme();
+    let mut config = themectl::config::ThemectlConfig::default();
+    config.set_hyprland_version("0.41.2").unwrap();
+    
+    let output = generators::generate_with_config(&theme, "hyprland", &config).unwrap();
+    assert!(output.contains("drop_shadow = true"));
+    assert!(output.contains("shadow_range = 10"));
+    assert!(output.contains("col.shadow = rgba(2828284d)"));
+    assert!(!output.contains("shadow {"));
+    
+    // Other formats are unaffected by the config
+    assert_eq!(
+        generators::generate_with_config(&theme, "kitty", &config).unwrap(),
+        generators::generate(&theme, "kitty").unwrap()
+    );
+}
+
+#[test]
+fn test_generate_hyprland_without_animation_duration() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "hyprland").unwrap();
+    
+    assert!(output.contains("general {"));
+    assert!(!output.contains("animations {"));
+}
+
+#[test]
+fn test_generate_sway() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "sway").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("include ~/.config/sway/config.d/*"));
+    for class in ["focused", "focused_inactive", "unfocused", "urgent", "placeholder"] {
+        let line = output.lines()
+            .find(|line| line.starts_with(&format!("client.{} ", class)))
+            .unwrap_or_else(|| panic!("Missing client.{}", class));
+        // border background text indicator child_border
+        assert_eq!(line.split_whitespace().count(), 6, "Unexpected color count in {}", line);
+    }
+    let focused: Vec<&str> = output.lines()
+        .find(|line| line.starts_with("client.focused "))
+        .unwrap()
+        .split_whitespace()
+        .collect();
+    assert_eq!(focused[1..4], [theme.colors.accent.as_str(), theme.colors.accent.as_str(), theme.colors.bg.as_str()]);
+    assert!(output.contains("default_border pixel 2"));
+    assert!(output.contains("gaps inner 4"));
+    // The swaybar colors are only suggested, a live bar block would start a bar
+    assert!(output.contains("# bar {\n#     colors {\n"));
+    assert!(!output.lines().any(|line| line.trim_start().starts_with("bar ")));
+    assert!(output.contains(&format!("#         urgent_workspace   {} {} {}", theme.colors.red, theme.colors.red, theme.colors.bg)));
+    assert_eq!(output.matches('{').count(), output.matches('}').count());
+}
+
+#[test]
+fn test_generate_i3() {
+    let theme = create_test_theme();
+    let sway = generators::generate(&theme, "sway").unwrap();
+    let i3 = generators::generate(&theme, "i3").unwrap();
+    
+    assert!(i3.contains("include ~/.config/i3/config.d/*"));
+    assert!(i3.contains(&format!("client.background {}", theme.colors.bg)));
+    // Same window colors as sway
+    let clients = |output: &str| output.lines().filter(|line| line.starts_with("client.f")).map(String::from).collect::<Vec<_>>();
+    assert_eq!(clients(&sway), clients(&i3));
+    // The i3bar colors are only suggested, a live bar {} would start a second bar
+    assert!(i3.contains("# bar {\n#     colors {\n"));
+    assert!(!i3.lines().any(|line| line.trim_start().starts_with("bar ")));
+    
+    assert_eq!(generators::reload_hint("sway"), Some("swaymsg reload"));
+    assert_eq!(generators::reload_hint("i3"), Some("i3-msg reload"));
+}
+
+#[test]
+fn test_home_manager_sway_i3_use_typed_colors() {
+    let theme = create_test_theme();
+    for (app, option) in [("sway", "wayland.windowManager.sway = {"), ("i3", "xsession.windowManager.i3 = {")] {
+        let module = generators::generate_home_manager_module(&theme, app).unwrap();
+        assert!(module.contains(option));
+        assert!(!module.contains("extraConfig"), "{} module should not paste config text", app);
+        assert!(module.contains(&format!(
+            "focused = {{ border = \"{0}\"; background = \"{0}\"; text = \"{1}\";",
+            theme.colors.accent, theme.colors.bg
+        )));
+        assert!(module.contains("focusedInactive = {"));
+        assert!(module.contains("placeholder = {"));
+        assert!(module.contains("window = { border = 2; titlebar = false; };"));
+        // bars is a list option, so the bar colors are only suggested
+        assert!(!module.contains("bars = ["));
+        assert!(module.contains(&format!(
+            "#   urgentWorkspace = {{ border = \"{0}\"; background = \"{0}\"; text = \"{1}\"; }};",
+            theme.colors.red, theme.colors.bg
+        )));
+        assert!(rnix::parse(&module).errors().is_empty());
+    }
+}
+
 #[test]
 fn test_generate_hyprpaper() {
     let theme = create_test_theme();
@@ -159,6 +832,675 @@ fn test_generate_wofi() {
     assert!(output_contains_color(&output, &theme.colors.bg));
 }
 
+#[test]
+fn test_generate_rofi() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "rofi").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("@theme \"~/.config/rofi/themes/"));
+    for section in ["window {", "mainbox {", "inputbar {", "listview {", "element {",
+                    "element normal.normal {", "element selected.normal {", "element normal.urgent {",
+                    "element selected.active {"] {
+        assert!(output.contains(section), "Missing rasi section {}", section);
+    }
+    assert!(output.contains(&format!("accent: {};", theme.colors.accent)));
+    assert!(output.contains("border-radius: 8px;"));
+    assert_eq!(output.matches('{').count(), output.matches('}').count());
+}
+
+#[test]
+fn test_generate_fuzzel() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "fuzzel").unwrap();
+    
+    assert!(output.contains("include=~/.config/fuzzel/themes/"));
+    assert!(output.contains("[colors]"));
+    let background = format!("background={}ff", theme.colors.bg.trim_start_matches('#').to_lowercase());
+    assert!(output.contains(&background));
+    // Every color is RRGGBBAA without '#'
+    let colors_section = output.split("[colors]").nth(1).unwrap().split("\n[").next().unwrap();
+    for line in colors_section.lines().filter(|line| line.contains('=')) {
+        let value = line.split('=').nth(1).unwrap();
+        assert_eq!(value.len(), 8, "Expected RRGGBBAA in {}", line);
+        assert!(!value.starts_with('#'));
+    }
+}
+
+#[test]
+fn test_generate_tofi() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "tofi").unwrap();
+    
+    assert!(output.contains(&format!("include = themes/{}", theme.name)));
+    assert!(output.contains(&format!("background-color = {}", theme.colors.bg)));
+    assert!(output.contains(&format!("selection-background = {}", theme.colors.accent)));
+    assert!(output.contains("corner-radius = "));
+}
+
+#[test]
+fn test_generate_gtk_css_defines_libadwaita_colors() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "gtk-css").unwrap();
+    
+    for name in ["window_bg_color", "window_fg_color", "view_bg_color", "accent_bg_color", "accent_fg_color",
+                 "headerbar_bg_color", "headerbar_shade_color", "sidebar_bg_color", "card_bg_color",
+                 "dialog_bg_color", "popover_bg_color", "destructive_bg_color", "success_bg_color",
+                 "warning_bg_color", "error_bg_color", "shade_color", "scrollbar_outline_color",
+                 "theme_bg_color", "theme_base_color", "borders"] {
+        let define = format!("@define-color {} ", name);
+        assert_eq!(output.matches(&define).count(), 1, "Expected exactly one {}", name);
+    }
+    assert!(output.contains(&format!("@define-color window_bg_color {};", theme.colors.bg)));
+    assert!(output.contains(&format!("@define-color accent_bg_color {};", theme.colors.accent)));
+    assert!(output.contains(&format!("@define-color destructive_bg_color {};", theme.colors.red)));
+}
+
+#[test]
+fn test_generate_gtk_settings_follow_variant() {
+    let dark = create_full_test_theme();
+    let gtk4 = generators::generate(&dark, "gtk").unwrap();
+    assert!(gtk4.contains("gtk-application-prefer-dark-theme=true"));
+    assert!(gtk4.contains("gtk-interface-color-scheme=dark"));
+    
+    let gtk3 = generators::generate(&dark, "gtk3-settings").unwrap();
+    assert!(gtk3.contains("gtk-application-prefer-dark-theme=true"));
+    assert!(!gtk3.contains("gtk-interface-color-scheme"));
+    
+    let mut light = create_test_theme();
+    light.variant = Some("light".to_string());
+    let gtk4 = generators::generate(&light, "gtk").unwrap();
+    assert!(gtk4.contains("gtk-interface-color-scheme=light"));
+    assert_eq!(generators::gtk_color_scheme(&light), "prefer-light");
+    assert_eq!(generators::gtk_color_scheme(&dark), "prefer-dark");
+    let hint = generators::reload_hint_for_theme(&light, "gtk").unwrap();
+    assert!(hint.contains("color-scheme prefer-light"));
+}
+
+#[test]
+fn test_home_manager_gtk_module() {
+    let theme = create_full_test_theme();
+    let output = generators::generate_home_manager_module(&theme, "gtk").unwrap();
+    
+    for path in ["gtk-3.0/settings.ini", "gtk-4.0/settings.ini", "gtk-3.0/gtk.css", "gtk-4.0/gtk.css"] {
+        assert!(output.contains(&format!("home.file.\".config/{}\".text", path)), "Missing {}", path);
+    }
+    assert!(output.contains("dconf.settings.\"org/gnome/desktop/interface\".color-scheme = \"prefer-dark\";"));
+}
+
+#[test]
+fn test_generate_qt() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "qt").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains("[ColorScheme]"));
+    for key in ["active_colors=", "disabled_colors=", "inactive_colors="] {
+        let line = output.lines().find(|line| line.starts_with(key)).unwrap();
+        let colors: Vec<&str> = line[key.len()..].split(", ").collect();
+        assert_eq!(colors.len(), 21, "Expected 21 palette roles in {}", key);
+        assert!(colors.iter().all(|color| color.len() == 9 && color.starts_with("#ff")));
+    }
+    // WindowText is the first role, Window the eleventh
+    let active = output.lines().find(|line| line.starts_with("active_colors=")).unwrap();
+    let roles: Vec<&str> = active["active_colors=".len()..].split(", ").collect();
+    assert_eq!(roles[0], format!("#ff{}", theme.colors.fg.trim_start_matches('#').to_lowercase()));
+    assert_eq!(roles[10], format!("#ff{}", theme.colors.bg.trim_start_matches('#').to_lowercase()));
+    assert_eq!(roles[12], format!("#ff{}", theme.colors.accent.trim_start_matches('#').to_lowercase()));
+}
+
+#[test]
+fn test_qt_select_color_scheme_keeps_other_settings() {
+    let existing = "[Appearance]\ncolor_scheme_path=/old/path.conf\ncustom_palette=false\nstyle=Fusion\n\n[Fonts]\nfixed=\"Monospace,10\"\n";
+    let updated = generators::qt_select_color_scheme(existing, "/home/user/.config/qt5ct/colors/test.conf");
+    
+    assert!(updated.contains("color_scheme_path=/home/user/.config/qt5ct/colors/test.conf\n"));
+    assert!(updated.contains("custom_palette=true\n"));
+    assert!(updated.contains("style=Fusion"));
+    assert!(updated.contains("[Fonts]\nfixed=\"Monospace,10\""));
+    assert!(!updated.contains("/old/path.conf"));
+    
+    // Missing keys go into the existing section, a missing section is appended
+    let added = generators::qt_select_color_scheme("[Appearance]\nstyle=Fusion\n\n[Fonts]\n", "/x.conf");
+    assert!(added.starts_with("[Appearance]\nstyle=Fusion\ncolor_scheme_path=/x.conf\ncustom_palette=true\n\n[Fonts]"));
+    let created = generators::qt_select_color_scheme("", "/x.conf");
+    assert_eq!(created, "[Appearance]\ncolor_scheme_path=/x.conf\ncustom_palette=true\n");
+}
+
+#[test]
+fn test_generate_qt_kvantum() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "qt-kvantum").unwrap();
+    
+    assert!(output.contains("[%General]"));
+    assert!(output.contains("[GeneralColors]"));
+    assert!(output.contains(&format!("window.color={}", theme.colors.bg)));
+    assert!(output.contains(&format!("highlight.color={}", theme.colors.accent)));
+}
+
+#[test]
+fn test_home_manager_qt_module() {
+    let theme = create_test_theme();
+    let output = generators::generate_home_manager_module(&theme, "qt").unwrap();
+    
+    assert!(output.contains("platformTheme.name = \"qtct\";"));
+    assert!(output.contains(&format!("xdg.configFile.\"qt5ct/colors/{}.conf\".text", theme.name)));
+    assert!(output.contains(&format!("xdg.configFile.\"qt6ct/colors/{}.conf\".text", theme.name)));
+    assert!(output.contains(&format!("color_scheme_path=${{config.xdg.configHome}}/qt6ct/colors/{}.conf", theme.name)));
+}
+
+#[test]
+fn test_generate_emacs() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "emacs").unwrap();
+    
+    assert!(output.starts_with(&format!(";;; {}-theme.el", theme.name)));
+    assert!(output.contains(&format!("(deftheme {} ", theme.name)));
+    assert!(output.contains(&format!("(provide-theme '{})", theme.name)));
+    assert!(output.contains(&format!(" '(default ((t (:foreground \"{}\" :background \"{}\"))))", theme.colors.fg, theme.colors.bg)));
+    for face in ["mode-line", "region", "font-lock-keyword-face", "font-lock-string-face", "diff-added", "diff-removed", "magit-section-heading", "magit-diff-added"] {
+        assert!(output.contains(&format!(" '({} ((t (", face)), "Missing face {}", face);
+    }
+    // Same syntax role mapping as the neovim generator
+    assert!(output.contains(&format!(" '(font-lock-string-face ((t (:foreground \"{}\"))))", theme.colors.green)));
+    assert!(output.contains(&format!(" '(font-lock-keyword-face ((t (:foreground \"{}\"))))", theme.colors.blue)));
+    assert_eq!(output.matches('(').count(), output.matches(')').count());
+}
+
+#[test]
+fn test_generate_vim() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "vim").unwrap();
+    
+    assert!(output.contains(&format!("let g:colors_name = \"{}\"", theme.name)));
+    assert!(output.contains("set background=dark"));
+    assert!(output.contains("hi clear"));
+    assert!(output.contains("let g:terminal_ansi_colors = [\"#"));
+    // gui colors with the nearest xterm-256 fallback (#282828 -> 235)
+    assert!(output.contains(&format!("hi Normal guifg={} ctermfg=", theme.colors.fg)));
+    assert!(output.contains("guibg=#282828 ctermbg=235 "));
+    assert!(output.contains(&format!("hi String guifg={} ctermfg=", theme.colors.green)));
+    assert!(output.contains(&format!("hi Keyword guifg={} ctermfg=", theme.colors.blue)));
+    assert!(output.contains("hi SpellBad guifg=#cc241d ctermfg=160 gui=undercurl cterm=underline"));
+    for line in output.lines().filter(|line| line.starts_with("hi ") && line != &"hi clear") {
+        assert!(line.contains(" gui=") && line.contains(" cterm="), "Missing attributes in {}", line);
+        assert_eq!(line.matches("guifg=").count(), line.matches("ctermfg=").count());
+        assert_eq!(line.matches("guibg=").count(), line.matches("ctermbg=").count());
+    }
+}
+
+#[test]
+fn test_emacs_vim_slug_theme_names() {
+    let mut theme = create_full_test_theme();
+    theme.name = "Tokyo Night (Storm)".to_string();
+    
+    let emacs = generators::generate(&theme, "emacs").unwrap();
+    assert!(emacs.contains("(deftheme tokyo-night-storm "));
+    assert!(emacs.contains("(provide-theme 'tokyo-night-storm)"));
+    let vim = generators::generate(&theme, "vim").unwrap();
+    assert!(vim.contains("let g:colors_name = \"tokyo-night-storm\""));
+    
+    let base_dir = std::path::Path::new("/config");
+    assert!(generators::config_path("vim", &theme, base_dir).unwrap().ends_with("vim/colors/tokyo-night-storm.vim"));
+    let module = generators::generate_home_manager_module(&theme, "emacs").unwrap();
+    assert!(module.contains("\"emacs/themes/tokyo-night-storm-theme.el\""));
+    assert!(module.contains("(load-theme 'tokyo-night-storm t)"));
+}
+
+#[test]
+fn test_generate_zathura() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "zathura").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains(&format!("set default-bg \"{}\"", theme.colors.bg)));
+    assert!(output.contains(&format!("set recolor-lightcolor \"{}\"", theme.colors.bg)));
+    assert!(output.contains(&format!("set recolor-darkcolor \"{}\"", theme.colors.fg)));
+    assert!(output.contains("set highlight-color \"rgba("));
+    assert!(output.contains("set statusbar-bg \"#"));
+    assert!(output.contains("set inputbar-fg \"#"));
+}
+
+#[test]
+fn test_home_manager_editor_modules() {
+    let theme = create_test_theme();
+    
+    let emacs = generators::generate_home_manager_module(&theme, "emacs").unwrap();
+    assert!(emacs.contains(&format!("xdg.configFile.\"emacs/themes/{}-theme.el\".text = ''", theme.name)));
+    assert!(emacs.contains(&format!("(load-theme '{} t)", theme.name)));
+    
+    let vim = generators::generate_home_manager_module(&theme, "vim").unwrap();
+    assert!(vim.contains(&format!("home.file.\".vim/colors/{}.vim\".text = ''", theme.name)));
+    assert!(vim.contains(&format!("extraConfig = \"colorscheme {}\";", theme.name)));
+    
+    let zathura = generators::generate_home_manager_module(&theme, "zathura").unwrap();
+    assert!(zathura.contains("programs.zathura = {"));
+    assert!(zathura.contains(&format!("default-bg = \"{}\";", theme.colors.bg)));
+    assert!(zathura.contains("recolor-keephue = true;"));
+}
+
+#[test]
+fn test_generate_tty() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "tty").unwrap();
+    
+    assert!(output.starts_with("#!/bin/sh\n"));
+    assert!(output.contains("setvtrgb - <<'EOF'"));
+    // #282828 background as color0 escape sequence
+    assert!(output.contains("printf '\\033]P0282828'"));
+    assert!(output.contains("printf '\\033]PF"));
+    assert!(output.contains("#   vt.default_red=0x28,"));
+    
+    let vtrgb = generators::generate(&theme, "tty-vtrgb").unwrap();
+    let lines: Vec<&str> = vtrgb.lines().collect();
+    assert_eq!(lines.len(), 3);
+    for line in &lines {
+        let values: Vec<u8> = line.split(',').map(|v| v.parse().unwrap()).collect();
+        assert_eq!(values.len(), 16);
+    }
+    assert!(lines[0].starts_with("40,") && lines[1].starts_with("40,") && lines[2].starts_with("40,"));
+    
+    let kernel = generators::generate(&theme, "tty-kernel").unwrap();
+    let params: Vec<&str> = kernel.trim_end().split(' ').collect();
+    assert_eq!(params.len(), 3);
+    for (param, prefix) in params.iter().zip(["vt.default_red=", "vt.default_grn=", "vt.default_blu="]) {
+        assert!(param.starts_with(prefix));
+        assert_eq!(param[prefix.len()..].split(',').count(), 16);
+    }
+}
+
+#[test]
+fn test_generate_xresources() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "xresources").unwrap();
+    
+    assert!(output.contains("xrdb -merge"));
+    assert!(output.contains(&format!("*.foreground: {}\n", theme.colors.fg)));
+    assert!(output.contains(&format!("*.background: {}\n", theme.colors.bg)));
+    assert!(output.contains(&format!("*.cursorColor: {}\n", theme.colors.accent)));
+    for i in 0..16 {
+        assert!(output.contains(&format!("*.color{}: #", i)), "Missing color{}", i);
+    }
+    assert!(output.contains(&format!("*.color1: {}\n", theme.colors.red)));
+    assert_eq!(generators::reload_hint("xresources"), Some("xrdb -merge ~/.config/X11/themectl.Xresources"));
+}
+
+#[test]
+fn test_home_manager_tty_and_xresources_modules() {
+    let theme = create_test_theme();
+    
+    let tty = generators::generate_home_manager_module(&theme, "tty").unwrap();
+    assert!(tty.contains("#   console.colors = [ \"282828\""));
+    assert!(tty.contains("xdg.configFile.\"tty/themectl.sh\".text = ''"));
+    
+    let xresources = generators::generate_home_manager_module(&theme, "xresources").unwrap();
+    assert!(xresources.contains("xresources.properties = {"));
+    assert!(xresources.contains(&format!("\"*.background\" = \"{}\";", theme.colors.bg)));
+    assert!(xresources.contains("\"*.color15\" = \"#"));
+}
+
+#[test]
+fn test_generate_cava() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "cava").unwrap();
+    
+    assert!(output.contains("[color]"));
+    assert!(output.contains(&format!("background = '{}'", theme.colors.bg)));
+    assert!(output.contains("gradient = 1"));
+    let count: usize = output.lines()
+        .find_map(|line| line.strip_prefix("gradient_count = "))
+        .unwrap().parse().unwrap();
+    assert!((2..=8).contains(&count));
+    let stops: Vec<&str> = output.lines().filter(|line| line.starts_with("gradient_color_")).collect();
+    assert_eq!(stops.len(), count);
+    // Runs from accent to red
+    assert_eq!(stops[0], format!("gradient_color_1 = '{}'", theme.colors.accent.to_lowercase()));
+    assert_eq!(stops[count - 1], format!("gradient_color_{} = '{}'", count, theme.colors.red.to_lowercase()));
+}
+
+#[test]
+fn test_generate_mpv() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "mpv").unwrap();
+    
+    assert!(output.contains(&format!("osd-color=\"{}\"", theme.colors.fg)));
+    assert!(output.contains(&format!("osd-border-color=\"{}\"", theme.colors.bg)));
+    assert!(output.contains(&format!("sub-color=\"{}\"", theme.colors.fg)));
+    
+    let uosc = generators::generate(&theme, "mpv-uosc").unwrap();
+    let color = uosc.lines().find(|line| line.starts_with("color=")).unwrap();
+    assert!(color.contains(&format!("foreground={}", theme.colors.accent.trim_start_matches('#').to_lowercase())));
+    assert!(color.contains("background=282828"));
+    
+    let modernx = generators::generate(&theme, "mpv-modernx").unwrap();
+    assert!(modernx.contains(&format!("seekbarfg_color={}", theme.colors.accent)));
+}
+
+#[test]
+fn test_mpv_update_script_opts_keeps_other_options() {
+    let theme = create_test_theme();
+    let existing = "# my uosc settings\ntimeline_style=line\ncolor=foreground=ffffff\nautohide=yes\n";
+    let updated = generators::mpv_update_script_opts(&theme, "uosc", existing).unwrap();
+    
+    assert!(updated.starts_with("# my uosc settings\ntimeline_style=line\ncolor=foreground="));
+    assert!(updated.ends_with("autohide=yes\n"));
+    assert!(!updated.contains("foreground=ffffff"));
+    assert_eq!(updated.matches("color=").count(), 1);
+    
+    // Missing options are appended
+    let appended = generators::mpv_update_script_opts(&theme, "modernx", "showtitle=no\n").unwrap();
+    assert!(appended.starts_with("showtitle=no\nosc_color="));
+    assert!(generators::mpv_update_script_opts(&theme, "osc", "").is_err());
+}
+
+#[test]
+fn test_generate_imv() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "imv").unwrap();
+    
+    assert!(output.contains("[options]"));
+    assert!(output.contains("background = 282828"));
+    assert!(output.contains(&format!("overlay_text_color = {}", theme.colors.fg.trim_start_matches('#').to_lowercase())));
+}
+
+#[test]
+fn test_update_config_colors_keeps_other_sections() {
+    let theme = create_test_theme();
+    let cava = "[general]\nbars = 40\n\n[input]\nmethod = pulse\n\n[color]\n; my old colors\nbackground = 'black'\ngradient = 0\n\n[smoothing]\nnoise_reduction = 77\n";
+    let updated = generators::update_config_colors(&theme, "cava", cava).unwrap();
+    
+    assert!(updated.starts_with("[general]\nbars = 40\n\n[input]\nmethod = pulse\n\n[color]\n; my old colors\n"));
+    assert!(updated.contains(&format!("background = '{}'\ngradient = 1\nforeground = '{}'\n", theme.colors.bg, theme.colors.accent)));
+    assert!(updated.contains("gradient_color_1 = '"));
+    assert!(updated.ends_with("\n\n[smoothing]\nnoise_reduction = 77\n"));
+    assert_eq!(updated.matches("background =").count(), 1);
+    
+    // imv's binds stay; a missing [options] section is added
+    let imv = "[binds]\nq = quit\n<Left> = prev\n";
+    let updated = generators::update_config_colors(&theme, "imv", imv).unwrap();
+    assert!(updated.starts_with("[binds]\nq = quit\n<Left> = prev\n\n[options]\nbackground = 282828\n"));
+    
+    // Keys with the same name in other sections are left alone
+    let updated = generators::update_config_colors(&theme, "imv", "[aliases]\nbackground = bg\n[options]\nfullscreen = true\n").unwrap();
+    assert!(updated.starts_with("[aliases]\nbackground = bg\n[options]\nfullscreen = true\nbackground = 282828\n"));
+    
+    assert_eq!(generators::update_config_colors(&theme, "cava", "").unwrap(), generators::generate(&theme, "cava").unwrap());
+    assert!(generators::update_config_colors(&theme, "kitty", "").is_err());
+}
+
+#[test]
+fn test_home_manager_media_modules() {
+    let theme = create_test_theme();
+    
+    let cava = generators::generate_home_manager_module(&theme, "cava").unwrap();
+    assert!(cava.contains("programs.cava = {"));
+    assert!(cava.contains(&format!("background = \"'{}'\";", theme.colors.bg)));
+    assert!(cava.contains("gradient_color_1 = \"'#"));
+    
+    let mpv = generators::generate_home_manager_module(&theme, "mpv").unwrap();
+    assert!(mpv.contains(&format!("osd-color = \"{}\";", theme.colors.fg)));
+    assert!(mpv.contains("uosc = {"));
+    assert!(mpv.contains("modernx = {"));
+    
+    let imv = generators::generate_home_manager_module(&theme, "imv").unwrap();
+    assert!(imv.contains("programs.imv = {"));
+    assert!(imv.contains("background = \"282828\";"));
+}
+
+#[test]
+fn test_generate_firefox() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "firefox").unwrap();
+    
+    assert!(output_contains_name(&output, &theme.name));
+    assert!(output.contains(":root {"));
+    assert!(output.contains(&format!("--lwt-accent-color: {} !important;", theme.colors.bg)));
+    assert!(output.contains(&format!("--toolbar-field-focus-border-color: {} !important;", theme.colors.accent)));
+    for variable in ["--toolbar-bgcolor:", "--tab-selected-bgcolor:", "--urlbar-box-bgcolor:", "--sidebar-background-color:"] {
+        assert!(output.contains(variable), "Missing {}", variable);
+    }
+    
+    let content = generators::generate(&theme, "firefox-content").unwrap();
+    assert!(content.contains("@-moz-document url-prefix(\"about:newtab\")"));
+    assert!(content.contains(&format!("--newtab-background-color: {} !important;", theme.colors.bg)));
+}
+
+#[test]
+fn test_firefox_update_user_js_managed_block() {
+    let pref = "user_pref(\"toolkit.legacyUserProfileCustomizations.stylesheets\", true);";
+    
+    let created = generators::firefox_update_user_js("");
+    assert!(created.starts_with("// BEGIN themectl\n"));
+    assert!(created.contains(pref));
+    
+    // Appended after the user's own preferences
+    let appended = generators::firefox_update_user_js("user_pref(\"browser.startup.page\", 3);");
+    assert!(appended.starts_with("user_pref(\"browser.startup.page\", 3);\n\n// BEGIN themectl\n"));
+    
+    // Replaced in place, keeping what follows the block
+    let existing = "user_pref(\"a\", 1);\n// BEGIN themectl\nuser_pref(\"old\", false);\n// END themectl\nuser_pref(\"b\", 2);\n";
+    let replaced = generators::firefox_update_user_js(existing);
+    assert!(!replaced.contains("\"old\""));
+    assert!(replaced.contains(pref));
+    assert!(replaced.starts_with("user_pref(\"a\", 1);\n// BEGIN themectl\n"));
+    assert!(replaced.ends_with("// END themectl\nuser_pref(\"b\", 2);\n"));
+    assert_eq!(generators::firefox_update_user_js(&replaced), replaced);
+}
+
+#[test]
+fn test_firefox_profile_dirs() {
+    let ini = "[General]\nStartWithLastProfile=1\n\n[Profile0]\nName=default\nIsRelative=1\nPath=abc.default\n\n[Profile1]\nName=work\nIsRelative=0\nPath=/data/firefox/work\n\n[Install4F96D1932A9F858E]\nDefault=abc.default\n";
+    let dirs = generators::firefox_profile_dirs(ini, std::path::Path::new("/home/user/.mozilla/firefox"));
+    
+    assert_eq!(dirs, vec![
+        std::path::PathBuf::from("/home/user/.mozilla/firefox/abc.default"),
+        std::path::PathBuf::from("/data/firefox/work"),
+    ]);
+}
+
+#[test]
+fn test_home_manager_firefox_module() {
+    let theme = create_test_theme();
+    let output = generators::generate_home_manager_module(&theme, "firefox").unwrap();
+    
+    assert!(output.contains("programs.firefox.profiles.default = {"));
+    assert!(output.contains("settings.\"toolkit.legacyUserProfileCustomizations.stylesheets\" = true;"));
+    assert!(output.contains("userChrome = ''"));
+    assert!(output.contains("userContent = ''"));
+    assert!(output.contains("--toolbar-bgcolor:"));
+}
+
+#[test]
+fn test_generate_git_truecolor_and_fallback() {
+    let theme = create_full_test_theme();
+    let mut config = themectl::config::ThemectlConfig::default();
+    
+    config.set_git_colors("truecolor").unwrap();
+    let truecolor = generators::generate_with_config(&theme, "git", &config).unwrap();
+    assert!(truecolor.contains(&format!("meta = \"{}\" bold", theme.colors.yellow)));
+    assert!(truecolor.contains(&format!("local = \"{}\"\n", theme.colors.yellow)));
+    assert!(truecolor.contains("reset = normal"));
+    let nix = generators::generate_with_config(&theme, "git-nix", &config).unwrap();
+    assert!(nix.contains(&format!("\"color.diff.new\" = \"{} bold\";", theme.colors.green)));
+    
+    config.set_git_colors("16").unwrap();
+    let named = generators::generate_with_config(&theme, "git", &config).unwrap();
+    assert!(!named.contains(" = \"#"));
+    assert!(named.contains("[color \"diff\"]"));
+    let nix = generators::generate_with_config(&theme, "git-nix", &config).unwrap();
+    assert!(!nix.contains("= \"#"));
+    
+    // Without a config the default git.colors setting applies
+    let plain = generators::generate(&theme, "git").unwrap();
+    let default = generators::generate_with_config(&theme, "git", &themectl::config::ThemectlConfig::default()).unwrap();
+    assert_eq!(plain, default);
+    let all = generators::generate_all_with_config(&theme, &config).unwrap();
+    let (_, git) = all.iter().find(|(format, _)| format == "git").unwrap();
+    assert_eq!(git, &named);
+}
+
+#[test]
+fn test_generate_tmtheme() {
+    let theme = create_full_test_theme();
+    let output = generators::generate(&theme, "tmtheme").unwrap();
+    
+    assert!(output.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(output.contains("<plist version=\"1.0\">"));
+    assert!(output.contains(&format!("<string>{}</string>", theme.name)));
+    assert_eq!(output.matches("<dict>").count(), output.matches("</dict>").count());
+    assert_eq!(output.matches("<key>").count(), output.matches("</key>").count());
+    for key in ["background", "caret", "selection", "lineHighlight", "gutter", "gutterForeground"] {
+        assert!(output.contains(&format!("<key>{}</key>", key)), "Missing global {}", key);
+    }
+    for scope in ["<string>comment, ", "<string>string, ", "<string>keyword, ", "<string>markup.inserted", "<string>markup.deleted"] {
+        assert!(output.contains(scope), "Missing scope {}", scope);
+    }
+    assert!(output.contains(&format!("<string>{}</string>", theme.colors.bg)));
+}
+
+#[test]
+fn test_generate_delta() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "delta").unwrap();
+    
+    assert!(output.contains("[delta]"));
+    assert!(output.contains(&format!("syntax-theme = {}", theme.name)));
+    assert!(output.contains("plus-style = syntax \"#"));
+    assert!(output.contains("minus-style = syntax \"#"));
+    assert!(output.contains(&format!("line-numbers-plus-style = \"{}\"", theme.colors.green)));
+    // Every hex color is quoted so git does not treat it as a comment
+    for line in output.lines().filter(|line| line.starts_with("    ")) {
+        assert!(!line.contains(" #"), "Unquoted color in {}", line);
+    }
+}
+
+#[test]
+fn test_home_manager_tmtheme_module() {
+    let theme = create_test_theme();
+    let output = generators::generate_home_manager_module(&theme, "tmtheme").unwrap();
+    
+    assert!(output.contains(&format!("config.theme = \"{}\";", theme.name)));
+    assert!(output.contains(&format!("themes.{0}.src = pkgs.writeText \"{0}.tmTheme\"", theme.name)));
+    assert!(output.contains("programs.git.delta = {"));
+    assert!(output.contains(&format!("syntax-theme = \"{}\";", theme.name)));
+    assert!(output.contains("plus-style = \"syntax #"));
+}
+
+#[test]
+fn test_generate_lscolors() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "lscolors").unwrap();
+    
+    let ls_colors = output.lines().find(|line| line.starts_with("export LS_COLORS='")).unwrap();
+    // #458588 (blue) as a bold 24-bit directory color
+    assert!(ls_colors.contains("di=01;38;2;69;133;136:"));
+    for key in ["ln=", "ex=", "or=", "mi=", "*.tar=", "*.png=", "*.mp3=", "*.mkv=", "*.rs=", "*.py=", "*.json="] {
+        assert!(ls_colors.contains(key), "Missing {}", key);
+    }
+    assert!(ls_colors.ends_with('\''));
+    let eza = output.lines().find(|line| line.starts_with("export EZA_COLORS='")).unwrap();
+    assert!(eza.contains("ur=38;2;") && eza.contains("gm=38;2;"));
+    
+    let fish = generators::generate(&theme, "lscolors-fish").unwrap();
+    assert!(fish.contains("set -gx LS_COLORS 'di=01;38;2;69;133;136:"));
+    assert!(fish.contains("set -gx EZA_COLORS '"));
+}
+
+#[test]
+fn test_generate_dircolors() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "dircolors").unwrap();
+    
+    assert!(output.contains("DIR 01;38;2;69;133;136\n"));
+    assert!(output.contains("ORPHAN 01;38;2;204;36;29\n"));
+    assert!(output.contains("\n.zip 01;38;2;204;36;29\n"));
+    for line in output.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        assert_eq!(line.split(' ').count(), 2, "Expected 'KEY SGR' in {}", line);
+    }
+}
+
+#[test]
+fn test_home_manager_lscolors_module() {
+    let theme = create_test_theme();
+    let output = generators::generate_home_manager_module(&theme, "lscolors").unwrap();
+    
+    assert!(output.contains("programs.dircolors = {"));
+    assert!(output.contains("DIR = \"01;38;2;69;133;136\";"));
+    assert!(output.contains("\".tar\" = \""));
+    assert!(output.contains("home.sessionVariables.EZA_COLORS = \""));
+}
+
+#[test]
+fn test_generate_fzf() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "fzf").unwrap();
+    
+    let line = output.lines().find(|line| line.starts_with("export FZF_DEFAULT_OPTS=")).unwrap();
+    assert!(line.contains("$FZF_DEFAULT_OPTS --color="));
+    for slot in ["fg", "bg", "hl", "fg+", "bg+", "hl+", "info", "prompt", "pointer", "marker", "spinner", "border"] {
+        assert!(line.contains(&format!("{}:#", slot)), "Missing fzf slot {}", slot);
+    }
+    assert!(line.contains(&format!("bg:{},", theme.colors.bg)));
+    assert!(line.contains(&format!("hl:{},", theme.colors.accent)));
+}
+
+#[test]
+fn test_generate_lazygit() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "lazygit").unwrap();
+    
+    assert!(output.contains("gui:\n  theme:\n"));
+    assert!(output.contains(&format!("    activeBorderColor:\n      - '{}'\n      - 'bold'\n", theme.colors.accent)));
+    assert!(output.contains("    selectedLineBgColor:\n      - '#"));
+    assert!(output.contains(&format!("    unstagedChangesColor:\n      - '{}'\n", theme.colors.red)));
+}
+
+#[test]
+fn test_generate_fish_colors() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "fish").unwrap();
+    
+    assert!(output.contains(&format!("set -U fish_color_normal {}\n", theme.colors.fg.trim_start_matches('#'))));
+    for var in ["fish_color_command", "fish_color_quote", "fish_color_error", "fish_color_comment",
+                "fish_color_autosuggestion", "fish_color_selection", "fish_pager_color_prefix"] {
+        assert!(output.contains(&format!("set -U {} ", var)), "Missing {}", var);
+    }
+    // fish colors have no '#'
+    assert!(output.lines().filter(|line| line.starts_with("set")).all(|line| !line.contains('#')));
+}
+
+#[test]
+fn test_generate_zsh_highlight_styles() {
+    let theme = create_test_theme();
+    let output = generators::generate(&theme, "zsh").unwrap();
+    
+    assert!(output.contains("typeset -gA ZSH_HIGHLIGHT_STYLES\n"));
+    assert!(output.contains(&format!("ZSH_HIGHLIGHT_STYLES[unknown-token]='fg={},bold'", theme.colors.red)));
+    assert!(output.contains(&format!("ZSH_HIGHLIGHT_STYLES[single-quoted-argument]='fg={}'", theme.colors.green)));
+    assert!(output.contains("ZSH_HIGHLIGHT_STYLES[comment]='fg=#"));
+}
+
+#[test]
+fn test_shell_app_group() {
+    assert_eq!(generators::app_group("shell"), Some(&["fzf", "lazygit", "fish", "zsh"][..]));
+    assert_eq!(generators::app_group("kitty"), None);
+}
+
+#[test]
+fn test_home_manager_shell_modules() {
+    let theme = create_test_theme();
+    
+    let fzf = generators::generate_home_manager_module(&theme, "fzf").unwrap();
+    assert!(fzf.contains("\"bg+\" = \"#"));
+    let lazygit = generators::generate_home_manager_module(&theme, "lazygit").unwrap();
+    assert!(lazygit.contains(&format!("activeBorderColor = [ \"{}\" \"bold\" ];", theme.colors.accent)));
+    let fish = generators::generate_home_manager_module(&theme, "fish").unwrap();
+    assert!(fish.contains("interactiveShellInit = ''"));
+    assert!(fish.contains("set -g fish_color_normal "));
+    let zsh = generators::generate_home_manager_module(&theme, "zsh").unwrap();
+    assert!(zsh.contains(&format!("\"unknown-token\" = \"fg={},bold\";", theme.colors.red)));
+}
+
 #[test]
 fn test_generate_wlogout() {
     let theme = create_test_theme();
@@ -291,28 +1633,75 @@ fn test_generate_all() {
     assert!(result.is_ok());
     let all_formats = result.unwrap();
     
-    // Should have all 15 formats
-    assert_eq!(all_formats.len(), 15);
+    // Should have all 48 formats
+    assert_eq!(all_formats.len(), 48);
     
     // Check that all expected formats are present
     let format_names: Vec<String> = all_formats.iter().map(|(name, _)| name.clone()).collect();
     assert!(format_names.contains(&"kitty".to_string()));
+    assert!(format_names.contains(&"alacritty".to_string()));
+    assert!(format_names.contains(&"foot".to_string()));
+    assert!(format_names.contains(&"wezterm".to_string()));
+    assert!(format_names.contains(&"ghostty".to_string()));
+    assert!(format_names.contains(&"tmux".to_string()));
+    assert!(format_names.contains(&"zellij".to_string()));
     assert!(format_names.contains(&"waybar".to_string()));
     assert!(format_names.contains(&"neovim".to_string()));
+    assert!(format_names.contains(&"lualine".to_string()));
+    assert!(format_names.contains(&"helix".to_string()));
     assert!(format_names.contains(&"starship".to_string()));
     assert!(format_names.contains(&"mako".to_string()));
+    assert!(format_names.contains(&"dunst".to_string()));
+    assert!(format_names.contains(&"swaync".to_string()));
     assert!(format_names.contains(&"hyprland".to_string()));
+    assert!(format_names.contains(&"sway".to_string()));
+    assert!(format_names.contains(&"i3".to_string()));
+    assert!(format_names.contains(&"hyprlock".to_string()));
+    assert!(format_names.contains(&"swaylock".to_string()));
     assert!(format_names.contains(&"hyprpaper".to_string()));
     assert!(format_names.contains(&"wofi".to_string()));
+    assert!(format_names.contains(&"rofi".to_string()));
+    assert!(format_names.contains(&"fuzzel".to_string()));
+    assert!(format_names.contains(&"tofi".to_string()));
     assert!(format_names.contains(&"wlogout".to_string()));
     assert!(format_names.contains(&"fastfetch".to_string()));
     assert!(format_names.contains(&"nix".to_string()));
     assert!(format_names.contains(&"yazi".to_string()));
+    assert!(format_names.contains(&"lscolors".to_string()));
+    assert!(format_names.contains(&"fzf".to_string()));
+    assert!(format_names.contains(&"lazygit".to_string()));
+    assert!(format_names.contains(&"fish".to_string()));
+    assert!(format_names.contains(&"zsh".to_string()));
     assert!(format_names.contains(&"gtk".to_string()));
+    assert!(format_names.contains(&"qt".to_string()));
+    assert!(format_names.contains(&"emacs".to_string()));
+    assert!(format_names.contains(&"vim".to_string()));
+    assert!(format_names.contains(&"zathura".to_string()));
+    assert!(format_names.contains(&"tty".to_string()));
+    assert!(format_names.contains(&"xresources".to_string()));
+    assert!(format_names.contains(&"cava".to_string()));
+    assert!(format_names.contains(&"mpv".to_string()));
+    assert!(format_names.contains(&"imv".to_string()));
+    assert!(format_names.contains(&"firefox".to_string()));
     assert!(format_names.contains(&"btop".to_string()));
+    assert!(format_names.contains(&"tmtheme".to_string()));
     assert!(format_names.contains(&"git".to_string()));
 }
 
+#[test]
+fn test_every_app_has_a_config_path() {
+    let theme = create_test_theme();
+    let base_dir = std::path::Path::new("/config");
+    
+    assert_eq!(generators::apps().count(), generators::formats().len() - 1);
+    for app in generators::apps().filter(|app| *app != "firefox") {
+        let path = generators::config_path(app, &theme, base_dir);
+        assert!(path.is_some_and(|path| path.starts_with(base_dir)), "{} has no config path", app);
+    }
+    assert_eq!(generators::file_extension("tmtheme"), "tmTheme");
+    assert_eq!(generators::file_extension("mpv"), "conf");
+}
+
 #[test]
 fn test_generate_all_non_empty() {
     let theme = create_test_theme();
@@ -372,8 +1761,8 @@ fn test_generate_all_with_full_theme() {
     assert!(result.is_ok());
     let all_formats = result.unwrap();
     
-    // Should still have all 15 formats
-    assert_eq!(all_formats.len(), 15);
+    // Should still have all 48 formats
+    assert_eq!(all_formats.len(), 48);
     
     // Formats that use optional colors should contain them
     if let Some(orange) = &theme.colors.orange {
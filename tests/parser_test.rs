@@ -108,6 +108,83 @@ cyan = "#689d6a"
     assert!(result.is_err());
 }
 
+#[test]
+fn test_parse_theme_with_syntax_overrides() {
+    let toml_content = r##"
+name = "syntax-theme"
+
+[colors]
+bg = "#282828"
+fg = "#ebdbb2"
+accent = "#fe8019"
+red = "#cc241d"
+green = "#98971a"
+yellow = "#d79921"
+blue = "#458588"
+magenta = "#b16286"
+cyan = "#689d6a"
+
+[syntax]
+keyword = "red"
+comment = "#7c6f64"
+"##;
+    
+    let theme = parse_theme(toml_content).unwrap();
+    assert_eq!(theme.syntax.get("keyword").map(String::as_str), Some("red"));
+    assert_eq!(theme.syntax.get("comment").map(String::as_str), Some("#7c6f64"));
+}
+
+#[test]
+fn test_parse_theme_with_wallpaper() {
+    let toml_content = r##"
+name = "wallpaper-theme"
+wallpaper = "~/Pictures/forest.png"
+
+[colors]
+bg = "#282828"
+fg = "#ebdbb2"
+accent = "#fe8019"
+red = "#cc241d"
+green = "#98971a"
+yellow = "#d79921"
+blue = "#458588"
+magenta = "#b16286"
+cyan = "#689d6a"
+"##;
+    
+    let theme = parse_theme(toml_content).unwrap();
+    assert_eq!(theme.wallpaper.as_deref(), Some("~/Pictures/forest.png"));
+}
+
+#[test]
+fn test_parse_theme_invalid_syntax_overrides() {
+    let base = r##"
+name = "syntax-theme"
+
+[colors]
+bg = "#282828"
+fg = "#ebdbb2"
+accent = "#fe8019"
+red = "#cc241d"
+green = "#98971a"
+yellow = "#d79921"
+blue = "#458588"
+magenta = "#b16286"
+cyan = "#689d6a"
+
+[syntax]
+"##;
+    
+    // Unknown role
+    assert!(parse_theme(&format!("{}keywords = \"red\"\n", base)).is_err());
+    // Optional color not defined by the theme
+    assert!(parse_theme(&format!("{}keyword = \"orange\"\n", base)).is_err());
+    // Invalid hex color
+    assert!(parse_theme(&format!("{}keyword = \"#12345\"\n", base)).is_err());
+    // Derived colors are allowed
+    assert!(parse_theme(&format!("{}comment = \"fg_dim\"\n", base)).is_ok());
+}
+
 #[test]
 fn test_parse_theme_file_success() {
     let temp_dir = create_temp_themes_dir();
@@ -89,6 +89,37 @@ fn test_darken_color() {
     assert!(darkened != "#808080");
 }
 
+#[test]
+fn test_mix_colors() {
+    assert_eq!(mix_colors("#ffffff", "#000000", 0.0), Some("#000000".to_string()));
+    assert_eq!(mix_colors("#ffffff", "#000000", 1.0), Some("#ffffff".to_string()));
+    assert_eq!(mix_colors("#ff0000", "#0000ff", 0.5), Some("#800080".to_string()));
+    assert_eq!(mix_colors("invalid", "#000000", 0.5), None);
+}
+
+#[test]
+fn test_oklab_round_trip() {
+    for color in ["#000000", "#ffffff", "#ff0000", "#458588", "#fe8019"] {
+        let (l, a, b) = hex_to_oklab(color).unwrap();
+        assert_eq!(oklab_to_hex(l, a, b), color);
+    }
+    let (l, _, _) = hex_to_oklab("#ffffff").unwrap();
+    assert!((l - 1.0).abs() < 1e-3);
+    assert_eq!(hex_to_oklab("invalid"), None);
+}
+
+#[test]
+fn test_oklab_gradient() {
+    let gradient = oklab_gradient(&["#0000ff", "#ff00ff", "#ff0000"], 5).unwrap();
+    assert_eq!(gradient.len(), 5);
+    assert_eq!(gradient[0], "#0000ff");
+    assert_eq!(gradient[2], "#ff00ff");
+    assert_eq!(gradient[4], "#ff0000");
+    
+    assert_eq!(oklab_gradient(&["#123456"], 3).unwrap(), vec!["#123456"; 3]);
+    assert_eq!(oklab_gradient(&["#000000", "invalid"], 3), None);
+}
+
 #[test]
 fn test_dim_color() {
     // Dim white
@@ -350,3 +381,11 @@ fn test_invert_theme_colors() {
         assert_eq!(inverted_variant.as_deref(), Some("dark"));
     }
 }
+
+#[test]
+fn test_theme_slug() {
+    assert_eq!(theme_slug("gruvbox-dark"), "gruvbox-dark");
+    assert_eq!(theme_slug("Tokyo Night"), "tokyo-night");
+    assert_eq!(theme_slug("  Rosé Pine: Moon!"), "ros-pine-moon");
+    assert_eq!(theme_slug("***"), "themectl");
+}
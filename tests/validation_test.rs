@@ -68,6 +68,8 @@ fn test_validate_theme_missing_required_color() {
             gray: None,
         },
         properties: ThemeProperties::default(),
+        syntax: Default::default(),
+        wallpaper: None,
     };
     
     let result = validate_theme(&theme);